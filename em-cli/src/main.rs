@@ -10,11 +10,12 @@
 extern crate lazy_static;
 
 use crypto_hash::{Algorithm, Hasher};
+use em_client::client::TlsOptions;
+use em_client::oauth::OauthLogin;
+use em_client::pagination::PaginatedApi;
+use em_client::query::{EventQuery, EventSortField, SortOrder};
 use em_client::{models, Api, Client};
 use hyper::header::{Authorization, Basic, Bearer};
-use hyper::net::HttpsConnector;
-use hyper_native_tls::NativeTlsClient;
-use native_tls::{Certificate, TlsConnector};
 use serde::{Deserialize, Serialize};
 use serde_json;
 use sgx_isa::Sigstruct;
@@ -88,19 +89,12 @@ fn get_cached_client() -> Result<Client, String> {
 
 /// Construct an API client with given parameters
 fn get_client(url: &str, token: Option<String>, root_ca_str: &Option<String>) -> Client {
-    let ssl = match root_ca_str {
-        Some(str) => {
-            let cert = Certificate::from_pem(str.as_bytes()).unwrap();
-
-            let mut connector = TlsConnector::builder();
-            connector.add_root_certificate(cert);
-            NativeTlsClient::from(connector.build().unwrap())
-        }
-        None => NativeTlsClient::new().unwrap(),
+    let options = match root_ca_str {
+        Some(str) => TlsOptions::new().with_root_ca(str.as_bytes()),
+        None => TlsOptions::new(),
     };
 
-    let https_connector = HttpsConnector::new(ssl);
-    let mut client = Client::try_new_with_connector(url, Some("https"), https_connector).unwrap();
+    let mut client = Client::try_new_https(url, options).unwrap();
 
     if let Some(token) = token {
         client.headers().set(Authorization(Bearer { token: token }));
@@ -116,11 +110,40 @@ fn login(client: &mut Client, username: &str, password: &str) -> Result<String,
     }));
 
     let auth = client
-        .authenticate_user()
-        .map_err(|e| format!("Authenticate user call failed, {}", e.0))?;
+        .authenticate_user(None)
+        .map_err(|e| format!("Authenticate user call failed, {}", e))?;
     Ok(auth.access_token.unwrap())
 }
 
+/// Login through the identity provider of the user's account.
+///
+/// The user opens the authorization URL in a browser and pastes back the URL the
+/// identity provider redirected the browser to.
+fn login_sso(client: &Client, email: &str) -> Result<String, String> {
+    let login = OauthLogin::discover(client, email)
+        .map_err(|e| format!("Discovering authentication methods failed, {}", e))?
+        .ok_or_else(|| format!("{} does not log in through an identity provider", email))?;
+
+    println!(
+        "Open the following URL in a browser to log in with {}:\n\n    {}\n",
+        login.grant().name,
+        login.authorization_url()
+    );
+    print!("URL you were redirected to: ");
+    std::io::stdout().flush().unwrap();
+
+    let mut redirect = String::new();
+    std::io::stdin()
+        .read_line(&mut redirect)
+        .map_err(|e| format!("Failed reading redirect URL, {}", e))?;
+
+    let auth = login
+        .finish(client, redirect.trim())
+        .map_err(|e| format!("Authenticate user call failed, {}", e))?;
+    auth.access_token
+        .ok_or_else(|| "Authenticate user call returned no access token".to_string())
+}
+
 /// Construct a build request from a provided SIGSTRUCT.
 ///
 /// Quick way to get a sigstruct for testing:
@@ -178,9 +201,15 @@ fn args_desc<'a>() -> clap::App<'a, 'a> {
                         )
                         .arg(
                             Arg::with_name("password")
-                                .required(true)
+                                .required_unless("sso")
                                 .help("Password for authentication if using Basic authentication"),
                         )
+                        .arg(
+                            Arg::with_name("sso")
+                                .long("--sso")
+                                .conflicts_with("password")
+                                .help("Login through the identity provider of the user"),
+                        )
                         .arg(
                             Arg::with_name("add-root-ca")
                                 .takes_value(true)
@@ -298,9 +327,103 @@ fn args_desc<'a>() -> clap::App<'a, 'a> {
                 .subcommand(
                     SubCommand::with_name("get-join-token")
                         .arg(Arg::with_name("zone-id").required(true).help("zone uuid")),
+                )
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .arg(
+                            Arg::with_name("name")
+                                .required(true)
+                                .help("Name of the zone"),
+                        )
+                        .arg(
+                            Arg::with_name("description")
+                                .takes_value(true)
+                                .long("--description")
+                                .help("Description of the zone"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("update")
+                        .arg(Arg::with_name("zone-id").required(true).help("zone uuid"))
+                        .arg(
+                            Arg::with_name("name")
+                                .takes_value(true)
+                                .long("--name")
+                                .help("New name of the zone"),
+                        )
+                        .arg(
+                            Arg::with_name("description")
+                                .takes_value(true)
+                                .long("--description")
+                                .help("New description of the zone"),
+                        )
+                        .arg(
+                            Arg::with_name("node-refresh-interval")
+                                .takes_value(true)
+                                .long("--node-refresh-interval")
+                                .help("Interval between node agent checkins, in seconds"),
+                        )
+                        .arg(
+                            Arg::with_name("node-renewal-threshold")
+                                .takes_value(true)
+                                .long("--node-renewal-threshold")
+                                .help("Remaining certificate validity, in percent, at which nodes renew it"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("delete")
+                        .arg(Arg::with_name("zone-id").required(true).help("zone uuid")),
                 ),
         )
         .subcommand(SubCommand::with_name("node").subcommand(SubCommand::with_name("list")))
+        .subcommand(
+            SubCommand::with_name("event").subcommand(
+                SubCommand::with_name("list")
+                    .arg(
+                        Arg::with_name("actor-type")
+                            .takes_value(true)
+                            .long("--actor-type")
+                            .help("APP, USER or SYSTEM"),
+                    )
+                    .arg(
+                        Arg::with_name("action-type")
+                            .takes_value(true)
+                            .long("--action-type")
+                            .help("e.g. NODE_STATUS, USER_APPROVAL or USER_AUTH"),
+                    )
+                    .arg(
+                        Arg::with_name("severity")
+                            .takes_value(true)
+                            .long("--severity")
+                            .help("INFO, WARNING, ERROR or CRITICAL"),
+                    )
+                    .arg(
+                        Arg::with_name("entity-id")
+                            .takes_value(true)
+                            .long("--entity-id")
+                            .help("uuid of the object the events are about"),
+                    )
+                    .arg(
+                        Arg::with_name("from")
+                            .takes_value(true)
+                            .long("--from")
+                            .help("Earliest creation time, in seconds since the epoch"),
+                    )
+                    .arg(
+                        Arg::with_name("to")
+                            .takes_value(true)
+                            .long("--to")
+                            .help("Latest creation time, in seconds since the epoch"),
+                    )
+                    .arg(
+                        Arg::with_name("limit")
+                            .takes_value(true)
+                            .long("--limit")
+                            .default_value("100")
+                            .help("Maximum number of events to list, newest first"),
+                    ),
+            ),
+        )
 }
 
 /// Implement a CLI using node-manager openAPI definitions to be used in external scripts and logic.
@@ -326,6 +449,7 @@ fn main() -> Result<(), String> {
                     user_password: password,
                     first_name: None,
                     last_name: None,
+                    recaptcha_response: None,
                 };
                 let result = client
                     .create_user(request)
@@ -335,14 +459,16 @@ fn main() -> Result<(), String> {
             ("login", Some(param)) => {
                 let url = param.value_of("url").unwrap();
                 let username = param.value_of("username").unwrap();
-                let password = param.value_of("password").unwrap();
 
                 let root_ca_str: Option<String> = param.value_of("add-root-ca").map(|path| {
                     std::fs::read_to_string(path).expect("Failed reading root-ca certificate")
                 });
 
                 let mut client = get_client(&url, None, &root_ca_str);
-                let token = login(&mut client, username, password)?;
+                let token = match param.value_of("password") {
+                    Some(password) => login(&mut client, username, password)?,
+                    None => login_sso(&client, username)?,
+                };
 
                 store_login_data(url, &token, root_ca_str)?;
                 println!("Logged in.");
@@ -362,6 +488,7 @@ fn main() -> Result<(), String> {
                 let request = models::AccountRequest {
                     name: param.value_of("name").unwrap().to_string(),
                     custom_logo: None,
+                    auth_configs: None,
                 };
                 let result = client
                     .create_account(request)
@@ -416,11 +543,13 @@ fn main() -> Result<(), String> {
                         description: None,
                         input_image_name: None,
                         output_image_name: None,
+                        isvprodid: None,
                         isvsvn: None,
                         mem_size: None,
                         threads: None,
                         allowed_domains: domains,
                         advanced_settings: None,
+                        labels: None,
                     };
 
                     let result = client
@@ -451,6 +580,7 @@ fn main() -> Result<(), String> {
                         threads: *THREADS,
                         allowed_domains: domains,
                         advanced_settings: None,
+                        labels: None,
                     };
 
                     let result = client
@@ -465,7 +595,7 @@ fn main() -> Result<(), String> {
             ("list", Some(_)) => {
                 let client = get_cached_client()?;
                 let result = client
-                    .get_all_builds(None, None, None, None, None, None, None)
+                    .get_all_builds(None, None, None, None, None, None, None, None)
                     .map_err(|e| format!("get-all-builds failed: {}", e))?;
                 println!("{}", serde_json::to_string_pretty(&result).unwrap());
             }
@@ -588,6 +718,60 @@ fn main() -> Result<(), String> {
                     .map_err(|e| format!("get-zone-join-token failed: {}", e))?;
                 println!("{}", serde_json::to_string_pretty(&result).unwrap());
             }
+            ("create", Some(param)) => {
+                let client = get_cached_client()?;
+                let request = models::AddZoneRequest {
+                    name: Some(param.value_of("name").unwrap().to_string()),
+                    description: param.value_of("description").map(|s| s.to_string()),
+                };
+
+                let result = client
+                    .create_zone(request)
+                    .map_err(|e| format!("create-zone failed: {}", e))?;
+                println!("{}", serde_json::to_string_pretty(&result).unwrap());
+            }
+            ("update", Some(param)) => {
+                let zone_id = param.value_of("zone-id").unwrap();
+                let zone_uuid = Uuid::parse_str(zone_id).map_err(|e| {
+                    format!("update-zone UUID parsing failed for \"{}\": {}", zone_id, e)
+                })?;
+
+                let node_refresh_interval = param
+                    .value_of("node-refresh-interval")
+                    .map(|s| s.parse::<i64>())
+                    .transpose()
+                    .map_err(|e| format!("invalid node refresh interval: {}", e))?;
+                let node_renewal_threshold = param
+                    .value_of("node-renewal-threshold")
+                    .map(|s| s.parse::<i32>())
+                    .transpose()
+                    .map_err(|e| format!("invalid node renewal threshold: {}", e))?;
+
+                let client = get_cached_client()?;
+                let request = models::UpdateZoneRequest {
+                    name: param.value_of("name").map(|s| s.to_string()),
+                    description: param.value_of("description").map(|s| s.to_string()),
+                    node_refresh_interval,
+                    node_renewal_threshold,
+                };
+
+                let result = client
+                    .update_zone(zone_uuid, request)
+                    .map_err(|e| format!("update-zone failed: {}", e))?;
+                println!("{}", serde_json::to_string_pretty(&result).unwrap());
+            }
+            ("delete", Some(param)) => {
+                let zone_id = param.value_of("zone-id").unwrap();
+                let zone_uuid = Uuid::parse_str(zone_id).map_err(|e| {
+                    format!("delete-zone UUID parsing failed for \"{}\": {}", zone_id, e)
+                })?;
+
+                let client = get_cached_client()?;
+                client
+                    .delete_zone(zone_uuid)
+                    .map_err(|e| format!("delete-zone failed: {}", e))?;
+                println!("Delete succesful");
+            }
             _ => (),
         },
         ("node", Some(matches)) => match matches.subcommand() {
@@ -600,6 +784,63 @@ fn main() -> Result<(), String> {
             }
             _ => (),
         },
+        ("event", Some(matches)) => match matches.subcommand() {
+            ("list", Some(param)) => {
+                let mut query =
+                    EventQuery::new().sort_by(EventSortField::CreatedAt, SortOrder::Descending);
+                if let Some(actor_type) = param.value_of("actor-type") {
+                    let actor_type = models::EventActorType::from_str(&actor_type.to_uppercase())
+                        .map_err(|_| format!("invalid actor type: {}", actor_type))?;
+                    query = query.actor_type(actor_type);
+                }
+                if let Some(action_type) = param.value_of("action-type") {
+                    let action_type =
+                        models::EventActionType::from_str(&action_type.to_uppercase())
+                            .map_err(|_| format!("invalid action type: {}", action_type))?;
+                    query = query.action_type(action_type);
+                }
+                if let Some(severity) = param.value_of("severity") {
+                    let severity = models::EventSeverity::from_str(&severity.to_uppercase())
+                        .map_err(|_| format!("invalid severity: {}", severity))?;
+                    query = query.severity(severity);
+                }
+                if let Some(entity_id) = param.value_of("entity-id") {
+                    let entity_uuid = Uuid::parse_str(entity_id).map_err(|e| {
+                        format!(
+                            "list-events UUID parsing failed for \"{}\": {}",
+                            entity_id, e
+                        )
+                    })?;
+                    query = query.entity_id(entity_uuid);
+                }
+                if let Some(from) = param.value_of("from") {
+                    let from = from
+                        .parse::<i64>()
+                        .map_err(|e| format!("invalid from time: {}", e))?;
+                    query = query.from(from);
+                }
+                if let Some(to) = param.value_of("to") {
+                    let to = to
+                        .parse::<i64>()
+                        .map_err(|e| format!("invalid to time: {}", e))?;
+                    query = query.to(to);
+                }
+                let limit = param
+                    .value_of("limit")
+                    .unwrap()
+                    .parse::<usize>()
+                    .map_err(|e| format!("invalid limit: {}", e))?;
+
+                let client = get_cached_client()?;
+                let result = client
+                    .iter_events(query)
+                    .with_max_items(limit)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("get-all-events failed: {}", e))?;
+                println!("{}", serde_json::to_string_pretty(&result).unwrap());
+            }
+            _ => (),
+        },
         _ => (),
     }
     Ok(())
@@ -0,0 +1,2535 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+#![allow(unused_extern_crates)]
+extern crate url;
+extern crate uuid;
+
+use self::url::percent_encoding::utf8_percent_encode;
+use bytes::Bytes;
+use futures::future::{self, FutureExt, TryFutureExt};
+use futures::Future;
+use http_body_util::{BodyExt, Full};
+use hyper1::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use hyper1::{Method, Request, StatusCode};
+use hyper_util::client::legacy::connect::{Connect, HttpConnector};
+use hyper_util::client::legacy::ResponseFuture;
+use hyper_util::rt::TokioExecutor;
+use serde::de::DeserializeOwned;
+use serde_json;
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::io;
+use std::str;
+use std::sync::Arc;
+
+use client::{deserialize_config_checked, into_base_path, ClientInitError, ID_ENCODE_SET};
+use ApiError;
+use ApiFuture;
+use SimpleErrorType;
+
+use {
+    AccountsApiAsync, AppApiAsync, ApplicationConfigApiAsync, ApprovalRequestsApiAsync,
+    AuthApiAsync, BuildApiAsync, CertificateApiAsync, DatasetApiAsync, EventsApiAsync,
+    NodeApiAsync, RegistryApiAsync, SystemApiAsync, TaskApiAsync, ToolsApiAsync, UsersApiAsync,
+    WorkflowApiAsync, WorkflowFinalApiAsync, ZoneApiAsync,
+};
+
+use models;
+
+type HyperClient<C> = hyper_util::client::legacy::Client<C, Full<Bytes>>;
+
+/// Object-safe view of a pooled hyper client, so that `AsyncClient` does not
+/// have to be generic over the connector type.
+trait HttpService: Send + Sync {
+    fn request(&self, request: Request<Full<Bytes>>) -> ResponseFuture;
+}
+
+impl<C> HttpService for HyperClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn request(&self, request: Request<Full<Bytes>>) -> ResponseFuture {
+        HyperClient::request(self, request)
+    }
+}
+
+/// An asynchronous client that implements the `*ApiAsync` traits by making
+/// HTTP calls out to a server.
+///
+/// Requests are sent through a pooled hyper client, so connections are kept
+/// alive and reused, and any number of requests may be in flight at once.
+/// Cloning an `AsyncClient` is cheap and the clones share the same pool.
+pub struct AsyncClient {
+    hyper_client: Arc<dyn HttpService>,
+    base_path: String,
+    headers: HeaderMap,
+    use_new_paths: bool,
+}
+
+impl fmt::Debug for AsyncClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AsyncClient {{ base_path: {} }}", self.base_path)
+    }
+}
+
+impl Clone for AsyncClient {
+    fn clone(&self) -> Self {
+        AsyncClient {
+            hyper_client: self.hyper_client.clone(),
+            base_path: self.base_path.clone(),
+            headers: self.headers.clone(),
+            use_new_paths: self.use_new_paths,
+        }
+    }
+}
+
+impl AsyncClient {
+    /// Create an HTTP client.
+    ///
+    /// The returned futures must be polled from within a tokio runtime.
+    ///
+    /// # Arguments
+    /// * `base_path` - base path of the client API, i.e. "www.my-api-implementation.com"
+    pub fn try_new_http(base_path: &str) -> Result<AsyncClient, ClientInitError> {
+        Self::try_new_with_connector(base_path, Some("http"), HttpConnector::new())
+    }
+
+    /// Create a client with a custom connector, e.g. one which adds TLS.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_path` - base path of the client API, i.e. "www.my-api-implementation.com"
+    /// * `protocol` - Which protocol to use when constructing the request url, e.g. `Some("http")`
+    /// * `connector` - A hyper-util connector, e.g. `HttpConnector`
+    pub fn try_new_with_connector<C>(
+        base_path: &str,
+        protocol: Option<&'static str>,
+        connector: C,
+    ) -> Result<AsyncClient, ClientInitError>
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        let hyper_client =
+            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+
+        Ok(AsyncClient {
+            hyper_client: Arc::new(hyper_client),
+            base_path: into_base_path(base_path, protocol)?,
+            headers: HeaderMap::new(),
+            use_new_paths: false,
+        })
+    }
+
+    /// Constructor for creating an `AsyncClient` by passing in a pre-made
+    /// hyper-util client, e.g. one with custom pool settings.
+    pub fn try_new_with_hyper_client<C>(
+        hyper_client: HyperClient<C>,
+        base_path: &str,
+    ) -> Result<AsyncClient, ClientInitError>
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        Ok(AsyncClient {
+            hyper_client: Arc::new(hyper_client),
+            base_path: into_base_path(base_path, None)?,
+            headers: HeaderMap::new(),
+            use_new_paths: false,
+        })
+    }
+
+    pub fn headers(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    pub fn uses_new_paths(&self) -> bool {
+        self.use_new_paths
+    }
+
+    pub fn set_use_new_paths(&mut self, use_new_paths: bool) {
+        self.use_new_paths = use_new_paths;
+    }
+
+    pub fn with_new_paths(self) -> Self {
+        Self {
+            use_new_paths: true,
+            ..self
+        }
+    }
+
+    fn remap_operation_path<'a>(&self, operation: &'a str) -> Cow<'a, str> {
+        if !self.use_new_paths {
+            return Cow::Borrowed(operation);
+        }
+        if let Some(path_without_v1) = operation.strip_prefix("/v1/") {
+            return Cow::Owned(format!(
+                "/api/v1/confidential_computing/{}",
+                path_without_v1
+            ));
+        }
+        Cow::Borrowed(operation)
+    }
+
+    /// Build the request for an operation and return a future that resolves
+    /// once the whole response body has been received.
+    fn execute(
+        &self,
+        method: Method,
+        operation_path: &str,
+        query_string: String,
+        body: Option<Vec<u8>>,
+    ) -> impl Future<Output = Result<RawResponse, ApiError>> + Send + 'static {
+        let mut uri = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers.clone());
+            if body.is_some() {
+                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            }
+        }
+        let request = match builder.body(Full::new(Bytes::from(body.unwrap_or_default()))) {
+            Ok(request) => request,
+            Err(err) => {
+                return future::Either::Left(future::ready(Err(ApiError::new(
+                    format!("Unable to build request: {}", err),
+                    SimpleErrorType::Permanent,
+                ))))
+            }
+        };
+
+        future::Either::Right(
+            self.hyper_client
+                .request(request)
+                .map_err(|e| match is_timeout(&e) {
+                    true => ApiError::new_timeout(format!("Request timed out: {}", e)),
+                    // As in the sync client, the request may succeed if sent
+                    // again.
+                    false => ApiError::new(
+                        format!("No response received: {}", e),
+                        SimpleErrorType::Temporary,
+                    ),
+                })
+                .and_then(|response| {
+                    let (parts, body) = response.into_parts();
+                    body.collect().map(move |collected| match collected {
+                        Ok(collected) => Ok(RawResponse {
+                            status: parts.status,
+                            headers: parts.headers,
+                            body: collected.to_bytes(),
+                        }),
+                        Err(e) => Err(ApiError::new(
+                            format!("Failed to read response: {}", e),
+                            SimpleErrorType::Temporary,
+                        )),
+                    })
+                }),
+        )
+    }
+}
+
+/// A fully received response, before it is matched against the status code
+/// the operation expects.
+struct RawResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl RawResponse {
+    fn text(self, expected: u16) -> Result<String, ApiError> {
+        if self.status.as_u16() != expected {
+            return Err(self.into_error());
+        }
+        String::from_utf8(self.body.to_vec()).map_err(|e| {
+            ApiError::new(
+                format!("Response was not valid UTF8: {}", e),
+                SimpleErrorType::Temporary,
+            )
+        })
+    }
+
+    fn json<T: DeserializeOwned>(self, expected: u16) -> Result<T, ApiError> {
+        let body = self.text(expected)?;
+        serde_json::from_str::<T>(&body).map_err(|e| e.into())
+    }
+
+    fn empty(self, expected: u16) -> Result<(), ApiError> {
+        if self.status.as_u16() != expected {
+            return Err(self.into_error());
+        }
+        Ok(())
+    }
+
+    fn into_error(self) -> ApiError {
+        let err_type = match self.status.is_server_error() {
+            false => SimpleErrorType::Permanent,
+            true => SimpleErrorType::Temporary,
+        };
+        let message = format!(
+            "Unexpected response code {}:\n{:?}\n\n{}",
+            self.status.as_u16(),
+            self.headers,
+            match str::from_utf8(&self.body) {
+                Ok(body) => Cow::from(body),
+                Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
+            }
+        );
+        let headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_owned(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        ApiError::new(message, err_type).with_response(
+            self.status.as_u16(),
+            headers,
+            self.body.to_vec(),
+        )
+    }
+}
+
+impl AccountsApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_account(
+        &self,
+        param_body: models::AccountRequest,
+    ) -> ApiFuture<models::Account, ApiError> {
+        let operation_path = "/v1/accounts";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Account>(200)),
+        )
+    }
+
+    fn delete_account(&self, param_account_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_account(&self, param_account_id: uuid::Uuid) -> ApiFuture<models::Account, ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Account>(200)),
+        )
+    }
+
+    fn get_accounts(&self) -> ApiFuture<models::AccountListResponse, ApiError> {
+        let operation_path = "/v1/accounts";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::AccountListResponse>(200)),
+        )
+    }
+
+    fn select_account(&self, param_account_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/select_account/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn update_account(
+        &self,
+        param_account_id: uuid::Uuid,
+        param_body: models::AccountUpdateRequest,
+    ) -> ApiFuture<models::Account, ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Account>(200)),
+        )
+    }
+}
+
+impl AppApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn add_application(&self, param_body: models::AppRequest) -> ApiFuture<models::App, ApiError> {
+        let operation_path = "/v1/apps";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::App>(200)),
+        )
+    }
+
+    fn delete_app(&self, param_app_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_apps(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_all_search: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllAppsResponse, ApiError> {
+        let operation_path = "/v1/apps";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllAppsResponse>(200)),
+        )
+    }
+
+    fn get_app(&self, param_app_id: uuid::Uuid) -> ApiFuture<models::App, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::App>(200)),
+        )
+    }
+
+    fn get_app_certificate(
+        &self,
+        param_node_id: uuid::Uuid,
+        param_app_id: uuid::Uuid,
+    ) -> ApiFuture<models::Certificate, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}/node/{node_id}/certificate",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET),
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Certificate>(200)),
+        )
+    }
+
+    fn get_app_node_certificate_details(
+        &self,
+        param_node_id: uuid::Uuid,
+        param_app_id: uuid::Uuid,
+    ) -> ApiFuture<models::CertificateDetails, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}/node/{node_id}/certificate-details",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET),
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::CertificateDetails>(200)),
+        )
+    }
+
+    fn get_apps_unique_labels(&self) -> ApiFuture<models::LabelsCount, ApiError> {
+        let operation_path = "/v1/apps/unique_labels/count";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::LabelsCount>(200)),
+        )
+    }
+
+    fn update_app(
+        &self,
+        param_app_id: uuid::Uuid,
+        param_body: models::AppBodyUpdateRequest,
+    ) -> ApiFuture<models::App, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::App>(200)),
+        )
+    }
+}
+
+impl ApplicationConfigApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_application_config(
+        &self,
+        param_body: models::ApplicationConfig,
+    ) -> ApiFuture<models::ApplicationConfigResponse, ApiError> {
+        let operation_path = "/v1/app_configs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ApplicationConfigResponse>(200)),
+        )
+    }
+
+    fn delete_application_config(&self, param_config_id: String) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_application_configs(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_image_id: Option<uuid::Uuid>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllApplicationConfigsResponse, ApiError> {
+        let operation_path = "/v1/app_configs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(image_id) = param_image_id {
+            query_string.append_pair("image_id", &image_id.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllApplicationConfigsResponse>(200)),
+        )
+    }
+
+    fn get_application_config(
+        &self,
+        param_config_id: String,
+    ) -> ApiFuture<models::ApplicationConfigResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::ApplicationConfigResponse>(200)),
+        )
+    }
+
+    fn get_runtime_application_config(
+        &self,
+        expected_hash: &[u8; 32],
+    ) -> ApiFuture<models::RuntimeAppConfig, ApiError> {
+        let operation_path = "/v1/runtime/app_configs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let expected_hash = *expected_hash;
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(move |response| {
+                    let raw_config = response?.text(200)?;
+                    deserialize_config_checked(&raw_config, &expected_hash)
+                }),
+        )
+    }
+
+    fn get_specific_runtime_application_config(
+        &self,
+        param_config_id: String,
+    ) -> ApiFuture<models::RuntimeAppConfig, ApiError> {
+        let operation_path = format!(
+            "/v1/runtime/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::RuntimeAppConfig>(200)),
+        )
+    }
+
+    fn update_application_config(
+        &self,
+        param_config_id: String,
+        param_body: models::UpdateApplicationConfigRequest,
+    ) -> ApiFuture<models::ApplicationConfigResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ApplicationConfigResponse>(200)),
+        )
+    }
+}
+
+impl ApprovalRequestsApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn approve_approval_request(
+        &self,
+        param_request_id: uuid::Uuid,
+        param_body: Option<models::ApproveRequest>,
+    ) -> ApiFuture<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/approve",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = param_body.map(|ref body| {
+            serde_json::to_string(body)
+                .expect("impossible to fail to serialize")
+                .into_bytes()
+        });
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ApprovalRequest>(200)),
+        )
+    }
+
+    fn create_approval_request(
+        &self,
+        param_body: models::ApprovalRequestRequest,
+    ) -> ApiFuture<models::ApprovalRequest, ApiError> {
+        let operation_path = "/v1/approval_requests";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ApprovalRequest>(201)),
+        )
+    }
+
+    fn delete_approval_request(&self, param_request_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn deny_approval_request(
+        &self,
+        param_request_id: uuid::Uuid,
+        param_body: Option<models::DenyRequest>,
+    ) -> ApiFuture<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/deny",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = param_body.map(|ref body| {
+            serde_json::to_string(body)
+                .expect("impossible to fail to serialize")
+                .into_bytes()
+        });
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ApprovalRequest>(200)),
+        )
+    }
+
+    fn get_all_approval_requests(
+        &self,
+        param_requester: Option<uuid::Uuid>,
+        param_reviewer: Option<uuid::Uuid>,
+        param_subject: Option<uuid::Uuid>,
+        param_status: Option<String>,
+        param_all_search: Option<String>,
+        param_sort_by: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllApprovalRequests, ApiError> {
+        let operation_path = "/v1/approval_requests";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(requester) = param_requester {
+            query_string.append_pair("requester", &requester.to_string());
+        }
+        if let Some(reviewer) = param_reviewer {
+            query_string.append_pair("reviewer", &reviewer.to_string());
+        }
+        if let Some(subject) = param_subject {
+            query_string.append_pair("subject", &subject.to_string());
+        }
+        if let Some(status) = param_status {
+            query_string.append_pair("status", &status.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllApprovalRequests>(200)),
+        )
+    }
+
+    fn get_approval_request(
+        &self,
+        param_request_id: uuid::Uuid,
+    ) -> ApiFuture<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::ApprovalRequest>(200)),
+        )
+    }
+
+    fn get_approval_request_result(
+        &self,
+        param_request_id: uuid::Uuid,
+    ) -> ApiFuture<models::ApprovableResult, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/result",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::ApprovableResult>(200)),
+        )
+    }
+}
+
+impl AuthApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn auth_discover(
+        &self,
+        param_body: models::AuthDiscoverRequest,
+    ) -> ApiFuture<models::AuthDiscoverResponse, ApiError> {
+        let operation_path = "/v1/sys/auth/discover";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::AuthDiscoverResponse>(200)),
+        )
+    }
+
+    fn authenticate_user(
+        &self,
+        param_body: Option<models::AuthRequest>,
+    ) -> ApiFuture<models::AuthResponse, ApiError> {
+        let operation_path = "/v1/sys/auth";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = param_body.map(|ref body| {
+            serde_json::to_string(body)
+                .expect("impossible to fail to serialize")
+                .into_bytes()
+        });
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::AuthResponse>(200)),
+        )
+    }
+
+    fn refresh_session(&self) -> ApiFuture<models::RefreshResponse, ApiError> {
+        let operation_path = "/v1/sys/session/refresh";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::RefreshResponse>(200)),
+        )
+    }
+}
+
+impl BuildApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn convert_app_build(
+        &self,
+        param_body: models::ConvertAppBuildRequest,
+    ) -> ApiFuture<models::Build, ApiError> {
+        let operation_path = "/v1/builds/convert-app";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Build>(200)),
+        )
+    }
+
+    fn create_build(
+        &self,
+        param_body: models::CreateBuildRequest,
+    ) -> ApiFuture<models::Build, ApiError> {
+        let operation_path = "/v1/builds";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Build>(200)),
+        )
+    }
+
+    fn delete_build(&self, param_build_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_builds(
+        &self,
+        param_all_search: Option<String>,
+        param_docker_image_name: Option<String>,
+        param_config_id: Option<String>,
+        param_deployed_status: Option<String>,
+        param_status: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllBuildsResponse, ApiError> {
+        let operation_path = "/v1/builds";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(docker_image_name) = param_docker_image_name {
+            query_string.append_pair("docker_image_name", &docker_image_name.to_string());
+        }
+        if let Some(config_id) = param_config_id {
+            query_string.append_pair("config_id", &config_id.to_string());
+        }
+        if let Some(deployed_status) = param_deployed_status {
+            query_string.append_pair("deployed_status", &deployed_status.to_string());
+        }
+        if let Some(status) = param_status {
+            query_string.append_pair("status", &status.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllBuildsResponse>(200)),
+        )
+    }
+
+    fn get_build(&self, param_build_id: uuid::Uuid) -> ApiFuture<models::Build, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Build>(200)),
+        )
+    }
+
+    fn get_build_deployments(
+        &self,
+        param_build_id: uuid::Uuid,
+        param_status: Option<String>,
+        param_all_search: Option<String>,
+        param_sort_by: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllBuildDeploymentsResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/deployments/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(status) = param_status {
+            query_string.append_pair("status", &status.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllBuildDeploymentsResponse>(200)),
+        )
+    }
+
+    fn update_build(
+        &self,
+        param_build_id: uuid::Uuid,
+        param_body: models::BuildUpdateRequest,
+    ) -> ApiFuture<models::Build, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Build>(200)),
+        )
+    }
+}
+
+impl CertificateApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn get_certificate(
+        &self,
+        param_cert_id: uuid::Uuid,
+    ) -> ApiFuture<models::Certificate, ApiError> {
+        let operation_path = format!(
+            "/v1/certificates/{cert_id}",
+            cert_id = utf8_percent_encode(&param_cert_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Certificate>(200)),
+        )
+    }
+
+    fn new_certificate(
+        &self,
+        param_body: models::NewCertificateRequest,
+    ) -> ApiFuture<models::TaskResult, ApiError> {
+        let operation_path = "/v1/certificates";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::TaskResult>(200)),
+        )
+    }
+}
+
+impl DatasetApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_dataset(
+        &self,
+        param_body: models::CreateDatasetRequest,
+    ) -> ApiFuture<models::Dataset, ApiError> {
+        let operation_path = "/v1/datasets";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Dataset>(200)),
+        )
+    }
+
+    fn delete_dataset(&self, param_dataset_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_datasets(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllDatasetsResponse, ApiError> {
+        let operation_path = "/v1/datasets";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllDatasetsResponse>(200)),
+        )
+    }
+
+    fn get_dataset(&self, param_dataset_id: uuid::Uuid) -> ApiFuture<models::Dataset, ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Dataset>(200)),
+        )
+    }
+
+    fn update_dataset(
+        &self,
+        param_dataset_id: uuid::Uuid,
+        param_body: models::DatasetUpdateRequest,
+    ) -> ApiFuture<models::Dataset, ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Dataset>(200)),
+        )
+    }
+}
+
+impl EventsApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn get_all_events(
+        &self,
+        param_actor_type: Option<String>,
+        param_action_type: Option<String>,
+        param_severity: Option<String>,
+        param_entity_id: Option<uuid::Uuid>,
+        param_from: Option<i64>,
+        param_to: Option<i64>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllEventsResponse, ApiError> {
+        let operation_path = "/v1/events";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(actor_type) = param_actor_type {
+            query_string.append_pair("actor_type", &actor_type.to_string());
+        }
+        if let Some(action_type) = param_action_type {
+            query_string.append_pair("action_type", &action_type.to_string());
+        }
+        if let Some(severity) = param_severity {
+            query_string.append_pair("severity", &severity.to_string());
+        }
+        if let Some(entity_id) = param_entity_id {
+            query_string.append_pair("entity_id", &entity_id.to_string());
+        }
+        if let Some(from) = param_from {
+            query_string.append_pair("from", &from.to_string());
+        }
+        if let Some(to) = param_to {
+            query_string.append_pair("to", &to.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllEventsResponse>(200)),
+        )
+    }
+}
+
+impl NodeApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn deactivate_node(&self, param_node_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/deactivate",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_nodes(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_sgx_version: Option<String>,
+        param_all_search: Option<String>,
+        param_status: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllNodesResponse, ApiError> {
+        let operation_path = "/v1/nodes";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(sgx_version) = param_sgx_version {
+            query_string.append_pair("sgx_version", &sgx_version.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(status) = param_status {
+            query_string.append_pair("status", &status.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllNodesResponse>(200)),
+        )
+    }
+
+    fn get_node(&self, param_node_id: uuid::Uuid) -> ApiFuture<models::Node, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Node>(200)),
+        )
+    }
+
+    fn get_node_certificate(
+        &self,
+        param_node_id: uuid::Uuid,
+    ) -> ApiFuture<models::Certificate, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/certificate",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Certificate>(200)),
+        )
+    }
+
+    fn get_node_certificate_details(
+        &self,
+        param_node_id: uuid::Uuid,
+    ) -> ApiFuture<models::CertificateDetails, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/certificate-details",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::CertificateDetails>(200)),
+        )
+    }
+
+    fn get_nodes_unique_labels(&self) -> ApiFuture<models::LabelsCount, ApiError> {
+        let operation_path = "/v1/nodes/unique_labels/count";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::LabelsCount>(200)),
+        )
+    }
+
+    fn provision_node(
+        &self,
+        param_body: models::NodeProvisionRequest,
+    ) -> ApiFuture<models::TaskResult, ApiError> {
+        let operation_path = "/v1/nodes";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::TaskResult>(200)),
+        )
+    }
+
+    fn update_node(
+        &self,
+        param_node_id: uuid::Uuid,
+        param_body: models::NodeUpdateRequest,
+    ) -> ApiFuture<models::Node, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Node>(200)),
+        )
+    }
+
+    fn update_node_status(
+        &self,
+        param_body: models::NodeStatusRequest,
+    ) -> ApiFuture<models::NodeStatusResponse, ApiError> {
+        let operation_path = "/v1/node/status";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::NodeStatusResponse>(200)),
+        )
+    }
+}
+
+impl RegistryApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_registry(
+        &self,
+        param_registry_request: models::RegistryRequest,
+    ) -> ApiFuture<models::Registry, ApiError> {
+        let operation_path = "/v1/registry";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_registry_request)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Registry>(200)),
+        )
+    }
+
+    fn delete_registry(&self, param_registry_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_registries(&self) -> ApiFuture<Vec<models::Registry>, ApiError> {
+        let operation_path = "/v1/registry";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<Vec<models::Registry>>(200)),
+        )
+    }
+
+    fn get_registry(&self, param_registry_id: uuid::Uuid) -> ApiFuture<models::Registry, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Registry>(200)),
+        )
+    }
+
+    fn get_registry_for_app(
+        &self,
+        param_app_id: uuid::Uuid,
+    ) -> ApiFuture<models::AppRegistryResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/app/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::AppRegistryResponse>(200)),
+        )
+    }
+
+    fn get_registry_for_image(
+        &self,
+        param_image_name: String,
+    ) -> ApiFuture<models::ImageRegistryResponse, ApiError> {
+        let operation_path = "/v1/image/registry";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        query_string.append_pair("image_name", &param_image_name.to_string());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::ImageRegistryResponse>(200)),
+        )
+    }
+
+    fn update_registry(
+        &self,
+        param_registry_id: uuid::Uuid,
+        param_body: models::UpdateRegistryRequest,
+    ) -> ApiFuture<models::Registry, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Registry>(200)),
+        )
+    }
+}
+
+impl SystemApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn get_manager_version(&self) -> ApiFuture<models::VersionResponse, ApiError> {
+        let operation_path = "/v1/sys/version";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::VersionResponse>(200)),
+        )
+    }
+}
+
+impl TaskApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn get_all_tasks(
+        &self,
+        param_task_type: Option<String>,
+        param_status: Option<String>,
+        param_requester: Option<String>,
+        param_approver: Option<String>,
+        param_all_search: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+        param_base_filters: Option<String>,
+    ) -> ApiFuture<models::GetAllTasksResponse, ApiError> {
+        let operation_path = "/v1/tasks";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(task_type) = param_task_type {
+            query_string.append_pair("task_type", &task_type.to_string());
+        }
+        if let Some(status) = param_status {
+            query_string.append_pair("status", &status.to_string());
+        }
+        if let Some(requester) = param_requester {
+            query_string.append_pair("requester", &requester.to_string());
+        }
+        if let Some(approver) = param_approver {
+            query_string.append_pair("approver", &approver.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        if let Some(base_filters) = param_base_filters {
+            query_string.append_pair("base_filters", &base_filters.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllTasksResponse>(200)),
+        )
+    }
+
+    fn get_task(&self, param_task_id: uuid::Uuid) -> ApiFuture<models::Task, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Task>(200)),
+        )
+    }
+
+    fn get_task_status(
+        &self,
+        param_task_id: uuid::Uuid,
+    ) -> ApiFuture<models::TaskResult, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/status/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::TaskResult>(200)),
+        )
+    }
+
+    fn update_task(
+        &self,
+        param_task_id: uuid::Uuid,
+        param_body: models::TaskUpdateRequest,
+    ) -> ApiFuture<models::TaskResult, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::TaskResult>(200)),
+        )
+    }
+}
+
+impl ToolsApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn convert_app(
+        &self,
+        param_body: models::ConversionRequest,
+    ) -> ApiFuture<models::ConversionResponse, ApiError> {
+        let operation_path = "/v1/tools/converter/convert-app";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ConversionResponse>(200)),
+        )
+    }
+}
+
+impl UsersApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn accept_terms_and_conditions(&self) -> ApiFuture<(), ApiError> {
+        let operation_path = "/v1/users/terms_and_conditions";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::PATCH, operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn change_password(
+        &self,
+        param_body: models::PasswordChangeRequest,
+    ) -> ApiFuture<(), ApiError> {
+        let operation_path = "/v1/users/change_password";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn confirm_email(
+        &self,
+        param_body: models::ConfirmEmailRequest,
+    ) -> ApiFuture<models::ConfirmEmailResponse, ApiError> {
+        let operation_path = "/v1/users/confirm_email";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ConfirmEmailResponse>(200)),
+        )
+    }
+
+    fn create_user(&self, param_body: models::SignupRequest) -> ApiFuture<models::User, ApiError> {
+        let operation_path = "/v1/users";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::User>(201)),
+        )
+    }
+
+    fn delete_user_account(&self, param_user_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn delete_user_from_account(&self, param_user_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/accounts",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn forgot_password(
+        &self,
+        param_body: models::ForgotPasswordRequest,
+    ) -> ApiFuture<(), ApiError> {
+        let operation_path = "/v1/users/forgot_password";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_users(
+        &self,
+        param_all_search: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllUsersResponse, ApiError> {
+        let operation_path = "/v1/users";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllUsersResponse>(200)),
+        )
+    }
+
+    fn get_logged_in_user(&self) -> ApiFuture<models::User, ApiError> {
+        let operation_path = "/v1/user";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::User>(200)),
+        )
+    }
+
+    fn get_user(&self, param_user_id: uuid::Uuid) -> ApiFuture<models::User, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::User>(200)),
+        )
+    }
+
+    fn invite_user(
+        &self,
+        param_body: models::InviteUserRequest,
+    ) -> ApiFuture<models::User, ApiError> {
+        let operation_path = "/v1/users/invite";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::User>(201)),
+        )
+    }
+
+    fn process_invitations(
+        &self,
+        param_body: models::ProcessInviteRequest,
+    ) -> ApiFuture<(), ApiError> {
+        let operation_path = "/v1/users/process_invite";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn resend_confirm_email(&self) -> ApiFuture<(), ApiError> {
+        let operation_path = "/v1/users/resend_confirm_email";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn resend_invitation(&self, param_user_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/resend_invite",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn reset_password(
+        &self,
+        param_user_id: uuid::Uuid,
+        param_body: models::PasswordResetRequest,
+    ) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/reset_password",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), body)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn update_user(
+        &self,
+        param_user_id: uuid::Uuid,
+        param_body: models::UpdateUserRequest,
+    ) -> ApiFuture<models::User, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::User>(200)),
+        )
+    }
+
+    fn validate_password_reset_token(
+        &self,
+        param_user_id: uuid::Uuid,
+        param_body: models::ValidateTokenRequest,
+    ) -> ApiFuture<models::ValidateTokenResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/validate_token",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::ValidateTokenResponse>(200)),
+        )
+    }
+}
+
+impl WorkflowApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_workflow_graph(
+        &self,
+        param_body: models::CreateWorkflowGraph,
+    ) -> ApiFuture<models::WorkflowGraph, ApiError> {
+        let operation_path = "/v1/workflows/draft/graphs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::WorkflowGraph>(200)),
+        )
+    }
+
+    fn delete_workflow_graph(&self, param_graph_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_workflow_graphs(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_all_search: Option<String>,
+        param_parent_graph_id: Option<String>,
+        param_sort_by: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllWorkflowGraphsResponse, ApiError> {
+        let operation_path = "/v1/workflows/draft/graphs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(parent_graph_id) = param_parent_graph_id {
+            query_string.append_pair("parent_graph_id", &parent_graph_id.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllWorkflowGraphsResponse>(200)),
+        )
+    }
+
+    fn get_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+    ) -> ApiFuture<models::WorkflowGraph, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::WorkflowGraph>(200)),
+        )
+    }
+
+    fn update_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+        param_body: models::UpdateWorkflowGraph,
+    ) -> ApiFuture<models::WorkflowGraph, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PUT, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::WorkflowGraph>(200)),
+        )
+    }
+}
+
+impl WorkflowFinalApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_final_workflow_graph(
+        &self,
+        param_body: models::CreateFinalWorkflowGraph,
+    ) -> ApiFuture<models::FinalWorkflow, ApiError> {
+        let operation_path = "/v1/workflows/final/graphs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::FinalWorkflow>(200)),
+        )
+    }
+
+    fn delete_final_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+        param_version: String,
+    ) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}/{version}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET),
+            version = utf8_percent_encode(&param_version.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_all_final_workflow_graphs(
+        &self,
+        param_name: Option<String>,
+        param_description: Option<String>,
+        param_all_search: Option<String>,
+        param_sort_by: Option<String>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllFinalWorkflowGraphsResponse, ApiError> {
+        let operation_path = "/v1/workflows/final/graphs";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        if let Some(name) = param_name {
+            query_string.append_pair("name", &name.to_string());
+        }
+        if let Some(description) = param_description {
+            query_string.append_pair("description", &description.to_string());
+        }
+        if let Some(all_search) = param_all_search {
+            query_string.append_pair("all_search", &all_search.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::GetAllFinalWorkflowGraphsResponse>(200)),
+        )
+    }
+
+    fn get_final_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+        param_version: String,
+    ) -> ApiFuture<models::VersionInFinalWorkflow, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}/{version}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET),
+            version = utf8_percent_encode(&param_version.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::VersionInFinalWorkflow>(200)),
+        )
+    }
+
+    fn get_full_final_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+    ) -> ApiFuture<models::FinalWorkflow, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::FinalWorkflow>(200)),
+        )
+    }
+
+    fn update_final_workflow_graph(
+        &self,
+        param_graph_id: uuid::Uuid,
+        param_body: models::CreateWorkflowVersionRequest,
+    ) -> ApiFuture<models::VersionInFinalWorkflow, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::VersionInFinalWorkflow>(200)),
+        )
+    }
+}
+
+impl ZoneApiAsync for AsyncClient {
+    type Error = ApiError;
+
+    fn create_zone(&self, param_body: models::AddZoneRequest) -> ApiFuture<models::Zone, ApiError> {
+        let operation_path = "/v1/zones";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::POST, operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Zone>(200)),
+        )
+    }
+
+    fn delete_zone(&self, param_zone_id: uuid::Uuid) -> ApiFuture<(), ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::DELETE, &operation_path, query_string.finish(), None)
+                .map(|response| response?.empty(204)),
+        )
+    }
+
+    fn get_zone(&self, param_zone_id: uuid::Uuid) -> ApiFuture<models::Zone, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::Zone>(200)),
+        )
+    }
+
+    fn get_zone_join_token(
+        &self,
+        param_zone_id: uuid::Uuid,
+    ) -> ApiFuture<models::ZoneJoinToken, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}/token",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, &operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<models::ZoneJoinToken>(200)),
+        )
+    }
+
+    fn get_zones(&self) -> ApiFuture<Vec<models::Zone>, ApiError> {
+        let operation_path = "/v1/zones";
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        Box::pin(
+            self.execute(Method::GET, operation_path, query_string.finish(), None)
+                .map(|response| response?.json::<Vec<models::Zone>>(200)),
+        )
+    }
+
+    fn update_zone(
+        &self,
+        param_zone_id: uuid::Uuid,
+        param_body: models::UpdateZoneRequest,
+    ) -> ApiFuture<models::Zone, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        // Body parameter
+        let body = Some(
+            serde_json::to_string(&param_body)
+                .expect("impossible to fail to serialize")
+                .into_bytes(),
+        );
+        Box::pin(
+            self.execute(Method::PATCH, &operation_path, query_string.finish(), body)
+                .map(|response| response?.json::<models::Zone>(200)),
+        )
+    }
+}
+
+/// Whether a request failed because an I/O operation timed out.
+fn is_timeout(err: &(dyn error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<io::Error>() {
+            if err.kind() == io::ErrorKind::TimedOut {
+                return true;
+            }
+        }
+        if let Some(err) = err.downcast_ref::<hyper1::Error>() {
+            if err.is_timeout() {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{models, AsyncClient, SystemApiAsync, ZoneApiAsync};
+    use futures::future;
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_concurrent_requests_share_pool() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"4.0.0"}"#)
+            .expect(8)
+            .create();
+
+        let client = AsyncClient::try_new_http(&server.url()).unwrap();
+        let versions = runtime().block_on(future::join_all(
+            (0..8).map(|_| client.get_manager_version()),
+        ));
+
+        mock.assert();
+        for version in versions {
+            assert_eq!(version.unwrap().version, Some("4.0.0".to_owned()));
+        }
+    }
+
+    #[test]
+    fn test_unexpected_status_is_an_error() {
+        let mut server = mockito::Server::new();
+        let zone_id = uuid::Uuid::new_v4();
+        let mock = server
+            .mock("GET", format!("/v1/zones/{}", zone_id).as_str())
+            .with_status(503)
+            .create();
+
+        let client = AsyncClient::try_new_http(&server.url()).unwrap();
+        let result = runtime().block_on(client.get_zone(zone_id));
+
+        mock.assert();
+        let err = result.unwrap_err();
+        assert_eq!(err.error_type(), crate::SimpleErrorType::Temporary);
+    }
+
+    #[test]
+    fn test_connection_failure_is_temporary() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let client = AsyncClient::try_new_http(&url).unwrap();
+        let err = runtime()
+            .block_on(client.get_manager_version())
+            .unwrap_err();
+        assert_eq!(err.error_type(), crate::SimpleErrorType::Temporary);
+        assert!(!err.is_timeout());
+    }
+
+    #[test]
+    fn test_use_new_paths() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/api/v1/confidential_computing/zones")
+            .with_status(200)
+            .with_body("[]")
+            .create();
+
+        let client = AsyncClient::try_new_http(&server.url())
+            .unwrap()
+            .with_new_paths();
+        let zones: Vec<models::Zone> = runtime().block_on(client.get_zones()).unwrap();
+
+        mock.assert();
+        assert!(zones.is_empty());
+    }
+}
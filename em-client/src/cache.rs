@@ -0,0 +1,1338 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! A caching wrapper around any implementation of `Api`.
+//!
+//! `CachingApi` caches the results of the operations that get a single object
+//! by ID, such as `get_app`, `get_build`, `get_node` and `get_zone`. Entries
+//! expire after a TTL, and the least recently used entry is evicted once the
+//! cache is full. Errors are never cached.
+//!
+//! Updating or deleting an object through the wrapper invalidates its entry,
+//! whether or not the call succeeds, and so does updating a task for the
+//! build, node or certificate it belongs to. Tasks themselves are not cached,
+//! so they can be polled. Selecting an account or authenticating clears the
+//! cache. Changes made by other clients are only seen once the entry expires;
+//! call `invalidate_all` when in doubt.
+//!
+//! ```no_run
+//! use em_client::cache::CachingApi;
+//! use em_client::Api;
+//! use std::time::Duration;
+//!
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let api = CachingApi::new(client).with_ttl(Duration::from_secs(10));
+//! let app_id = "f0c7d9c2-5bb4-4ee4-a3c5-5b2bd9bb5ea2".parse().unwrap();
+//! let app = api.get_app(app_id);
+//! let again = api.get_app(app_id); // served from the cache
+//! println!("{:?}", api.stats());
+//! ```
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use models;
+use uuid;
+use {
+    AccountsApi, Api, AppApi, ApplicationConfigApi, ApprovalRequestsApi, AuthApi, BuildApi,
+    CertificateApi, DatasetApi, EventsApi, NodeApi, RegistryApi, SystemApi, TaskApi, ToolsApi,
+    UsersApi, WorkflowApi, WorkflowFinalApi, ZoneApi,
+};
+
+/// How long entries are cached unless configured otherwise.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+/// Number of cached entries unless configured otherwise.
+pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+/// The kind of object and its ID, e.g. `("build", "<build id>")`. Versions of
+/// final workflow graphs are keyed by `"<graph id>/<version>"`.
+type CacheKey = (&'static str, String);
+
+fn key(kind: &'static str, id: &dyn fmt::Display) -> CacheKey {
+    (kind, id.to_string())
+}
+
+struct Entry {
+    value: Box<dyn Any + Send>,
+    expires: Instant,
+    last_used: u64,
+}
+
+/// Calls of the inner `Api` in progress for a key.
+struct InFlight {
+    calls: usize,
+    /// Bumped whenever the key is invalidated, so that a result fetched
+    /// before the invalidation is not cached after it.
+    generation: u64,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<CacheKey, Entry>,
+    in_flight: HashMap<CacheKey, InFlight>,
+    clock: u64,
+    stats: CacheStats,
+}
+
+/// Counters of a `CachingApi`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// Calls answered from the cache.
+    pub hits: u64,
+    /// Calls forwarded because the entry was missing or expired.
+    pub misses: u64,
+    /// Entries evicted to stay within the size bound.
+    pub evictions: u64,
+    /// Entries removed by updates, deletes and `invalidate_all`.
+    pub invalidations: u64,
+    /// Entries currently cached, including expired ones not yet removed.
+    pub entries: usize,
+}
+
+/// Caches the `get_*` operations of the wrapped `Api`; see the module
+/// documentation.
+pub struct CachingApi<A> {
+    inner: A,
+    ttl: Duration,
+    max_entries: usize,
+    cache: Mutex<Cache>,
+}
+
+impl<A: Api> CachingApi<A> {
+    pub fn new(inner: A) -> Self {
+        CachingApi {
+            inner,
+            ttl: DEFAULT_TTL,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Bound the number of cached entries. A bound of 0 disables caching.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let cache = self.lock();
+        CacheStats {
+            entries: cache.entries.len(),
+            ..cache.stats
+        }
+    }
+
+    pub fn reset_stats(&self) {
+        self.lock().stats = CacheStats::default();
+    }
+
+    /// Remove all entries.
+    pub fn invalidate_all(&self) {
+        self.invalidate(|_| true);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn invalidate<F: Fn(&CacheKey) -> bool>(&self, matches: F) {
+        let mut cache = self.lock();
+        let before = cache.entries.len();
+        cache.entries.retain(|k, _| !matches(k));
+        cache.stats.invalidations += (before - cache.entries.len()) as u64;
+        for (_, in_flight) in cache.in_flight.iter_mut().filter(|&(k, _)| matches(k)) {
+            in_flight.generation += 1;
+        }
+    }
+
+    /// Return the cached value for `key`, or call `f` and cache its result.
+    /// The lock is not held while `f` runs, so concurrent misses for the same
+    /// key all reach the inner `Api`. The result is not cached if the key is
+    /// invalidated while `f` runs.
+    fn cached<T, F>(&self, key: CacheKey, f: F) -> Result<T, A::Error>
+    where
+        T: Clone + Send + 'static,
+        F: FnOnce(&A) -> Result<T, A::Error>,
+    {
+        let generation = {
+            let mut cache = self.lock();
+            let now = Instant::now();
+            cache.clock += 1;
+            let clock = cache.clock;
+            let hit = match cache.entries.get_mut(&key) {
+                Some(entry) if entry.expires > now => {
+                    entry.last_used = clock;
+                    entry.value.downcast_ref::<T>().cloned()
+                }
+                _ => None,
+            };
+            match hit {
+                Some(value) => {
+                    cache.stats.hits += 1;
+                    return Ok(value);
+                }
+                None => cache.stats.misses += 1,
+            }
+            let in_flight = cache.in_flight.entry(key.clone()).or_insert(InFlight {
+                calls: 0,
+                generation: 0,
+            });
+            in_flight.calls += 1;
+            in_flight.generation
+        };
+
+        let result = f(&self.inner);
+        let mut cache = self.lock();
+        let invalidated = match cache.in_flight.get_mut(&key) {
+            Some(in_flight) => {
+                in_flight.calls -= 1;
+                let invalidated = in_flight.generation != generation;
+                if in_flight.calls == 0 {
+                    cache.in_flight.remove(&key);
+                }
+                invalidated
+            }
+            None => true,
+        };
+        let value = result?;
+        if invalidated || self.max_entries == 0 {
+            return Ok(value);
+        }
+        let now = Instant::now();
+        if !cache.entries.contains_key(&key) && cache.entries.len() >= self.max_entries {
+            cache.entries.retain(|_, entry| entry.expires > now);
+            while cache.entries.len() >= self.max_entries {
+                let lru = cache
+                    .entries
+                    .iter()
+                    .min_by_key(|&(_, entry)| entry.last_used)
+                    .map(|(k, _)| k.clone());
+                if let Some(lru) = lru {
+                    cache.entries.remove(&lru);
+                    cache.stats.evictions += 1;
+                }
+            }
+        }
+        cache.clock += 1;
+        let entry = Entry {
+            value: Box::new(value.clone()),
+            expires: now + self.ttl,
+            last_used: cache.clock,
+        };
+        cache.entries.insert(key, entry);
+        Ok(value)
+    }
+}
+
+impl<A> fmt::Debug for CachingApi<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CachingApi")
+            .field("ttl", &self.ttl)
+            .field("max_entries", &self.max_entries)
+            .finish()
+    }
+}
+
+impl<A: Api> AccountsApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_account(&self, body: models::AccountRequest) -> Result<models::Account, Self::Error> {
+        Api::create_account(&self.inner, body)
+    }
+
+    fn delete_account(&self, account_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("account", &account_id);
+        let result = Api::delete_account(&self.inner, account_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_account(&self, account_id: uuid::Uuid) -> Result<models::Account, Self::Error> {
+        self.cached(key("account", &account_id), |a| {
+            Api::get_account(a, account_id)
+        })
+    }
+
+    fn get_accounts(&self) -> Result<models::AccountListResponse, Self::Error> {
+        Api::get_accounts(&self.inner)
+    }
+
+    fn select_account(&self, account_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let result = Api::select_account(&self.inner, account_id);
+        self.invalidate_all();
+        result
+    }
+
+    fn update_account(
+        &self,
+        account_id: uuid::Uuid,
+        body: models::AccountUpdateRequest,
+    ) -> Result<models::Account, Self::Error> {
+        let key = key("account", &account_id);
+        let result = Api::update_account(&self.inner, account_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> AppApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn add_application(&self, body: models::AppRequest) -> Result<models::App, Self::Error> {
+        Api::add_application(&self.inner, body)
+    }
+
+    fn delete_app(&self, app_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("app", &app_id);
+        let result = Api::delete_app(&self.inner, app_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_apps(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllAppsResponse, Self::Error> {
+        Api::get_all_apps(
+            &self.inner,
+            name,
+            description,
+            all_search,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
+    fn get_app(&self, app_id: uuid::Uuid) -> Result<models::App, Self::Error> {
+        self.cached(key("app", &app_id), |a| Api::get_app(a, app_id))
+    }
+
+    fn get_app_certificate(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> Result<models::Certificate, Self::Error> {
+        Api::get_app_certificate(&self.inner, node_id, app_id)
+    }
+
+    fn get_app_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> Result<models::CertificateDetails, Self::Error> {
+        Api::get_app_node_certificate_details(&self.inner, node_id, app_id)
+    }
+
+    fn get_apps_unique_labels(&self) -> Result<models::LabelsCount, Self::Error> {
+        Api::get_apps_unique_labels(&self.inner)
+    }
+
+    fn update_app(
+        &self,
+        app_id: uuid::Uuid,
+        body: models::AppBodyUpdateRequest,
+    ) -> Result<models::App, Self::Error> {
+        let key = key("app", &app_id);
+        let result = Api::update_app(&self.inner, app_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> ApplicationConfigApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_application_config(
+        &self,
+        body: models::ApplicationConfig,
+    ) -> Result<models::ApplicationConfigResponse, Self::Error> {
+        Api::create_application_config(&self.inner, body)
+    }
+
+    fn delete_application_config(&self, config_id: String) -> Result<(), Self::Error> {
+        let key = key("application_config", &config_id);
+        let result = Api::delete_application_config(&self.inner, config_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_application_configs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        image_id: Option<uuid::Uuid>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllApplicationConfigsResponse, Self::Error> {
+        Api::get_all_application_configs(&self.inner, name, description, image_id, limit, offset)
+    }
+
+    fn get_application_config(
+        &self,
+        config_id: String,
+    ) -> Result<models::ApplicationConfigResponse, Self::Error> {
+        self.cached(key("application_config", &config_id), |a| {
+            Api::get_application_config(a, config_id)
+        })
+    }
+
+    fn get_runtime_application_config(
+        &self,
+        expected_hash: &[u8; 32],
+    ) -> Result<models::RuntimeAppConfig, Self::Error> {
+        Api::get_runtime_application_config(&self.inner, expected_hash)
+    }
+
+    fn get_specific_runtime_application_config(
+        &self,
+        config_id: String,
+    ) -> Result<models::RuntimeAppConfig, Self::Error> {
+        Api::get_specific_runtime_application_config(&self.inner, config_id)
+    }
+
+    fn update_application_config(
+        &self,
+        config_id: String,
+        body: models::UpdateApplicationConfigRequest,
+    ) -> Result<models::ApplicationConfigResponse, Self::Error> {
+        let key = key("application_config", &config_id);
+        let result = Api::update_application_config(&self.inner, config_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> ApprovalRequestsApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn approve_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::ApproveRequest>,
+    ) -> Result<models::ApprovalRequest, Self::Error> {
+        let key = key("approval_request", &request_id);
+        let result = Api::approve_approval_request(&self.inner, request_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn create_approval_request(
+        &self,
+        body: models::ApprovalRequestRequest,
+    ) -> Result<models::ApprovalRequest, Self::Error> {
+        Api::create_approval_request(&self.inner, body)
+    }
+
+    fn delete_approval_request(&self, request_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("approval_request", &request_id);
+        let result = Api::delete_approval_request(&self.inner, request_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn deny_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::DenyRequest>,
+    ) -> Result<models::ApprovalRequest, Self::Error> {
+        let key = key("approval_request", &request_id);
+        let result = Api::deny_approval_request(&self.inner, request_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_approval_requests(
+        &self,
+        requester: Option<uuid::Uuid>,
+        reviewer: Option<uuid::Uuid>,
+        subject: Option<uuid::Uuid>,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllApprovalRequests, Self::Error> {
+        Api::get_all_approval_requests(
+            &self.inner,
+            requester,
+            reviewer,
+            subject,
+            status,
+            all_search,
+            sort_by,
+            limit,
+            offset,
+        )
+    }
+
+    fn get_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> Result<models::ApprovalRequest, Self::Error> {
+        self.cached(key("approval_request", &request_id), |a| {
+            Api::get_approval_request(a, request_id)
+        })
+    }
+
+    fn get_approval_request_result(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> Result<models::ApprovableResult, Self::Error> {
+        Api::get_approval_request_result(&self.inner, request_id)
+    }
+}
+
+impl<A: Api> AuthApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        Api::auth_discover(&self.inner, body)
+    }
+
+    fn authenticate_user(
+        &self,
+        body: Option<models::AuthRequest>,
+    ) -> Result<models::AuthResponse, Self::Error> {
+        let result = Api::authenticate_user(&self.inner, body);
+        self.invalidate_all();
+        result
+    }
+
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error> {
+        Api::refresh_session(&self.inner)
+    }
+}
+
+impl<A: Api> BuildApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn convert_app_build(
+        &self,
+        body: models::ConvertAppBuildRequest,
+    ) -> Result<models::Build, Self::Error> {
+        Api::convert_app_build(&self.inner, body)
+    }
+
+    fn create_build(&self, body: models::CreateBuildRequest) -> Result<models::Build, Self::Error> {
+        Api::create_build(&self.inner, body)
+    }
+
+    fn delete_build(&self, build_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("build", &build_id);
+        let result = Api::delete_build(&self.inner, build_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_builds(
+        &self,
+        all_search: Option<String>,
+        docker_image_name: Option<String>,
+        config_id: Option<String>,
+        deployed_status: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllBuildsResponse, Self::Error> {
+        Api::get_all_builds(
+            &self.inner,
+            all_search,
+            docker_image_name,
+            config_id,
+            deployed_status,
+            status,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
+    fn get_build(&self, build_id: uuid::Uuid) -> Result<models::Build, Self::Error> {
+        self.cached(key("build", &build_id), |a| Api::get_build(a, build_id))
+    }
+
+    fn get_build_deployments(
+        &self,
+        build_id: uuid::Uuid,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllBuildDeploymentsResponse, Self::Error> {
+        Api::get_build_deployments(
+            &self.inner,
+            build_id,
+            status,
+            all_search,
+            sort_by,
+            limit,
+            offset,
+        )
+    }
+
+    fn update_build(
+        &self,
+        build_id: uuid::Uuid,
+        body: models::BuildUpdateRequest,
+    ) -> Result<models::Build, Self::Error> {
+        let key = key("build", &build_id);
+        let result = Api::update_build(&self.inner, build_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> CertificateApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn get_certificate(&self, cert_id: uuid::Uuid) -> Result<models::Certificate, Self::Error> {
+        self.cached(key("certificate", &cert_id), |a| {
+            Api::get_certificate(a, cert_id)
+        })
+    }
+
+    fn new_certificate(
+        &self,
+        body: models::NewCertificateRequest,
+    ) -> Result<models::TaskResult, Self::Error> {
+        Api::new_certificate(&self.inner, body)
+    }
+}
+
+impl<A: Api> DatasetApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_dataset(
+        &self,
+        body: models::CreateDatasetRequest,
+    ) -> Result<models::Dataset, Self::Error> {
+        Api::create_dataset(&self.inner, body)
+    }
+
+    fn delete_dataset(&self, dataset_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("dataset", &dataset_id);
+        let result = Api::delete_dataset(&self.inner, dataset_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_datasets(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllDatasetsResponse, Self::Error> {
+        Api::get_all_datasets(&self.inner, name, description, limit, offset)
+    }
+
+    fn get_dataset(&self, dataset_id: uuid::Uuid) -> Result<models::Dataset, Self::Error> {
+        self.cached(key("dataset", &dataset_id), |a| {
+            Api::get_dataset(a, dataset_id)
+        })
+    }
+
+    fn update_dataset(
+        &self,
+        dataset_id: uuid::Uuid,
+        body: models::DatasetUpdateRequest,
+    ) -> Result<models::Dataset, Self::Error> {
+        let key = key("dataset", &dataset_id);
+        let result = Api::update_dataset(&self.inner, dataset_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> EventsApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        Api::get_all_events(
+            &self.inner,
+            actor_type,
+            action_type,
+            severity,
+            entity_id,
+            from,
+            to,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+}
+
+impl<A: Api> NodeApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("node", &node_id);
+        let result = Api::deactivate_node(&self.inner, node_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_nodes(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        sgx_version: Option<String>,
+        all_search: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllNodesResponse, Self::Error> {
+        Api::get_all_nodes(
+            &self.inner,
+            name,
+            description,
+            sgx_version,
+            all_search,
+            status,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
+    fn get_node(&self, node_id: uuid::Uuid) -> Result<models::Node, Self::Error> {
+        self.cached(key("node", &node_id), |a| Api::get_node(a, node_id))
+    }
+
+    fn get_node_certificate(
+        &self,
+        node_id: uuid::Uuid,
+    ) -> Result<models::Certificate, Self::Error> {
+        Api::get_node_certificate(&self.inner, node_id)
+    }
+
+    fn get_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+    ) -> Result<models::CertificateDetails, Self::Error> {
+        Api::get_node_certificate_details(&self.inner, node_id)
+    }
+
+    fn get_nodes_unique_labels(&self) -> Result<models::LabelsCount, Self::Error> {
+        Api::get_nodes_unique_labels(&self.inner)
+    }
+
+    fn provision_node(
+        &self,
+        body: models::NodeProvisionRequest,
+    ) -> Result<models::TaskResult, Self::Error> {
+        Api::provision_node(&self.inner, body)
+    }
+
+    fn update_node(
+        &self,
+        node_id: uuid::Uuid,
+        body: models::NodeUpdateRequest,
+    ) -> Result<models::Node, Self::Error> {
+        let key = key("node", &node_id);
+        let result = Api::update_node(&self.inner, node_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn update_node_status(
+        &self,
+        body: models::NodeStatusRequest,
+    ) -> Result<models::NodeStatusResponse, Self::Error> {
+        Api::update_node_status(&self.inner, body)
+    }
+}
+
+impl<A: Api> RegistryApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_registry(
+        &self,
+        registry_request: models::RegistryRequest,
+    ) -> Result<models::Registry, Self::Error> {
+        Api::create_registry(&self.inner, registry_request)
+    }
+
+    fn delete_registry(&self, registry_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("registry", &registry_id);
+        let result = Api::delete_registry(&self.inner, registry_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_registries(&self) -> Result<Vec<models::Registry>, Self::Error> {
+        Api::get_all_registries(&self.inner)
+    }
+
+    fn get_registry(&self, registry_id: uuid::Uuid) -> Result<models::Registry, Self::Error> {
+        self.cached(key("registry", &registry_id), |a| {
+            Api::get_registry(a, registry_id)
+        })
+    }
+
+    fn get_registry_for_app(
+        &self,
+        app_id: uuid::Uuid,
+    ) -> Result<models::AppRegistryResponse, Self::Error> {
+        Api::get_registry_for_app(&self.inner, app_id)
+    }
+
+    fn get_registry_for_image(
+        &self,
+        image_name: String,
+    ) -> Result<models::ImageRegistryResponse, Self::Error> {
+        Api::get_registry_for_image(&self.inner, image_name)
+    }
+
+    fn update_registry(
+        &self,
+        registry_id: uuid::Uuid,
+        body: models::UpdateRegistryRequest,
+    ) -> Result<models::Registry, Self::Error> {
+        let key = key("registry", &registry_id);
+        let result = Api::update_registry(&self.inner, registry_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> SystemApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn get_manager_version(&self) -> Result<models::VersionResponse, Self::Error> {
+        Api::get_manager_version(&self.inner)
+    }
+}
+
+impl<A: Api> TaskApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn get_all_tasks(
+        &self,
+        task_type: Option<String>,
+        status: Option<String>,
+        requester: Option<String>,
+        approver: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+        base_filters: Option<String>,
+    ) -> Result<models::GetAllTasksResponse, Self::Error> {
+        Api::get_all_tasks(
+            &self.inner,
+            task_type,
+            status,
+            requester,
+            approver,
+            all_search,
+            limit,
+            offset,
+            sort_by,
+            base_filters,
+        )
+    }
+
+    fn get_task(&self, task_id: uuid::Uuid) -> Result<models::Task, Self::Error> {
+        Api::get_task(&self.inner, task_id)
+    }
+
+    fn get_task_status(&self, task_id: uuid::Uuid) -> Result<models::TaskResult, Self::Error> {
+        Api::get_task_status(&self.inner, task_id)
+    }
+
+    fn update_task(
+        &self,
+        task_id: uuid::Uuid,
+        body: models::TaskUpdateRequest,
+    ) -> Result<models::TaskResult, Self::Error> {
+        let result = Api::update_task(&self.inner, task_id, body);
+        match result {
+            Ok(ref task) => {
+                let keys = [
+                    task.build_id.map(|id| key("build", &id)),
+                    task.node_id.map(|id| key("node", &id)),
+                    task.certificate_id.map(|id| key("certificate", &id)),
+                ];
+                self.invalidate(|k| keys.iter().any(|key| key.as_ref() == Some(k)));
+            }
+            // The task may have been updated without us learning what it
+            // belongs to.
+            Err(_) => self.invalidate(|k| ["build", "node", "certificate"].contains(&k.0)),
+        }
+        result
+    }
+}
+
+impl<A: Api> ToolsApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn convert_app(
+        &self,
+        body: models::ConversionRequest,
+    ) -> Result<models::ConversionResponse, Self::Error> {
+        Api::convert_app(&self.inner, body)
+    }
+}
+
+impl<A: Api> UsersApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn accept_terms_and_conditions(&self) -> Result<(), Self::Error> {
+        Api::accept_terms_and_conditions(&self.inner)
+    }
+
+    fn change_password(&self, body: models::PasswordChangeRequest) -> Result<(), Self::Error> {
+        Api::change_password(&self.inner, body)
+    }
+
+    fn confirm_email(
+        &self,
+        body: models::ConfirmEmailRequest,
+    ) -> Result<models::ConfirmEmailResponse, Self::Error> {
+        Api::confirm_email(&self.inner, body)
+    }
+
+    fn create_user(&self, body: models::SignupRequest) -> Result<models::User, Self::Error> {
+        Api::create_user(&self.inner, body)
+    }
+
+    fn delete_user_account(&self, user_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("user", &user_id);
+        let result = Api::delete_user_account(&self.inner, user_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn delete_user_from_account(&self, user_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("user", &user_id);
+        let result = Api::delete_user_from_account(&self.inner, user_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn forgot_password(&self, body: models::ForgotPasswordRequest) -> Result<(), Self::Error> {
+        Api::forgot_password(&self.inner, body)
+    }
+
+    fn get_all_users(
+        &self,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllUsersResponse, Self::Error> {
+        Api::get_all_users(&self.inner, all_search, limit, offset, sort_by)
+    }
+
+    fn get_logged_in_user(&self) -> Result<models::User, Self::Error> {
+        Api::get_logged_in_user(&self.inner)
+    }
+
+    fn get_user(&self, user_id: uuid::Uuid) -> Result<models::User, Self::Error> {
+        self.cached(key("user", &user_id), |a| Api::get_user(a, user_id))
+    }
+
+    fn invite_user(&self, body: models::InviteUserRequest) -> Result<models::User, Self::Error> {
+        Api::invite_user(&self.inner, body)
+    }
+
+    fn process_invitations(&self, body: models::ProcessInviteRequest) -> Result<(), Self::Error> {
+        Api::process_invitations(&self.inner, body)
+    }
+
+    fn resend_confirm_email(&self) -> Result<(), Self::Error> {
+        Api::resend_confirm_email(&self.inner)
+    }
+
+    fn resend_invitation(&self, user_id: uuid::Uuid) -> Result<(), Self::Error> {
+        Api::resend_invitation(&self.inner, user_id)
+    }
+
+    fn reset_password(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::PasswordResetRequest,
+    ) -> Result<(), Self::Error> {
+        Api::reset_password(&self.inner, user_id, body)
+    }
+
+    fn update_user(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::UpdateUserRequest,
+    ) -> Result<models::User, Self::Error> {
+        let key = key("user", &user_id);
+        let result = Api::update_user(&self.inner, user_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn validate_password_reset_token(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::ValidateTokenRequest,
+    ) -> Result<models::ValidateTokenResponse, Self::Error> {
+        Api::validate_password_reset_token(&self.inner, user_id, body)
+    }
+}
+
+impl<A: Api> WorkflowApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_workflow_graph(
+        &self,
+        body: models::CreateWorkflowGraph,
+    ) -> Result<models::WorkflowGraph, Self::Error> {
+        Api::create_workflow_graph(&self.inner, body)
+    }
+
+    fn delete_workflow_graph(&self, graph_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("workflow_graph", &graph_id);
+        let result = Api::delete_workflow_graph(&self.inner, graph_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_all_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        parent_graph_id: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllWorkflowGraphsResponse, Self::Error> {
+        Api::get_all_workflow_graphs(
+            &self.inner,
+            name,
+            description,
+            all_search,
+            parent_graph_id,
+            sort_by,
+            limit,
+            offset,
+        )
+    }
+
+    fn get_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+    ) -> Result<models::WorkflowGraph, Self::Error> {
+        self.cached(key("workflow_graph", &graph_id), |a| {
+            Api::get_workflow_graph(a, graph_id)
+        })
+    }
+
+    fn update_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::UpdateWorkflowGraph,
+    ) -> Result<models::WorkflowGraph, Self::Error> {
+        let key = key("workflow_graph", &graph_id);
+        let result = Api::update_workflow_graph(&self.inner, graph_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+impl<A: Api> WorkflowFinalApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_final_workflow_graph(
+        &self,
+        body: models::CreateFinalWorkflowGraph,
+    ) -> Result<models::FinalWorkflow, Self::Error> {
+        Api::create_final_workflow_graph(&self.inner, body)
+    }
+
+    fn delete_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> Result<(), Self::Error> {
+        let full = key("full_final_workflow_graph", &graph_id);
+        let version_key = key("final_workflow_graph", &format!("{}/{}", graph_id, version));
+        let result = Api::delete_final_workflow_graph(&self.inner, graph_id, version);
+        self.invalidate(|k| *k == full || *k == version_key);
+        result
+    }
+
+    fn get_all_final_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllFinalWorkflowGraphsResponse, Self::Error> {
+        Api::get_all_final_workflow_graphs(
+            &self.inner,
+            name,
+            description,
+            all_search,
+            sort_by,
+            limit,
+            offset,
+        )
+    }
+
+    fn get_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> Result<models::VersionInFinalWorkflow, Self::Error> {
+        self.cached(
+            key("final_workflow_graph", &format!("{}/{}", graph_id, version)),
+            |a| Api::get_final_workflow_graph(a, graph_id, version),
+        )
+    }
+
+    fn get_full_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+    ) -> Result<models::FinalWorkflow, Self::Error> {
+        self.cached(key("full_final_workflow_graph", &graph_id), |a| {
+            Api::get_full_final_workflow_graph(a, graph_id)
+        })
+    }
+
+    fn update_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::CreateWorkflowVersionRequest,
+    ) -> Result<models::VersionInFinalWorkflow, Self::Error> {
+        let full = key("full_final_workflow_graph", &graph_id);
+        let prefix = format!("{}/", graph_id);
+        let result = Api::update_final_workflow_graph(&self.inner, graph_id, body);
+        self.invalidate(|k| {
+            *k == full || (k.0 == "final_workflow_graph" && k.1.starts_with(&prefix))
+        });
+        result
+    }
+}
+
+impl<A: Api> ZoneApi for CachingApi<A> {
+    type Error = A::Error;
+
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        Api::create_zone(&self.inner, body)
+    }
+
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        let key = key("zone", &zone_id);
+        let result = Api::delete_zone(&self.inner, zone_id);
+        self.invalidate(|k| *k == key);
+        result
+    }
+
+    fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
+        self.cached(key("zone", &zone_id), |a| Api::get_zone(a, zone_id))
+    }
+
+    fn get_zone_join_token(
+        &self,
+        zone_id: uuid::Uuid,
+    ) -> Result<models::ZoneJoinToken, Self::Error> {
+        Api::get_zone_join_token(&self.inner, zone_id)
+    }
+
+    fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error> {
+        Api::get_zones(&self.inner)
+    }
+
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        let key = key("zone", &zone_id);
+        let result = Api::update_zone(&self.inner, zone_id, body);
+        self.invalidate(|k| *k == key);
+        result
+    }
+}
+
+#[cfg(all(test, feature = "fake"))]
+mod tests {
+    use super::{key, CacheStats, CachingApi};
+    use fake::FakeEnclaveManager;
+    use models;
+    use std::thread;
+    use std::time::Duration;
+    use Api;
+
+    fn build(api: &CachingApi<FakeEnclaveManager>, name: &str) -> uuid::Uuid {
+        api.create_build(models::CreateBuildRequest {
+            docker_info: None,
+            mrenclave: "11".repeat(32),
+            mrsigner: "22".repeat(32),
+            isvprodid: 1,
+            isvsvn: 1,
+            app_id: None,
+            app_name: Some(name.to_owned()),
+            mem_size: None,
+            threads: None,
+            advanced_settings: None,
+        })
+        .unwrap()
+        .build_id
+        .unwrap()
+    }
+
+    #[test]
+    fn test_hits_and_invalidation() {
+        let api = CachingApi::new(FakeEnclaveManager::new());
+        let build_id = build(&api, "app");
+
+        let first = api.get_build(build_id).unwrap();
+        assert_eq!(api.get_build(build_id).unwrap(), first);
+        assert_eq!(api.inner().calls("get_build"), 1);
+
+        api.update_build(build_id, models::BuildUpdateRequest::new())
+            .unwrap();
+        api.get_build(build_id).unwrap();
+        assert_eq!(api.inner().calls("get_build"), 2);
+
+        api.delete_build(build_id).unwrap();
+        assert!(api.get_build(build_id).unwrap_err().is_not_found());
+        assert_eq!(
+            api.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 3,
+                evictions: 0,
+                invalidations: 2,
+                entries: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_zone_updates_are_not_stale() {
+        let api = CachingApi::new(FakeEnclaveManager::new());
+        let zone_id = api.get_zones().unwrap()[0].zone_id;
+        api.get_zone(zone_id).unwrap();
+
+        let mut update = models::UpdateZoneRequest::new();
+        update.node_refresh_interval = Some(600);
+        api.update_zone(zone_id, update).unwrap();
+        assert_eq!(api.get_zone(zone_id).unwrap().node_refresh_interval, 600);
+
+        let mut request = models::AddZoneRequest::new();
+        request.name = Some("edge".to_owned());
+        api.delete_zone(api.create_zone(request).unwrap().zone_id)
+            .unwrap();
+        assert_eq!(api.inner().calls("get_zone"), 2);
+        assert_eq!(api.stats().invalidations, 1);
+    }
+
+    #[test]
+    fn test_ttl_and_size_bound() {
+        let api = CachingApi::new(FakeEnclaveManager::new())
+            .with_ttl(Duration::from_millis(50))
+            .with_max_entries(1);
+        let first = build(&api, "first");
+        let second = build(&api, "second");
+
+        api.get_build(first).unwrap();
+        api.get_build(second).unwrap();
+        api.get_build(first).unwrap();
+        assert_eq!(api.inner().calls("get_build"), 3);
+        assert_eq!(api.stats().evictions, 2);
+
+        api.get_build(first).unwrap();
+        assert_eq!(api.inner().calls("get_build"), 3);
+        thread::sleep(Duration::from_millis(60));
+        api.get_build(first).unwrap();
+        assert_eq!(api.inner().calls("get_build"), 4);
+
+        api.reset_stats();
+        api.invalidate_all();
+        assert_eq!(api.stats().invalidations, 1);
+    }
+
+    #[test]
+    fn test_invalidation_during_miss() {
+        let api = CachingApi::new(FakeEnclaveManager::new());
+        let build_id = build(&api, "app");
+
+        // The build is updated after the inner call fetched it.
+        let stale = api.cached(key("build", &build_id), |inner| {
+            let build = Api::get_build(inner, build_id);
+            api.update_build(build_id, models::BuildUpdateRequest::new())
+                .unwrap();
+            build
+        });
+        assert!(stale.is_ok());
+        api.get_build(build_id).unwrap();
+        assert_eq!(api.inner().calls("get_build"), 2);
+        assert_eq!(api.stats().entries, 1);
+    }
+
+    #[test]
+    fn test_task_updates_invalidate_build() {
+        let api = CachingApi::new(FakeEnclaveManager::new());
+        let build_id = build(&api, "app");
+        let task_id = api.get_build(build_id).unwrap().pending_task_id.unwrap();
+
+        api.get_task(task_id).unwrap();
+        api.get_task(task_id).unwrap();
+        assert_eq!(api.inner().calls("get_task"), 2);
+
+        let approve = models::TaskUpdateRequest::new(models::ApprovalStatus::APPROVED);
+        api.update_task(task_id, approve).unwrap();
+        assert_eq!(
+            api.get_build(build_id).unwrap().status.status,
+            models::BuildStatusType::WHITELISTED
+        );
+        assert_eq!(
+            api.get_task(task_id).unwrap().status.status,
+            models::TaskStatusType::SUCCESS
+        );
+    }
+}
@@ -0,0 +1,310 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use std::cell::Cell;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
+
+use super::Client;
+use {Api, ApiDecorator, ApiError};
+
+/// The account selected in the session of a `Client` and its clones, as far
+/// as the account handles know.
+#[derive(Default)]
+pub(crate) struct Selection {
+    account_id: Option<Uuid>,
+    /// Generation of the session the account was selected in.
+    generation: usize,
+}
+
+pub(crate) type SharedSelection = RwLock<Selection>;
+
+// The account of the handle performing an operation on this thread, so that
+// `Client::execute` can select it again in a new session.
+thread_local! {
+    static SCOPED: Cell<Option<Uuid>> = const { Cell::new(None) };
+}
+
+/// Scopes the operation performed on this thread to an account until dropped.
+struct Scope {
+    previous: Option<Uuid>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+fn enter(account_id: Option<Uuid>) -> Scope {
+    Scope {
+        previous: SCOPED.with(|scoped| scoped.replace(account_id)),
+    }
+}
+
+/// Select the account of the current operation again after the client
+/// opened a new session.
+pub(crate) fn reselect(client: &Client) -> Result<(), ApiError> {
+    if let Some(account_id) = SCOPED.with(Cell::get) {
+        debug!("Selecting account {} in the new session", account_id);
+        let _scope = enter(None);
+        Api::select_account(client, account_id)?;
+    }
+    Ok(())
+}
+
+/// A view of a `Client` whose operations are performed in one account,
+/// returned by `Client::for_account`.
+///
+/// Before each operation the handle selects its account, unless it is
+/// already selected in the current session. Operations of handles for the
+/// account that is selected run concurrently, while switching to another
+/// account waits for them to finish, so handles for different accounts can
+/// be used from several threads. If the session is opened again during an
+/// operation, the account is selected in the new session before the request
+/// is replayed.
+///
+/// Calling `select_account` on the client directly bypasses this tracking,
+/// and should not be mixed with the use of handles.
+#[derive(Clone, Debug)]
+pub struct AccountClient {
+    pub(crate) client: Client,
+    pub(crate) account_id: Uuid,
+}
+
+impl AccountClient {
+    pub fn account_id(&self) -> Uuid {
+        self.account_id
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Selection> {
+        self.client
+            .accounts
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Selection> {
+        self.client
+            .accounts
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn generation(&self) -> usize {
+        self.client
+            .session
+            .as_ref()
+            .map_or(0, |session| session.generation())
+    }
+
+    fn is_selected(&self, selection: &Selection) -> bool {
+        selection.account_id == Some(self.account_id) && selection.generation == self.generation()
+    }
+}
+
+impl ApiDecorator for AccountClient {
+    type Error = ApiError;
+
+    fn dispatch<F, T>(&self, f: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(&dyn Api<Error = ApiError>) -> Result<T, ApiError>,
+    {
+        {
+            let selection = self.read();
+            if self.is_selected(&selection) {
+                let generation = selection.generation;
+                let _scope = enter(Some(self.account_id));
+                let result = f(&self.client);
+                drop(selection);
+                if result.is_ok() && self.generation() != generation {
+                    // `reselect` selected the account in a new session.
+                    let mut selection = self.write();
+                    if selection.account_id == Some(self.account_id) {
+                        selection.generation = self.generation();
+                    }
+                }
+                return result;
+            }
+        }
+
+        let mut selection = self.write();
+        if !self.is_selected(&selection) {
+            selection.account_id = None;
+            Api::select_account(&self.client, self.account_id)?;
+            selection.account_id = Some(self.account_id);
+            selection.generation = self.generation();
+        }
+        let _scope = enter(Some(self.account_id));
+        let result = f(&self.client);
+        if result.is_ok() {
+            selection.generation = self.generation();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::auth_response;
+    use client::{Client, Credentials, InterceptedRequest, Interceptor};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use uuid::Uuid;
+    use Api;
+
+    fn select(server: &mut mockito::Server, account_id: Uuid, token: &str) -> mockito::Mock {
+        server
+            .mock(
+                "POST",
+                &*format!("/v1/accounts/select_account/{}", account_id),
+            )
+            .match_header("authorization", &*format!("Bearer {}", token))
+            .with_status(204)
+    }
+
+    #[test]
+    fn test_accounts_are_selected_before_calls() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_credentials_provider(Credentials::Bearer("api-key".to_owned()));
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let first_auth = server
+            .mock("POST", "/v1/sys/auth")
+            .with_body(auth_response("first", 3600))
+            .expect(1)
+            .create();
+        let second_auth = server
+            .mock("POST", "/v1/sys/auth")
+            .with_body(auth_response("second", 3600))
+            .expect(1)
+            .create();
+        let select_a = select(&mut server, a, "first").expect(2).create();
+        let select_b = select(&mut server, b, "first").expect(1).create();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(6)
+            .create();
+        let expired = server
+            .mock("GET", "/v1/zones")
+            .match_header("authorization", "Bearer first")
+            .with_status(401)
+            .with_body(r#"{"message":"Session expired","code":"SESSION_EXPIRED"}"#)
+            .expect(1)
+            .create();
+        let reselect_a = select(&mut server, a, "second").expect(1).create();
+        let zones = server
+            .mock("GET", "/v1/zones")
+            .match_header("authorization", "Bearer second")
+            .with_body("[]")
+            .expect(1)
+            .create();
+        let reselect_b = select(&mut server, b, "second").expect(1).create();
+
+        let account_a = client.for_account(a);
+        let account_b = client.for_account(b);
+        account_a.get_manager_version().unwrap();
+        account_a.get_manager_version().unwrap();
+        account_b.get_manager_version().unwrap();
+        account_a.get_manager_version().unwrap();
+        account_a.get_zones().unwrap();
+        // The account was selected again in the new session.
+        account_a.get_manager_version().unwrap();
+        account_b.get_manager_version().unwrap();
+
+        first_auth.assert();
+        second_auth.assert();
+        select_a.assert();
+        select_b.assert();
+        version.assert();
+        expired.assert();
+        reselect_a.assert();
+        zones.assert();
+        reselect_b.assert();
+    }
+
+    /// Records the selected account and the operations performed in it.
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Interceptor for Recorder {
+        fn before_request(&self, request: &mut InterceptedRequest) {
+            let entry = match request.operation() {
+                "select_account" => request.url().path().rsplit('/').next().unwrap().to_owned(),
+                operation => operation.to_owned(),
+            };
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    #[test]
+    fn test_concurrent_handles() {
+        let mut server = mockito::Server::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_interceptor(Recorder(log.clone()));
+        // Each account is used for a different operation.
+        let operations = ["get_manager_version", "get_zones", "get_accounts"];
+        let accounts = operations
+            .iter()
+            .map(|_| Uuid::new_v4())
+            .collect::<Vec<_>>();
+        server
+            .mock(
+                "POST",
+                mockito::Matcher::Regex("^/v1/accounts/select_account/".to_owned()),
+            )
+            .with_status(204)
+            .create();
+        server
+            .mock("GET", "/v1/sys/version")
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+        server.mock("GET", "/v1/zones").with_body("[]").create();
+        server
+            .mock("GET", "/v1/accounts")
+            .with_body(r#"{"items":[]}"#)
+            .create();
+
+        let threads = accounts
+            .iter()
+            .enumerate()
+            .map(|(i, &account_id)| {
+                let account = client.for_account(account_id);
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        match i {
+                            0 => account.get_manager_version().map(drop),
+                            1 => account.get_zones().map(drop),
+                            _ => account.get_accounts().map(drop),
+                        }
+                        .unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let log = log.lock().unwrap();
+        let mut selected = None;
+        for entry in log.iter() {
+            match operations.iter().position(|operation| operation == entry) {
+                Some(i) => assert_eq!(selected, Some(accounts[i])),
+                None => selected = Some(entry.parse().unwrap()),
+            }
+        }
+        let performed = log.iter().filter(|entry| operations.contains(&&***entry));
+        assert_eq!(performed.count(), 30);
+    }
+}
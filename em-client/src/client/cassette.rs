@@ -0,0 +1,784 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Recording and replaying of the HTTP traffic of a `Client`.
+//!
+//! A `Recorder` wraps the connector of a client and records every request it
+//! sends and the response it receives into a `Cassette`, which can be saved
+//! as JSON. A `Replayer` serves the responses of a cassette without any
+//! network access, so tests can run against traffic recorded once from a live
+//! service:
+//!
+//! ```no_run
+//! # extern crate em_client;
+//! # use em_client::client::*;
+//! # use em_client::SystemApi;
+//! # fn main() {
+//! let recorder = Recorder::new(HttpsConnector::new(TlsOptions::default()).unwrap());
+//! let client = Client::try_new_with_connector(
+//!     "https://em.example.com",
+//!     Some("https"),
+//!     recorder.clone(),
+//! ).unwrap();
+//! client.get_manager_version().unwrap();
+//! recorder.save("tests/cassettes/version.json").unwrap();
+//!
+//! let cassette = Cassette::load("tests/cassettes/version.json").unwrap();
+//! let client = Client::try_new_with_connector(
+//!     "https://em.example.com",
+//!     Some("https"),
+//!     Replayer::new(cassette, MatchMode::Strict),
+//! ).unwrap();
+//! client.get_manager_version().unwrap();
+//! # }
+//! ```
+//!
+//! Requests are recorded as they are sent, so the path is the remapped path
+//! when the client uses the new API paths. The values of credential headers
+//! are replaced by `<redacted>`; response bodies are recorded as they are.
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hyper;
+use hyper::net::{NetworkConnector, NetworkStream};
+use serde_json;
+use std::fs;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr};
+use std::path::Path;
+use std::str;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::form_urlencoded;
+
+const REDACTED: &str = "<redacted>";
+
+const DEFAULT_REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+];
+
+/// Recorded HTTP interactions, in the order they happened.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn new() -> Self {
+        Cassette::default()
+    }
+
+    /// Read a cassette saved with `Cassette::save`.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_json()?)
+    }
+
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    pub fn to_json(&self) -> io::Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(flatten)]
+    pub body: RecordedBody,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(flatten)]
+    pub body: RecordedBody,
+}
+
+/// A request or response body. Bodies that are not valid UTF-8 are stored
+/// base64-encoded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedBody {
+    #[serde(rename = "body")]
+    Text(String),
+    #[serde(rename = "body_base64")]
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(text) => RecordedBody::Text(text),
+            Err(e) => RecordedBody::Base64(STANDARD.encode(e.as_bytes())),
+        }
+    }
+
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        match *self {
+            RecordedBody::Text(ref text) => Ok(text.as_bytes().to_vec()),
+            RecordedBody::Base64(ref encoded) => STANDARD
+                .decode(encoded)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e)),
+        }
+    }
+}
+
+impl Default for RecordedBody {
+    fn default() -> Self {
+        RecordedBody::Text(String::new())
+    }
+}
+
+/// How a `Replayer` finds the recorded interaction for a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Requests must be sent in the recorded order, with the same method,
+    /// path, query string and body.
+    Strict,
+    /// A request is answered by the first unused interaction with the same
+    /// method, path and query parameters, in any order; the body is ignored.
+    /// Once all matching interactions are used the last one is served again,
+    /// which suits polling.
+    Lenient,
+}
+
+/// A connector that records the traffic going through the inner connector.
+///
+/// Clones share the recorded interactions.
+pub struct Recorder<C> {
+    connector: Arc<C>,
+    redacted_headers: Arc<Vec<String>>,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl<C> Clone for Recorder<C> {
+    fn clone(&self) -> Self {
+        Recorder {
+            connector: self.connector.clone(),
+            redacted_headers: self.redacted_headers.clone(),
+            interactions: self.interactions.clone(),
+        }
+    }
+}
+
+impl<C> Recorder<C> {
+    pub fn new(connector: C) -> Self {
+        Recorder {
+            connector: Arc::new(connector),
+            redacted_headers: Arc::new(
+                DEFAULT_REDACTED_HEADERS
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect(),
+            ),
+            interactions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Also redact the value of the given request and response header. The
+    /// `Authorization`, `Proxy-Authorization`, `Cookie` and `Set-Cookie`
+    /// headers are always redacted.
+    pub fn with_redacted_header(mut self, name: &str) -> Self {
+        Arc::make_mut(&mut self.redacted_headers).push(name.to_ascii_lowercase());
+        self
+    }
+
+    /// The interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        Cassette {
+            interactions: self
+                .interactions
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone(),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.cassette().save(path)
+    }
+}
+
+impl<C> NetworkConnector for Recorder<C>
+where
+    C: NetworkConnector,
+    C::Stream: NetworkStream,
+{
+    type Stream = RecordingStream<C::Stream>;
+
+    fn connect(&self, host: &str, port: u16, scheme: &str) -> hyper::Result<Self::Stream> {
+        Ok(RecordingStream {
+            inner: self.connector.connect(host, port, scheme)?,
+            redacted_headers: self.redacted_headers.clone(),
+            interactions: self.interactions.clone(),
+            sent: Vec::new(),
+            received: Vec::new(),
+        })
+    }
+}
+
+pub struct RecordingStream<S> {
+    inner: S,
+    redacted_headers: Arc<Vec<String>>,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+    sent: Vec<u8>,
+    received: Vec<u8>,
+}
+
+impl<S> RecordingStream<S> {
+    /// Record the exchanges that are complete in the buffered traffic.
+    fn collect(&mut self, eof: bool) {
+        while let Some((request, request_len)) = parse_request(&self.sent) {
+            let (response, response_len) = match parse_response(&self.received, &request, eof) {
+                Some(response) => response,
+                None => return,
+            };
+            self.sent.drain(..request_len);
+            self.received.drain(..response_len);
+            let interaction = Interaction {
+                request: RecordedRequest {
+                    headers: self.redact(request.headers),
+                    ..request.recorded
+                },
+                response: RecordedResponse {
+                    headers: self.redact(response.headers),
+                    ..response
+                },
+            };
+            self.interactions
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(interaction);
+        }
+    }
+
+    fn redact(&self, headers: Vec<(String, String)>) -> Vec<(String, String)> {
+        headers
+            .into_iter()
+            .map(|(name, value)| {
+                if self.redacted_headers.contains(&name.to_ascii_lowercase()) {
+                    (name, REDACTED.to_owned())
+                } else {
+                    (name, value)
+                }
+            })
+            .collect()
+    }
+}
+
+impl<S: NetworkStream> Read for RecordingStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.received.extend_from_slice(&buf[..n]);
+        self.collect(n == 0);
+        Ok(n)
+    }
+}
+
+impl<S: NetworkStream> Write for RecordingStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.sent.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<S: NetworkStream> NetworkStream for RecordingStream<S> {
+    fn peer_addr(&mut self) -> io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    fn set_read_timeout(&self, dur: Option<Duration>) -> io::Result<()> {
+        self.inner.set_read_timeout(dur)
+    }
+
+    fn set_write_timeout(&self, dur: Option<Duration>) -> io::Result<()> {
+        self.inner.set_write_timeout(dur)
+    }
+
+    fn close(&mut self, how: Shutdown) -> io::Result<()> {
+        self.inner.close(how)
+    }
+
+    fn set_previous_response_expected_no_content(&mut self, expected: bool) {
+        self.inner
+            .set_previous_response_expected_no_content(expected)
+    }
+
+    fn previous_response_expected_no_content(&self) -> bool {
+        self.inner.previous_response_expected_no_content()
+    }
+}
+
+impl<S> Drop for RecordingStream<S> {
+    fn drop(&mut self) {
+        // A response without a length ends when the connection is closed.
+        self.collect(true);
+    }
+}
+
+/// A connector that answers requests from a cassette instead of the network.
+///
+/// Clones share the state of the replay.
+#[derive(Clone)]
+pub struct Replayer {
+    state: Arc<Mutex<ReplayState>>,
+}
+
+struct ReplayState {
+    mode: MatchMode,
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+impl Replayer {
+    pub fn new(cassette: Cassette, mode: MatchMode) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        Replayer {
+            state: Arc::new(Mutex::new(ReplayState {
+                mode,
+                interactions: cassette.interactions,
+                used,
+            })),
+        }
+    }
+
+    /// The number of recorded interactions that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        let state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.used.iter().filter(|used| !**used).count()
+    }
+}
+
+impl ReplayState {
+    fn find(&mut self, request: &RecordedRequest) -> Option<usize> {
+        let index = match self.mode {
+            MatchMode::Strict => self
+                .used
+                .iter()
+                .position(|used| !used)
+                .filter(|&i| strict_match(&self.interactions[i].request, request)),
+            MatchMode::Lenient => {
+                let matching = (0..self.interactions.len())
+                    .filter(|&i| lenient_match(&self.interactions[i].request, request))
+                    .collect::<Vec<_>>();
+                matching
+                    .iter()
+                    .find(|&&i| !self.used[i])
+                    .or_else(|| matching.last())
+                    .cloned()
+            }
+        };
+        if let Some(i) = index {
+            self.used[i] = true;
+        }
+        index
+    }
+}
+
+fn strict_match(recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+    recorded.method == request.method
+        && recorded.path == request.path
+        && recorded.query == request.query
+        && recorded.body.to_bytes().ok() == request.body.to_bytes().ok()
+}
+
+fn lenient_match(recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+    fn params(query: &Option<String>) -> Vec<(String, String)> {
+        let mut params: Vec<(String, String)> = query
+            .as_ref()
+            .map(|query| {
+                form_urlencoded::parse(query.as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        params.sort();
+        params
+    }
+    recorded.method == request.method
+        && recorded.path == request.path
+        && params(&recorded.query) == params(&request.query)
+}
+
+impl NetworkConnector for Replayer {
+    type Stream = ReplayStream;
+
+    fn connect(&self, _host: &str, _port: u16, _scheme: &str) -> hyper::Result<ReplayStream> {
+        Ok(ReplayStream {
+            state: self.state.clone(),
+            sent: Vec::new(),
+            response: io::Cursor::new(Vec::new()),
+        })
+    }
+}
+
+pub struct ReplayStream {
+    state: Arc<Mutex<ReplayState>>,
+    sent: Vec<u8>,
+    response: io::Cursor<Vec<u8>>,
+}
+
+impl ReplayStream {
+    fn answer(&mut self) -> io::Result<()> {
+        let (request, len) = parse_request(&self.sent).ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "Incomplete request sent to replayer",
+            )
+        })?;
+        self.sent.drain(..len);
+        let request = request.recorded;
+
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let index = state.find(&request).ok_or_else(|| {
+            let query = request
+                .query
+                .as_ref()
+                .map(|q| format!("?{}", q))
+                .unwrap_or_default();
+            io::Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "No recorded interaction matches {} {}{}",
+                    request.method, request.path, query
+                ),
+            )
+        })?;
+        let response = &state.interactions[index].response;
+        let body = response.body.to_bytes()?;
+
+        let mut bytes = format!("HTTP/1.1 {}\r\n", response.status).into_bytes();
+        for (name, value) in &response.headers {
+            let name_lower = name.to_ascii_lowercase();
+            if name_lower != "content-length" && name_lower != "transfer-encoding" {
+                bytes.extend_from_slice(format!("{}: {}\r\n", name, value).as_bytes());
+            }
+        }
+        bytes.extend_from_slice(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes());
+        if request.method != "HEAD" {
+            bytes.extend_from_slice(&body);
+        }
+        self.response = io::Cursor::new(bytes);
+        Ok(())
+    }
+}
+
+impl Read for ReplayStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.response.position() as usize == self.response.get_ref().len() {
+            self.answer()?;
+        }
+        self.response.read(buf)
+    }
+}
+
+impl Write for ReplayStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.sent.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl NetworkStream for ReplayStream {
+    fn peer_addr(&mut self) -> io::Result<SocketAddr> {
+        Ok(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+    }
+
+    fn set_read_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_write_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self, _how: Shutdown) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A request parsed from the bytes written to a stream.
+struct ParsedRequest {
+    recorded: RecordedRequest,
+    headers: Vec<(String, String)>,
+}
+
+type Head = (String, Vec<(String, String)>, usize);
+
+/// The start line, the headers and the length of the head of an HTTP message.
+fn parse_head(buf: &[u8]) -> Option<Head> {
+    let end = buf.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = str::from_utf8(&buf[..end]).ok()?;
+    let mut lines = head.split("\r\n");
+    let start = lines.next()?.to_owned();
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .collect();
+    Some((start, headers, end + 4))
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// The body starting at `buf` and the number of bytes it takes, if complete.
+fn parse_body(buf: &[u8], headers: &[(String, String)], eof: bool) -> Option<(Vec<u8>, usize)> {
+    let chunked = header(headers, "Transfer-Encoding")
+        .is_some_and(|te| te.to_ascii_lowercase().contains("chunked"));
+    if chunked {
+        return parse_chunked(buf);
+    }
+    match header(headers, "Content-Length") {
+        Some(len) => {
+            let len = len.parse::<usize>().ok()?;
+            buf.get(..len).map(|body| (body.to_vec(), len))
+        }
+        None if eof => Some((buf.to_vec(), buf.len())),
+        None => None,
+    }
+}
+
+fn parse_chunked(buf: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let mut body = Vec::new();
+    let mut pos = 0;
+    loop {
+        let line_end = pos + buf[pos..].windows(2).position(|w| w == b"\r\n")?;
+        let size = str::from_utf8(&buf[pos..line_end]).ok()?;
+        let size = size.split(';').next()?.trim();
+        let size = usize::from_str_radix(size, 16).ok()?;
+        pos = line_end + 2;
+        if size == 0 {
+            // Trailers, terminated by an empty line.
+            loop {
+                let line_end = pos + buf[pos..].windows(2).position(|w| w == b"\r\n")?;
+                let empty = line_end == pos;
+                pos = line_end + 2;
+                if empty {
+                    return Some((body, pos));
+                }
+            }
+        }
+        body.extend_from_slice(buf.get(pos..pos + size)?);
+        pos += size + 2;
+        if pos > buf.len() {
+            return None;
+        }
+    }
+}
+
+fn parse_request(buf: &[u8]) -> Option<(ParsedRequest, usize)> {
+    let (start, headers, head_len) = parse_head(buf)?;
+    let mut parts = start.split(' ');
+    let method = parts.next()?.to_owned();
+    let target = parts.next()?;
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_owned(), Some(query.to_owned())),
+        None => (target.to_owned(), None),
+    };
+    // Requests without a length have no body.
+    let (body, body_len) = match parse_body(&buf[head_len..], &headers, false) {
+        Some(body) => body,
+        None if header(&headers, "Content-Length").is_none()
+            && header(&headers, "Transfer-Encoding").is_none() =>
+        {
+            (Vec::new(), 0)
+        }
+        None => return None,
+    };
+    let request = ParsedRequest {
+        recorded: RecordedRequest {
+            method,
+            path,
+            query,
+            headers: Vec::new(),
+            body: RecordedBody::new(body),
+        },
+        headers,
+    };
+    Some((request, head_len + body_len))
+}
+
+fn parse_response(
+    buf: &[u8],
+    request: &ParsedRequest,
+    eof: bool,
+) -> Option<(RecordedResponse, usize)> {
+    let (start, headers, head_len) = parse_head(buf)?;
+    let status = start.split(' ').nth(1)?.parse::<u16>().ok()?;
+    let no_body =
+        request.recorded.method == "HEAD" || status == 204 || status == 304 || status < 200;
+    let (body, body_len) = if no_body {
+        (Vec::new(), 0)
+    } else {
+        parse_body(&buf[head_len..], &headers, eof)?
+    };
+    let response = RecordedResponse {
+        status,
+        headers,
+        body: RecordedBody::new(body),
+    };
+    Some((response, head_len + body_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::timeout::HttpConnector;
+    use client::Client;
+    use hyper::header::Authorization;
+    use {NodeApi, SystemApi};
+
+    fn record() -> Cassette {
+        let mut server = mockito::Server::new();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .with_header("set-cookie", "session=secret")
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+        let nodes = server
+            .mock("GET", "/v1/nodes")
+            .match_query(mockito::Matcher::Any)
+            .with_body(r#"{"metadata":null,"items":[]}"#)
+            .expect(1)
+            .create();
+
+        let recorder = Recorder::new(HttpConnector);
+        let mut client =
+            Client::try_new_with_connector(&server.url(), Some("http"), recorder.clone()).unwrap();
+        client
+            .headers()
+            .set(Authorization("Bearer secret".to_owned()));
+        client.get_manager_version().unwrap();
+        client
+            .get_all_nodes(None, None, None, None, None, Some(10), Some(0), None)
+            .unwrap();
+        version.assert();
+        nodes.assert();
+        recorder.cassette()
+    }
+
+    fn replay(cassette: Cassette, mode: MatchMode) -> (Client, Replayer) {
+        let replayer = Replayer::new(cassette, mode);
+        let client =
+            Client::try_new_with_connector("http://replay.invalid", Some("http"), replayer.clone())
+                .unwrap();
+        (client, replayer)
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let cassette = record();
+        assert_eq!(cassette.interactions.len(), 2);
+        let json = cassette.to_json().unwrap();
+        assert!(!json.contains("secret"));
+
+        let request = &cassette.interactions[1].request;
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/v1/nodes");
+        assert_eq!(request.query, Some("limit=10&offset=0".to_owned()));
+        assert_eq!(
+            header(&request.headers, "authorization"),
+            Some("<redacted>")
+        );
+
+        let (client, replayer) = replay(Cassette::from_json(&json).unwrap(), MatchMode::Strict);
+        assert_eq!(
+            client.get_manager_version().unwrap().version.unwrap(),
+            "1.0"
+        );
+        let nodes = client
+            .get_all_nodes(None, None, None, None, None, Some(10), Some(0), None)
+            .unwrap();
+        assert!(nodes.items.is_empty());
+        assert_eq!(replayer.remaining(), 0);
+    }
+
+    #[test]
+    fn test_strict_replay_rejects_other_requests() {
+        let (client, _) = replay(record(), MatchMode::Strict);
+        // Out of order.
+        let err = client
+            .get_all_nodes(None, None, None, None, None, Some(10), Some(0), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("No recorded interaction matches"));
+        assert!(err.to_string().contains("/v1/nodes?limit=10&offset=0"));
+    }
+
+    #[test]
+    fn test_lenient_replay() {
+        let mut cassette = record();
+        cassette.interactions[1].request.query = Some("offset=0&limit=10".to_owned());
+        let (client, replayer) = replay(cassette, MatchMode::Lenient);
+        for _ in 0..2 {
+            client
+                .get_all_nodes(None, None, None, None, None, Some(10), Some(0), None)
+                .unwrap();
+        }
+        assert_eq!(replayer.remaining(), 1);
+        client.get_manager_version().unwrap();
+        client.get_manager_version().unwrap();
+        assert_eq!(replayer.remaining(), 0);
+        assert!(client
+            .get_all_nodes(None, None, None, None, None, Some(5), Some(0), None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_chunked_response() {
+        let request = parse_request(b"GET /v1/sys/version HTTP/1.1\r\nHost: x\r\n\r\n")
+            .unwrap()
+            .0;
+        let response = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                         4\r\n{\"a\"\r\n3\r\n:1}\r\n0\r\n\r\n";
+        assert!(parse_response(&response[..response.len() - 2], &request, false).is_none());
+        let (parsed, len) = parse_response(response, &request, false).unwrap();
+        assert_eq!(len, response.len());
+        assert_eq!(parsed.body, RecordedBody::Text("{\"a\":1}".to_owned()));
+        assert_eq!(
+            RecordedBody::new(vec![0xff, 0x00]),
+            RecordedBody::Base64("/wA=".to_owned())
+        );
+    }
+}
@@ -0,0 +1,260 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use hyper::header::Headers;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use hyper::Url;
+use log::Level;
+use std::time::Duration;
+use uuid::Uuid;
+
+use ApiError;
+
+/// Hooks that run around every HTTP request sent by `Client`.
+///
+/// Interceptors run in the order they were added to the client, once per
+/// attempt: a request that is retried or replayed after re-authentication is
+/// seen several times. Requests the client makes on its own behalf, such as
+/// opening a session, are intercepted as well.
+pub trait Interceptor: Send + Sync {
+    /// Called before a request is sent. Only the headers can be modified.
+    fn before_request(&self, _request: &mut InterceptedRequest) {}
+
+    /// Called with the response to a request, or with the error if no
+    /// response was received.
+    fn after_response(
+        &self,
+        _request: &InterceptedRequest,
+        _response: Result<&InterceptedResponse, &ApiError>,
+    ) {
+    }
+}
+
+/// A request about to be sent by `Client`.
+#[derive(Debug)]
+pub struct InterceptedRequest<'a> {
+    pub(crate) operation: &'static str,
+    pub(crate) method: &'a Method,
+    pub(crate) url: &'a Url,
+    pub(crate) headers: Headers,
+    pub(crate) body: Option<&'a [u8]>,
+}
+
+impl<'a> InterceptedRequest<'a> {
+    /// Name of the API operation, e.g. `"get_manager_version"`.
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut Headers {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body
+    }
+}
+
+/// A response received by `Client`.
+#[derive(Debug)]
+pub struct InterceptedResponse<'a> {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: &'a Headers,
+    pub(crate) body: &'a [u8],
+    pub(crate) elapsed: Duration,
+}
+
+impl<'a> InterceptedResponse<'a> {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &Headers {
+        self.headers
+    }
+
+    pub fn body(&self) -> &[u8] {
+        self.body
+    }
+
+    /// Time between sending the request and receiving the whole response.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Logs every request and response through the `log` crate. Headers and
+/// bodies are not logged, since they may contain credentials.
+#[derive(Clone, Debug)]
+pub struct LoggingInterceptor {
+    level: Level,
+}
+
+impl Default for LoggingInterceptor {
+    fn default() -> Self {
+        LoggingInterceptor::new(Level::Debug)
+    }
+}
+
+impl LoggingInterceptor {
+    pub fn new(level: Level) -> Self {
+        LoggingInterceptor { level }
+    }
+}
+
+impl Interceptor for LoggingInterceptor {
+    fn before_request(&self, request: &mut InterceptedRequest) {
+        log!(
+            self.level,
+            "{}: {} {}",
+            request.operation(),
+            request.method(),
+            request.url()
+        );
+    }
+
+    fn after_response(
+        &self,
+        request: &InterceptedRequest,
+        response: Result<&InterceptedResponse, &ApiError>,
+    ) {
+        match response {
+            Ok(response) => log!(
+                self.level,
+                "{}: {} {} returned {} in {:?}",
+                request.operation(),
+                request.method(),
+                request.url(),
+                response.status(),
+                response.elapsed()
+            ),
+            Err(err) => log!(
+                self.level,
+                "{}: {} {} failed: {}",
+                request.operation(),
+                request.method(),
+                request.url(),
+                err.message()
+            ),
+        }
+    }
+}
+
+/// Adds a random request ID header to every request that does not already
+/// carry one, so that requests can be matched with server logs.
+#[derive(Clone, Debug)]
+pub struct RequestIdInterceptor {
+    header: String,
+}
+
+impl Default for RequestIdInterceptor {
+    fn default() -> Self {
+        RequestIdInterceptor::new("X-Request-Id")
+    }
+}
+
+impl RequestIdInterceptor {
+    pub fn new(header: &str) -> Self {
+        RequestIdInterceptor {
+            header: header.to_owned(),
+        }
+    }
+}
+
+impl Interceptor for RequestIdInterceptor {
+    fn before_request(&self, request: &mut InterceptedRequest) {
+        if request.headers().get_raw(&self.header).is_none() {
+            request.headers_mut().set_raw(
+                self.header.clone(),
+                vec![Uuid::new_v4().to_string().into_bytes()],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterceptedRequest, InterceptedResponse, Interceptor, RequestIdInterceptor};
+    use client::Client;
+    use std::sync::{Arc, Mutex};
+    use {ApiError, SystemApi};
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Interceptor for Recorder {
+        fn before_request(&self, request: &mut InterceptedRequest) {
+            request
+                .headers_mut()
+                .set_raw("X-Trace", vec![b"abc".to_vec()]);
+            self.calls.lock().unwrap().push(format!(
+                "before {} {} {}",
+                request.operation(),
+                request.method(),
+                request.url().path()
+            ));
+        }
+
+        fn after_response(
+            &self,
+            request: &InterceptedRequest,
+            response: Result<&InterceptedResponse, &ApiError>,
+        ) {
+            let response = response.unwrap();
+            self.calls.lock().unwrap().push(format!(
+                "after {} {} {}",
+                request.operation(),
+                response.status(),
+                String::from_utf8_lossy(response.body())
+            ));
+        }
+    }
+
+    #[test]
+    fn test_interceptors_see_requests_and_responses() {
+        let mut server = mockito::Server::new();
+        let recorder = Recorder::default();
+        let calls = recorder.calls.clone();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_interceptor(RequestIdInterceptor::default())
+            .with_interceptor(recorder);
+
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .match_header("x-trace", "abc")
+            .match_header(
+                "x-request-id",
+                mockito::Matcher::Regex("^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-".to_owned()),
+            )
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+
+        client.get_manager_version().unwrap();
+        version.assert();
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "before get_manager_version GET /v1/sys/version".to_owned(),
+                r#"after get_manager_version 200 OK {"version":"1.0"}"#.to_owned(),
+            ]
+        );
+    }
+}
@@ -16,19 +16,22 @@ use futures::{Future, Stream};
 use hyper::client::{Request, Response};
 use hyper::header::{ContentType, Header, HeaderFormat, Headers};
 use hyper::method::Method;
+use hyper::status::StatusCode;
 use hyper::Url;
 use mimetypes;
 use serde_json;
 use std::borrow::Cow;
 use std::error;
 use std::fmt;
-use std::io::{Error, ErrorKind, Read};
+use std::io::{self, Cursor, Error, ErrorKind, Read};
 use std::path::Path;
 use std::str;
 use std::str::FromStr;
 use std::string::ToString;
-use std::sync::Arc;
-use SHA256_BYTE_LENGTH;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use {Sha256Hash, SHA256_BYTE_LENGTH};
 
 #[allow(unused_imports)]
 use std::collections::{BTreeMap, HashMap};
@@ -38,13 +41,38 @@ use SimpleErrorType;
 
 use {
     AccountsApi, AppApi, ApplicationConfigApi, ApprovalRequestsApi, AuthApi, BuildApi,
-    CertificateApi, DatasetApi, NodeApi, RegistryApi, SystemApi, TaskApi, ToolsApi, UsersApi,
-    WorkflowApi, WorkflowFinalApi, ZoneApi,
+    CertificateApi, DatasetApi, EventsApi, NodeApi, RegistryApi, SystemApi, TaskApi, ToolsApi,
+    UsersApi, WorkflowApi, WorkflowFinalApi, ZoneApi,
 };
 
 use mbedtls::hash;
 use models;
 
+mod account;
+mod cassette;
+mod interceptor;
+mod options;
+mod proxy;
+mod retry;
+mod session;
+mod timeout;
+mod tls;
+
+pub use self::account::AccountClient;
+pub use self::cassette::{
+    Cassette, Interaction, MatchMode, RecordedBody, RecordedRequest, RecordedResponse, Recorder,
+    RecordingStream, ReplayStream, Replayer,
+};
+pub use self::interceptor::{
+    InterceptedRequest, InterceptedResponse, Interceptor, LoggingInterceptor, RequestIdInterceptor,
+};
+pub use self::options::{OptionsOverride, RequestOptions, IDEMPOTENCY_KEY_HEADER};
+pub use self::proxy::Proxy;
+pub use self::retry::RetryPolicy;
+pub use self::session::{Credentials, CredentialsProvider};
+pub use self::timeout::{TimeoutOverride, Timeouts};
+pub use self::tls::{HttpsConnector, HttpsStream, TlsOptions};
+
 define_encode_set! {
     /// This encode set is used for object IDs
     ///
@@ -54,7 +82,7 @@ define_encode_set! {
 }
 
 /// Convert input into a base path, e.g. "http://example:123". Also checks the scheme as it goes.
-fn into_base_path(
+pub(crate) fn into_base_path(
     input: &str,
     correct_scheme: Option<&'static str>,
 ) -> Result<String, ClientInitError> {
@@ -75,12 +103,82 @@ fn into_base_path(
     Ok(format!("{}://{}{}", scheme, host, port))
 }
 
+/// Turn a response with an unexpected status code into an `ApiError` that
+/// carries the status, headers and body of the response.
+fn unexpected_response(mut response: BufferedResponse) -> ApiError {
+    let code = response.status.to_u16();
+    let headers = response.headers.clone();
+    let mut body = Vec::new();
+    let result = response.read_to_end(&mut body);
+    let err_type = match response.status.is_server_error() {
+        false => SimpleErrorType::Permanent,
+        true => SimpleErrorType::Temporary,
+    };
+    let message = format!(
+        "Unexpected response code {}:\n{:?}\n\n{}",
+        code,
+        headers,
+        match result {
+            Ok(_) => match str::from_utf8(&body) {
+                Ok(body) => Cow::from(body),
+                Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
+            },
+            Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
+        }
+    );
+    let headers = headers
+        .iter()
+        .map(|header| (header.name().to_owned(), header.value_string()))
+        .collect();
+    ApiError::new(message, err_type).with_response(code, headers, body)
+}
+
+/// Prefix under which newer Enclave Manager deployments serve the `/v1/` API.
+const NEW_PATH_PREFIX: &str = "/api/v1/confidential_computing/";
+
+/// How long `PathMode::Auto` waits before probing again after a probe that
+/// did not tell which prefix the server uses.
+const PROBE_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Which path prefix the client uses for API operations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathMode {
+    /// Operations are sent to `/v1/...`.
+    Legacy,
+    /// Operations are sent to `/api/v1/confidential_computing/...`.
+    New,
+    /// The server is probed on the first request by fetching the manager
+    /// version under the new prefix. The new prefix is used if the probe
+    /// succeeds and the legacy prefix if it returns 404. Any other outcome,
+    /// e.g. a 503 from a load balancer, is not remembered: the legacy prefix
+    /// is used and the server is probed again later. Until the prefix is
+    /// known, an operation that returns 404 is sent again under the other
+    /// prefix.
+    Auto,
+}
+
+/// What `PathMode::Auto` found out about the server, shared by the clones
+/// of a client.
+#[derive(Debug, Default)]
+struct PathDetection {
+    new_paths: Option<bool>,
+    /// No probe is made before this time, after an inconclusive probe.
+    next_probe: Option<Instant>,
+}
+
 /// A client that implements the API by making HTTP calls out to a server.
 pub struct Client {
     hyper_client: Arc<hyper::client::Client>,
     base_path: String,
-    headers: Headers,
-    use_new_paths: bool,
+    headers: Mutex<Headers>,
+    path_mode: PathMode,
+    path_detection: Arc<Mutex<PathDetection>>,
+    retry_policy: RetryPolicy,
+    session: Option<Arc<session::Session>>,
+    accounts: Arc<account::SharedSelection>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    timeouts: Timeouts,
+    proxy: Option<Arc<Proxy>>,
 }
 
 impl fmt::Debug for Client {
@@ -94,8 +192,15 @@ impl Clone for Client {
         Client {
             hyper_client: self.hyper_client.clone(),
             base_path: self.base_path.clone(),
-            headers: self.headers.clone(),
-            use_new_paths: self.use_new_paths,
+            headers: Mutex::new(self.default_headers()),
+            path_mode: self.path_mode,
+            path_detection: self.path_detection.clone(),
+            retry_policy: self.retry_policy.clone(),
+            session: self.session.clone(),
+            accounts: self.accounts.clone(),
+            interceptors: self.interceptors.clone(),
+            timeouts: self.timeouts,
+            proxy: self.proxy.clone(),
         }
     }
 }
@@ -106,15 +211,43 @@ impl Client {
     /// # Arguments
     /// * `base_path` - base path of the client API, i.e. "www.my-api-implementation.com"
     pub fn try_new_http(base_path: &str) -> Result<Client, ClientInitError> {
-        Self::try_new_with_connector(base_path, Some("http"), hyper::net::HttpConnector)
+        Self::try_new_with_connector(base_path, Some("http"), timeout::HttpConnector)
+    }
+
+    /// Create a client with a TLS connection to the server.
+    ///
+    /// # Arguments
+    /// * `base_path` - base path of the client API, i.e. "www.my-api-implementation.com"
+    /// * `options` - how the server certificate is verified
+    pub fn try_new_https(base_path: &str, options: TlsOptions) -> Result<Client, ClientInitError> {
+        let connector = HttpsConnector::new(options)?;
+        Self::try_new_with_connector(base_path, Some("https"), connector)
+    }
+
+    /// Create a client with a mutually authenticated TLS connection to the server.
+    ///
+    /// # Arguments
+    /// * `base_path` - base path of the client API, i.e. "www.my-api-implementation.com"
+    /// * `options` - how the server certificate is verified
+    /// * `client_certificate` - the client certificate chain, PEM or DER encoded
+    /// * `client_key` - the private key of the client certificate, PEM or DER encoded
+    pub fn try_new_https_mutual(
+        base_path: &str,
+        options: TlsOptions,
+        client_certificate: &[u8],
+        client_key: &[u8],
+    ) -> Result<Client, ClientInitError> {
+        Self::try_new_https(
+            base_path,
+            options.with_client_certificate(client_certificate, client_key),
+        )
     }
 
     /// Create a client with a custom implementation of hyper::net::NetworkConnector.
     ///
     /// Intended for use with custom implementations of connect for e.g. protocol logging
-    /// or similar functionality which requires wrapping the transport layer. When wrapping a TCP connection,
-    /// this function should be used in conjunction with
-    /// `swagger::{http_connector, https_connector, https_mutual_connector}`.
+    /// or similar functionality which requires wrapping the transport layer. When wrapping a TLS connection,
+    /// this function should be used in conjunction with `HttpsConnector`.
     ///
     /// For ordinary tcp connections, prefer the use of `try_new_http`, `try_new_https`
     /// and `try_new_https_mutual`, to avoid introducing a dependency on the underlying transport layer.
@@ -133,13 +266,20 @@ impl Client {
         C: hyper::net::NetworkConnector<Stream = S> + Send + Sync + 'static,
         S: hyper::net::NetworkStream,
     {
-        let hyper_client = hyper::Client::with_connector(connector);
+        let hyper_client = hyper::Client::with_connector(timeout::TimeoutConnector(connector));
 
         Ok(Client {
             hyper_client: Arc::new(hyper_client),
             base_path: into_base_path(base_path, protocol)?,
-            headers: Headers::new(),
-            use_new_paths: false,
+            headers: Mutex::new(Headers::new()),
+            path_mode: PathMode::Legacy,
+            path_detection: Arc::new(Mutex::new(PathDetection::default())),
+            retry_policy: RetryPolicy::default(),
+            session: None,
+            accounts: Arc::new(RwLock::default()),
+            interceptors: Vec::new(),
+            timeouts: Timeouts::default(),
+            proxy: None,
         })
     }
 
@@ -163,42 +303,550 @@ impl Client {
         Ok(Client {
             hyper_client: hyper_client,
             base_path: into_base_path(base_path, None)?,
-            headers: Headers::new(),
-            use_new_paths: false,
+            headers: Mutex::new(Headers::new()),
+            path_mode: PathMode::Legacy,
+            path_detection: Arc::new(Mutex::new(PathDetection::default())),
+            retry_policy: RetryPolicy::default(),
+            session: None,
+            accounts: Arc::new(RwLock::default()),
+            interceptors: Vec::new(),
+            timeouts: Timeouts::default(),
+            proxy: None,
         })
     }
 
+    /// Headers sent with every request. Headers for individual operations of
+    /// a shared client can be set with `with_options` instead.
     pub fn headers(&mut self) -> &mut Headers {
-        &mut self.headers
+        self.headers
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn default_headers(&self) -> Headers {
+        self.headers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
     }
 
+    /// Whether operations are currently sent under the new path prefix. In
+    /// `PathMode::Auto` this is `false` until the server has been probed.
     pub fn uses_new_paths(&self) -> bool {
-        self.use_new_paths
+        match self.path_mode {
+            PathMode::Legacy => false,
+            PathMode::New => true,
+            PathMode::Auto => self.path_detection().new_paths == Some(true),
+        }
     }
 
     pub fn set_use_new_paths(&mut self, use_new_paths: bool) {
-        self.use_new_paths = use_new_paths;
+        self.path_mode = match use_new_paths {
+            false => PathMode::Legacy,
+            true => PathMode::New,
+        };
     }
 
     pub fn with_new_paths(self) -> Self {
+        self.with_path_mode(PathMode::New)
+    }
+
+    pub fn path_mode(&self) -> PathMode {
+        self.path_mode
+    }
+
+    pub fn set_path_mode(&mut self, path_mode: PathMode) {
+        self.path_mode = path_mode;
+    }
+
+    pub fn with_path_mode(self, path_mode: PathMode) -> Self {
+        Self { path_mode, ..self }
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Let the client manage its own session. It authenticates with the
+    /// credentials from `provider` before the first request, refreshes the
+    /// session before the token expires, and authenticates again (replaying
+    /// the request once) if the server reports the session as invalid or
+    /// expired. The session token replaces any `Authorization` header set
+    /// through `headers()`.
+    pub fn set_credentials_provider<P: CredentialsProvider + 'static>(&mut self, provider: P) {
+        self.session = Some(Arc::new(session::Session::new(Box::new(provider))));
+        self.accounts = Arc::new(RwLock::default());
+    }
+
+    pub fn with_credentials_provider<P: CredentialsProvider + 'static>(
+        mut self,
+        provider: P,
+    ) -> Self {
+        self.set_credentials_provider(provider);
+        self
+    }
+
+    /// Add an interceptor that runs after the ones added before it.
+    pub fn add_interceptor<I: Interceptor + 'static>(&mut self, interceptor: I) {
+        self.interceptors.push(Arc::new(interceptor));
+    }
+
+    pub fn with_interceptor<I: Interceptor + 'static>(mut self, interceptor: I) -> Self {
+        self.add_interceptor(interceptor);
+        self
+    }
+
+    pub fn timeouts(&self) -> &Timeouts {
+        &self.timeouts
+    }
+
+    pub fn set_timeouts(&mut self, timeouts: Timeouts) {
+        self.timeouts = timeouts;
+    }
+
+    pub fn with_timeouts(self, timeouts: Timeouts) -> Self {
+        Self { timeouts, ..self }
+    }
+
+    pub fn proxy(&self) -> Option<&Proxy> {
+        self.proxy.as_deref()
+    }
+
+    /// Tunnel connections through `proxy`, or connect directly if `None`.
+    pub fn set_proxy(&mut self, proxy: Option<Proxy>) {
+        self.proxy = proxy.map(Arc::new);
+    }
+
+    pub fn with_proxy(self, proxy: Proxy) -> Self {
         Self {
-            use_new_paths: true,
+            proxy: Some(Arc::new(proxy)),
             ..self
         }
     }
 
+    /// Perform operations with `timeouts` instead of the client's timeouts,
+    /// e.g. `client.with_request_timeouts(timeouts).get_app(app_id)`.
+    pub fn with_request_timeouts(&self, timeouts: Timeouts) -> TimeoutOverride<'_> {
+        TimeoutOverride {
+            client: self,
+            timeouts,
+        }
+    }
+
+    /// Perform operations with `options`, e.g.
+    /// `client.with_options(options).get_app(app_id)`.
+    pub fn with_options(&self, options: RequestOptions) -> OptionsOverride<'_> {
+        OptionsOverride {
+            client: self,
+            options: Arc::new(options),
+        }
+    }
+
+    /// A handle that performs operations in the account `account_id`,
+    /// selecting it in the session first when needed. See `AccountClient`.
+    pub fn for_account(&self, account_id: uuid::Uuid) -> AccountClient {
+        AccountClient {
+            client: self.clone(),
+            account_id,
+        }
+    }
+
+    /// Get the runtime config of the calling application without checking its
+    /// hash. Use `get_runtime_application_config` unless the hash is checked
+    /// otherwise, e.g. by a `ConfigWatcher`.
+    pub fn get_unverified_runtime_application_config(
+        &self,
+    ) -> Result<models::RuntimeAppConfig, ApiError> {
+        let raw_config = self.fetch_runtime_application_config()?;
+        deserialize_config(&raw_config)
+    }
+
+    /// The JSON of the runtime config of the calling application.
+    fn fetch_runtime_application_config(&self) -> Result<String, ApiError> {
+        let operation_path = "/v1/runtime/app_configs";
+        let url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+        let url = Url::from_str(&url).map_err(|err| {
+            ApiError::new(
+                format!("Unable to build URL: {}", err),
+                SimpleErrorType::Permanent,
+            )
+        })?;
+
+        self.execute(
+            "get_runtime_application_config",
+            Method::Get,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                String::from_utf8(body).map_err(|e| {
+                    ApiError::new(
+                        format!("Response was not valid UTF8: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+
     fn remap_operation_path<'a>(&self, operation: &'a str) -> Cow<'a, str> {
-        if !self.use_new_paths {
+        if !self.resolve_new_paths() {
             return Cow::Borrowed(operation);
         }
         if let Some(path_without_v1) = operation.strip_prefix("/v1/") {
-            return Cow::Owned(format!(
-                "/api/v1/confidential_computing/{}",
-                path_without_v1
-            ));
+            return Cow::Owned(format!("{}{}", NEW_PATH_PREFIX, path_without_v1));
         }
         return Cow::Borrowed(operation);
     }
+
+    fn path_detection(&self) -> MutexGuard<'_, PathDetection> {
+        self.path_detection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Decide whether to use the new path prefix, probing the server first
+    /// if needed.
+    fn resolve_new_paths(&self) -> bool {
+        match self.path_mode {
+            PathMode::Legacy => false,
+            PathMode::New => true,
+            PathMode::Auto => {
+                {
+                    let detection = self.path_detection();
+                    if let Some(new_paths) = detection.new_paths {
+                        return new_paths;
+                    }
+                    if detection
+                        .next_probe
+                        .is_some_and(|next| Instant::now() < next)
+                    {
+                        return false;
+                    }
+                }
+                // The lock is not held during the probe, so that a slow server
+                // does not block the clones of the client on other threads.
+                let probed = self.probe_new_paths();
+                let mut detection = self.path_detection();
+                match probed {
+                    Some(new_paths) => {
+                        debug!(
+                            "{} serves the {} API paths",
+                            self.base_path,
+                            if new_paths { "new" } else { "legacy" }
+                        );
+                        *detection.new_paths.get_or_insert(new_paths)
+                    }
+                    None => {
+                        detection.next_probe = Some(Instant::now() + PROBE_RETRY_INTERVAL);
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    /// Ask the server whether it serves the new path prefix. Returns `None`
+    /// if the outcome does not tell. The probe is limited by the timeouts of
+    /// the operation it is made for.
+    fn probe_new_paths(&self) -> Option<bool> {
+        let url = format!("{}{}sys/version", self.base_path, NEW_PATH_PREFIX);
+        let request = self.hyper_client.get(&url).headers(self.default_headers());
+        let _timeouts = timeout::enter(self.timeouts);
+        let _proxy = proxy::enter(self.proxy.clone());
+        let status = match request.send() {
+            Ok(response) => response.status.to_u16(),
+            Err(err) => {
+                debug!("Failed to probe {} for API paths: {}", self.base_path, err);
+                return None;
+            }
+        };
+        match status {
+            200..=299 => Some(true),
+            404 => Some(false),
+            _ => {
+                debug!(
+                    "Probing {} for API paths returned status {}",
+                    self.base_path, status
+                );
+                None
+            }
+        }
+    }
+
+    /// `url` with its path prefix swapped for the other one, and whether that
+    /// is the new prefix.
+    fn with_other_path_prefix(url: &Url) -> Option<(Url, bool)> {
+        let path = url.path();
+        let (path, new_paths) = if path.contains(NEW_PATH_PREFIX) {
+            (path.replacen(NEW_PATH_PREFIX, "/v1/", 1), false)
+        } else if path.contains("/v1/") {
+            (path.replacen("/v1/", NEW_PATH_PREFIX, 1), true)
+        } else {
+            return None;
+        };
+        let mut url = url.clone();
+        url.set_path(&path);
+        Some((url, new_paths))
+    }
+
+    /// Send a request for an operation. If the client manages its session,
+    /// the request carries the session token, and it is replayed once with a
+    /// new session if the server rejects the current one.
+    fn execute(
+        &self,
+        operation: &'static str,
+        method: Method,
+        url: Url,
+        body: Option<&[u8]>,
+        content_type: Option<hyper::mime::Mime>,
+    ) -> Result<BufferedResponse, ApiError> {
+        let _timeouts = timeout::enter(self.timeouts);
+        let (options, _options) = options::take();
+        let mut headers = self.default_headers();
+        if let Some(content_type) = content_type {
+            headers.set(ContentType(content_type));
+        }
+        if let Some(options) = options {
+            options::apply(&options, &mut headers);
+        }
+
+        let session = match self.session {
+            Some(ref session) => session,
+            None => return self.send_with_fallback(operation, &method, &url, headers, body),
+        };
+
+        let generation = session.generation();
+        let token = session.access_token(self)?;
+        if session.generation() != generation {
+            account::reselect(self)?;
+        }
+        headers.set(session::bearer(&token));
+        let response = self.send_with_fallback(operation, &method, &url, headers.clone(), body)?;
+        if !session::is_session_error(&response) {
+            return Ok(response);
+        }
+
+        debug!("Session was rejected, authenticating again");
+        let token = session.reauthenticate(self, &token)?;
+        account::reselect(self)?;
+        headers.set(session::bearer(&token));
+        self.send_with_fallback(operation, &method, &url, headers, body)
+    }
+
+    /// Send a request, and in `PathMode::Auto` send it again under the other
+    /// path prefix if the server does not know its path and the prefix is
+    /// not known yet. The prefix that worked is used for later operations.
+    fn send_with_fallback(
+        &self,
+        operation: &'static str,
+        method: &Method,
+        url: &Url,
+        headers: Headers,
+        body: Option<&[u8]>,
+    ) -> Result<BufferedResponse, ApiError> {
+        let response = self.send(operation, method, url, headers.clone(), body)?;
+        if self.path_mode != PathMode::Auto
+            || response.status != StatusCode::NotFound
+            || self.path_detection().new_paths.is_some()
+        {
+            return Ok(response);
+        }
+        let (other_url, new_paths) = match Client::with_other_path_prefix(url) {
+            Some(other) => other,
+            None => return Ok(response),
+        };
+        debug!("{} {} returned 404, trying {}", method, url, other_url);
+        let other_response = self.send(operation, method, &other_url, headers, body)?;
+        // Both prefixes return 404 for objects that do not exist.
+        if other_response.status == StatusCode::NotFound {
+            return Ok(response);
+        }
+        self.path_detection().new_paths = Some(new_paths);
+        Ok(other_response)
+    }
+
+    /// Send a request, retrying it according to the client's `RetryPolicy`
+    /// and running the interceptors around each attempt.
+    ///
+    /// The returned response may still carry an error status if the retries
+    /// were exhausted or the request was not eligible for a retry.
+    fn send(
+        &self,
+        operation: &'static str,
+        method: &Method,
+        url: &Url,
+        headers: Headers,
+        body: Option<&[u8]>,
+    ) -> Result<BufferedResponse, ApiError> {
+        let _proxy = proxy::enter(self.proxy.clone());
+        let retryable =
+            RetryPolicy::is_idempotent(method) || headers.get_raw(IDEMPOTENCY_KEY_HEADER).is_some();
+        let started = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let mut intercepted = InterceptedRequest {
+                operation,
+                method,
+                url,
+                headers: headers.clone(),
+                body,
+            };
+            for interceptor in &self.interceptors {
+                interceptor.before_request(&mut intercepted);
+            }
+
+            let mut request = self.hyper_client.request(method.clone(), url.clone());
+            request = request.headers(intercepted.headers.clone());
+            if let Some(body) = body {
+                request = request.body(body);
+            }
+
+            let sent = Instant::now();
+            let result = request.send().map_err(|err| match err {
+                hyper::Error::Io(ref e) if timeout::is_timeout(e) => {
+                    ApiError::new_timeout(format!("Request timed out: {}", err))
+                }
+                hyper::Error::Io(_) => ApiError::new(
+                    format!("No response received: {}", err),
+                    SimpleErrorType::Temporary,
+                ),
+                _ => ApiError::new(
+                    format!("No response received: {}", err),
+                    SimpleErrorType::Permanent,
+                ),
+            });
+            let result = result.and_then(BufferedResponse::read_from);
+            let elapsed = sent.elapsed();
+            if !self.interceptors.is_empty() {
+                let response = result.as_ref().map(|response| InterceptedResponse {
+                    status: response.status,
+                    headers: &response.headers,
+                    body: response.body(),
+                    elapsed,
+                });
+                for interceptor in &self.interceptors {
+                    interceptor.after_response(&intercepted, response.as_ref().map_err(|err| *err));
+                }
+            }
+
+            let delay = match result {
+                Ok(response) => {
+                    if !retryable || !RetryPolicy::is_retryable_status(response.status.to_u16()) {
+                        return Ok(response);
+                    }
+                    match self
+                        .retry_policy
+                        .next_delay(
+                            attempt,
+                            started,
+                            retry::retry_after(response.status, &response.headers),
+                        )
+                        .filter(|delay| within_deadline(*delay))
+                    {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    }
+                }
+                Err(err) => {
+                    let delay = match err.error_type() {
+                        SimpleErrorType::Temporary if retryable => self
+                            .retry_policy
+                            .next_delay(attempt, started, None)
+                            .filter(|delay| within_deadline(*delay)),
+                        _ => None,
+                    };
+                    match delay {
+                        Some(delay) => delay,
+                        None => return Err(err),
+                    }
+                }
+            };
+            debug!(
+                "Retrying {} {} in {:?} (attempt {} of {})",
+                method,
+                url,
+                delay,
+                attempt + 1,
+                self.retry_policy.max_attempts()
+            );
+            thread::sleep(delay);
+        }
+    }
+}
+
+/// Whether waiting for `delay` leaves time before the deadline of the
+/// current operation.
+fn within_deadline(delay: Duration) -> bool {
+    match timeout::remaining() {
+        Some(remaining) => delay < remaining,
+        None => true,
+    }
+}
+
+/// A response whose body has already been read, so that the request can be
+/// retried or replayed after looking at it.
+struct BufferedResponse {
+    status: StatusCode,
+    headers: Headers,
+    body: Cursor<Vec<u8>>,
+}
+
+impl BufferedResponse {
+    fn read_from(mut response: Response) -> Result<BufferedResponse, ApiError> {
+        let mut body = Vec::new();
+        response
+            .read_to_end(&mut body)
+            .map_err(|e| match timeout::is_timeout(&e) {
+                true => ApiError::new_timeout(format!("Timed out reading response: {}", e)),
+                false => ApiError::new(
+                    format!("Failed to read response: {}", e),
+                    SimpleErrorType::Temporary,
+                ),
+            })?;
+        Ok(BufferedResponse {
+            status: response.status,
+            headers: response.headers.clone(),
+            body: Cursor::new(body),
+        })
+    }
+
+    fn body(&self) -> &[u8] {
+        self.body.get_ref()
+    }
+}
+
+impl Read for BufferedResponse {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.body.read(buf)
+    }
 }
 
 impl AccountsApi for Client {
@@ -208,7 +856,12 @@ impl AccountsApi for Client {
         &self,
         param_body: models::AccountRequest,
     ) -> Result<models::Account, ApiError> {
-        let mut url = format!("{}/v1/accounts", self.base_path);
+        let operation_path = "/v1/accounts";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -228,76 +881,51 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::CREATE_ACCOUNT.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_account",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_ACCOUNT.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Account>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Account>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_account(&self, param_account_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/accounts/{account_id}",
+            "{}{}",
             self.base_path,
-            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -318,17 +946,7 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_account", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -341,38 +959,19 @@ impl AccountsApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_account(&self, param_account_id: uuid::Uuid) -> Result<models::Account, ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/accounts/{account_id}",
+            "{}{}",
             self.base_path,
-            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -393,17 +992,7 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_account", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -424,35 +1013,17 @@ impl AccountsApi for Client {
                             serde_json::from_str::<models::Account>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_accounts(&self) -> Result<models::AccountListResponse, ApiError> {
-        let mut url = format!("{}/v1/accounts", self.base_path);
+        let operation_path = "/v1/accounts";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -472,17 +1043,7 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_accounts", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -504,38 +1065,19 @@ impl AccountsApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn select_account(&self, param_account_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/select_account/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/accounts/select_account/{account_id}",
+            "{}{}",
             self.base_path,
-            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -556,17 +1098,7 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("select_account", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -579,30 +1111,7 @@ impl AccountsApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -611,10 +1120,14 @@ impl AccountsApi for Client {
         param_account_id: uuid::Uuid,
         param_body: models::AccountUpdateRequest,
     ) -> Result<models::Account, ApiError> {
+        let operation_path = format!(
+            "/v1/accounts/{account_id}",
+            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/accounts/{account_id}",
+            "{}{}",
             self.base_path,
-            account_id = utf8_percent_encode(&param_account_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -635,68 +1148,39 @@ impl AccountsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_ACCOUNT.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_account",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_ACCOUNT.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Account>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Account>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -704,9 +1188,14 @@ impl AppApi for Client {
     type Error = ApiError;
 
     fn add_application(&self, param_body: models::AppRequest) -> Result<models::App, ApiError> {
-        let mut url = format!("{}/v1/apps", self.base_path);
-
-        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+        let operation_path = "/v1/apps";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
         let query_string_str = query_string.finish();
         if !query_string_str.is_empty() {
@@ -724,76 +1213,51 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::ADD_APPLICATION.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "add_application",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::ADD_APPLICATION.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::App>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::App>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_app(&self, param_app_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/apps/{app_id}",
+            "{}{}",
             self.base_path,
-            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -814,17 +1278,7 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_app", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -837,30 +1291,7 @@ impl AppApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -873,7 +1304,12 @@ impl AppApi for Client {
         param_offset: Option<i32>,
         param_sort_by: Option<String>,
     ) -> Result<models::GetAllAppsResponse, ApiError> {
-        let mut url = format!("{}/v1/apps", self.base_path);
+        let operation_path = "/v1/apps";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -911,17 +1347,7 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_apps", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -943,38 +1369,19 @@ impl AppApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_app(&self, param_app_id: uuid::Uuid) -> Result<models::App, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/apps/{app_id}",
+            "{}{}",
             self.base_path,
-            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -995,17 +1402,7 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_app", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -1026,30 +1423,7 @@ impl AppApi for Client {
                             serde_json::from_str::<models::App>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1058,12 +1432,16 @@ impl AppApi for Client {
         param_node_id: uuid::Uuid,
         param_app_id: uuid::Uuid,
     ) -> Result<models::Certificate, ApiError> {
-        let mut url = format!(
-            "{}/v1/apps/{app_id}/node/{node_id}/certificate",
-            self.base_path,
+        let operation_path = format!(
+            "/v1/apps/{app_id}/node/{node_id}/certificate",
             node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET),
             app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
         );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -1083,17 +1461,7 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_app_certificate", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -1114,30 +1482,7 @@ impl AppApi for Client {
                             serde_json::from_str::<models::Certificate>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1146,12 +1491,16 @@ impl AppApi for Client {
         param_node_id: uuid::Uuid,
         param_app_id: uuid::Uuid,
     ) -> Result<models::CertificateDetails, ApiError> {
-        let mut url = format!(
-            "{}/v1/apps/{app_id}/node/{node_id}/certificate-details",
-            self.base_path,
+        let operation_path = format!(
+            "/v1/apps/{app_id}/node/{node_id}/certificate-details",
             node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET),
             app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
         );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -1171,67 +1520,45 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "get_app_node_certificate_details",
+            Method::Get,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::CertificateDetails>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::CertificateDetails>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn get_apps_unique_labels(&self) -> Result<models::LabelsCount, ApiError> {
-        let mut url = format!("{}/v1/apps/unique_labels/count", self.base_path);
+        let operation_path = "/v1/apps/unique_labels/count";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -1251,17 +1578,7 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_apps_unique_labels", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -1282,30 +1599,7 @@ impl AppApi for Client {
                             serde_json::from_str::<models::LabelsCount>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1314,10 +1608,14 @@ impl AppApi for Client {
         param_app_id: uuid::Uuid,
         param_body: models::AppBodyUpdateRequest,
     ) -> Result<models::App, ApiError> {
+        let operation_path = format!(
+            "/v1/apps/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/apps/{app_id}",
+            "{}{}",
             self.base_path,
-            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -1338,68 +1636,39 @@ impl AppApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_APP.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_app",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_APP.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::App>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::App>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -1410,7 +1679,12 @@ impl ApplicationConfigApi for Client {
         &self,
         param_body: models::ApplicationConfig,
     ) -> Result<models::ApplicationConfigResponse, ApiError> {
-        let mut url = format!("{}/v1/app_configs", self.base_path);
+        let operation_path = "/v1/app_configs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -1430,79 +1704,52 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(
-            mimetypes::requests::CREATE_APPLICATION_CONFIG.clone(),
-        ));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_application_config",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_APPLICATION_CONFIG.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ApplicationConfigResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ApplicationConfigResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_application_config(&self, param_config_id: String) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/app_configs/{config_id}",
+            "{}{}",
             self.base_path,
-            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -1523,17 +1770,7 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_application_config", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -1546,30 +1783,7 @@ impl ApplicationConfigApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1581,7 +1795,12 @@ impl ApplicationConfigApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllApplicationConfigsResponse, ApiError> {
-        let mut url = format!("{}/v1/app_configs", self.base_path);
+        let operation_path = "/v1/app_configs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -1616,17 +1835,7 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_application_configs", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -1648,30 +1857,7 @@ impl ApplicationConfigApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1679,10 +1865,14 @@ impl ApplicationConfigApi for Client {
         &self,
         param_config_id: String,
     ) -> Result<models::ApplicationConfigResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/app_configs/{config_id}",
+            "{}{}",
             self.base_path,
-            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -1703,17 +1893,7 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_application_config", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -1735,30 +1915,7 @@ impl ApplicationConfigApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -1766,11 +1923,22 @@ impl ApplicationConfigApi for Client {
         &self,
         expected_hash: &[u8; 32],
     ) -> Result<models::RuntimeAppConfig, ApiError> {
-        let mut operation_path = "/v1/runtime/app_configs";
+        let raw_config = self.fetch_runtime_application_config()?;
+        deserialize_config_checked(&raw_config, expected_hash)
+    }
+
+    fn get_specific_runtime_application_config(
+        &self,
+        param_config_id: String,
+    ) -> Result<models::RuntimeAppConfig, ApiError> {
+        let operation_path = format!(
+            "/v1/runtime/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
             "{}{}",
             self.base_path,
-            self.remap_operation_path(operation_path)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -1791,147 +1959,35 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        let raw_config = request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
-                    String::from_utf8(body).map_err(|e| {
+        self.execute(
+            "get_specific_runtime_application_config",
+            Method::Get,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
                             format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
                     })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })?;
-
-        deserialize_config_checked(&raw_config, expected_hash)
-    }
-
-    fn get_specific_runtime_application_config(
-        &self,
-        param_config_id: String,
-    ) -> Result<models::RuntimeAppConfig, ApiError> {
-        let mut url = format!(
-            "{}/v1/runtime/app_configs/{config_id}",
-            self.base_path,
-            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
-        );
-
-        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
-
-        let query_string_str = query_string.finish();
-        if !query_string_str.is_empty() {
-            url += "?";
-            url += &query_string_str;
-        }
-
-        let url = match Url::from_str(&url) {
-            Ok(url) => url,
-            Err(err) => {
-                return Err(ApiError::new(
-                    format!("Unable to build URL: {}", err),
-                    SimpleErrorType::Permanent,
-                ))
+                    .and_then(|body| {
+                        serde_json::from_str::<models::RuntimeAppConfig>(body).map_err(|e| e.into())
+                    })
             }
-        };
-
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::RuntimeAppConfig>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn update_application_config(
@@ -1939,10 +1995,14 @@ impl ApplicationConfigApi for Client {
         param_config_id: String,
         param_body: models::UpdateApplicationConfigRequest,
     ) -> Result<models::ApplicationConfigResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/app_configs/{config_id}",
+            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/app_configs/{config_id}",
+            "{}{}",
             self.base_path,
-            config_id = utf8_percent_encode(&param_config_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -1963,71 +2023,40 @@ impl ApplicationConfigApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(
-            mimetypes::requests::UPDATE_APPLICATION_CONFIG.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_application_config",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_APPLICATION_CONFIG.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ApplicationConfigResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ApplicationConfigResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -2039,10 +2068,14 @@ impl ApprovalRequestsApi for Client {
         param_request_id: uuid::Uuid,
         param_body: Option<models::ApproveRequest>,
     ) -> Result<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/approve",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/approval_requests/{request_id}/approve",
+            "{}{}",
             self.base_path,
-            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2063,8 +2096,6 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = param_body.map(|ref body| {
             serde_json::to_string(body)
@@ -2072,75 +2103,47 @@ impl ApprovalRequestsApi for Client {
                 .into_bytes()
         });
 
-        if let Some(body) = body.as_ref() {
-            request = request.body(body.as_slice());
-        }
-
-        request = request.header(ContentType(
-            mimetypes::requests::APPROVE_APPROVAL_REQUEST.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "approve_approval_request",
+            Method::Post,
+            url,
+            body.as_deref(),
+            Some(mimetypes::requests::APPROVE_APPROVAL_REQUEST.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ApprovalRequest>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ApprovalRequest>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn create_approval_request(
         &self,
         param_body: models::ApprovalRequestRequest,
     ) -> Result<models::ApprovalRequest, ApiError> {
-        let mut url = format!("{}/v1/approval_requests", self.base_path);
+        let operation_path = "/v1/approval_requests";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -2160,78 +2163,50 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(
-            mimetypes::requests::CREATE_APPROVAL_REQUEST.clone(),
-        ));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                201 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_approval_request",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_APPROVAL_REQUEST.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            201 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ApprovalRequest>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ApprovalRequest>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_approval_request(&self, param_request_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/approval_requests/{request_id}",
+            "{}{}",
             self.base_path,
-            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2252,17 +2227,7 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_approval_request", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -2275,30 +2240,7 @@ impl ApprovalRequestsApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -2307,10 +2249,14 @@ impl ApprovalRequestsApi for Client {
         param_request_id: uuid::Uuid,
         param_body: Option<models::DenyRequest>,
     ) -> Result<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/deny",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/approval_requests/{request_id}/deny",
+            "{}{}",
             self.base_path,
-            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2331,76 +2277,41 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = param_body.map(|ref body| {
             serde_json::to_string(body)
                 .expect("impossible to fail to serialize")
                 .into_bytes()
         });
 
-        if let Some(body) = body.as_ref() {
-            request = request.body(body.as_slice());
-        }
-
-        request = request.header(ContentType(
-            mimetypes::requests::DENY_APPROVAL_REQUEST.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "deny_approval_request",
+            Method::Post,
+            url,
+            body.as_deref(),
+            Some(mimetypes::requests::DENY_APPROVAL_REQUEST.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ApprovalRequest>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ApprovalRequest>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn get_all_approval_requests(
@@ -2414,7 +2325,12 @@ impl ApprovalRequestsApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllApprovalRequests, ApiError> {
-        let mut url = format!("{}/v1/approval_requests", self.base_path);
+        let operation_path = "/v1/approval_requests";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -2458,17 +2374,7 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_approval_requests", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -2490,30 +2396,7 @@ impl ApprovalRequestsApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -2521,10 +2404,14 @@ impl ApprovalRequestsApi for Client {
         &self,
         param_request_id: uuid::Uuid,
     ) -> Result<models::ApprovalRequest, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/approval_requests/{request_id}",
+            "{}{}",
             self.base_path,
-            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2545,17 +2432,7 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_approval_request", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -2577,30 +2454,7 @@ impl ApprovalRequestsApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -2608,10 +2462,14 @@ impl ApprovalRequestsApi for Client {
         &self,
         param_request_id: uuid::Uuid,
     ) -> Result<models::ApprovableResult, ApiError> {
+        let operation_path = format!(
+            "/v1/approval_requests/{request_id}/result",
+            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/approval_requests/{request_id}/result",
+            "{}{}",
             self.base_path,
-            request_id = utf8_percent_encode(&param_request_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2632,17 +2490,7 @@ impl ApprovalRequestsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_approval_request_result", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -2664,30 +2512,7 @@ impl ApprovalRequestsApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 }
@@ -2695,11 +2520,16 @@ impl ApprovalRequestsApi for Client {
 impl AuthApi for Client {
     type Error = ApiError;
 
-    fn authenticate_user(
+    fn auth_discover(
         &self,
-        param_body: Option<models::AuthRequest>,
-    ) -> Result<models::AuthResponse, ApiError> {
-        let mut url = format!("{}/v1/sys/auth", self.base_path);
+        param_body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, ApiError> {
+        let operation_path = "/v1/sys/auth/discover";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -2719,29 +2549,137 @@ impl AuthApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
-        let body = param_body.map(|ref body| {
-            serde_json::to_string(body)
-                .expect("impossible to fail to serialize")
+        let body = serde_json::to_string(&param_body)
+            .expect("impossible to fail to serialize")
+            .into_bytes();
+
+        self.execute(
+            "auth_discover",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::AUTH_DISCOVER.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::AuthDiscoverResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+
+    fn authenticate_user(
+        &self,
+        param_body: Option<models::AuthRequest>,
+    ) -> Result<models::AuthResponse, ApiError> {
+        let operation_path = "/v1/sys/auth";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
+
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
+                    SimpleErrorType::Permanent,
+                ))
+            }
+        };
+
+        // Body parameter
+        let body = param_body.map(|ref body| {
+            serde_json::to_string(body)
+                .expect("impossible to fail to serialize")
                 .into_bytes()
         });
 
-        if let Some(body) = body.as_ref() {
-            request = request.body(body.as_slice());
-        }
+        self.execute(
+            "authenticate_user",
+            Method::Post,
+            url,
+            body.as_deref(),
+            Some(mimetypes::requests::AUTHENTICATE_USER.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::AuthResponse>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+
+    fn refresh_session(&self) -> Result<models::RefreshResponse, ApiError> {
+        let operation_path = "/v1/sys/session/refresh";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
-        request = request.header(ContentType(mimetypes::requests::AUTHENTICATE_USER.clone()));
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
                     SimpleErrorType::Permanent,
-                )
-            })
+                ))
+            }
+        };
+
+        self.execute("refresh_session", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -2759,33 +2697,11 @@ impl AuthApi for Client {
                             )
                         })
                         .and_then(|body| {
-                            serde_json::from_str::<models::AuthResponse>(body).map_err(|e| e.into())
+                            serde_json::from_str::<models::RefreshResponse>(body)
+                                .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 }
@@ -2797,7 +2713,12 @@ impl BuildApi for Client {
         &self,
         param_body: models::ConvertAppBuildRequest,
     ) -> Result<models::Build, ApiError> {
-        let mut url = format!("{}/v1/builds/convert-app", self.base_path);
+        let operation_path = "/v1/builds/convert-app";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -2817,76 +2738,52 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::CONVERT_APP_BUILD.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "convert_app_build",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CONVERT_APP_BUILD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn create_build(
         &self,
         param_body: models::CreateBuildRequest,
     ) -> Result<models::Build, ApiError> {
-        let mut url = format!("{}/v1/builds", self.base_path);
+        let operation_path = "/v1/builds";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -2906,75 +2803,50 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::CREATE_BUILD.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_build",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_BUILD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_build(&self, param_build_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/builds/{build_id}",
+            "{}{}",
             self.base_path,
-            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -2995,17 +2867,7 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_build", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -3018,30 +2880,7 @@ impl BuildApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3056,7 +2895,12 @@ impl BuildApi for Client {
         param_offset: Option<i32>,
         param_sort_by: Option<String>,
     ) -> Result<models::GetAllBuildsResponse, ApiError> {
-        let mut url = format!("{}/v1/builds", self.base_path);
+        let operation_path = "/v1/builds";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -3100,17 +2944,7 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_builds", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3132,38 +2966,19 @@ impl BuildApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_build(&self, param_build_id: uuid::Uuid) -> Result<models::Build, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/builds/{build_id}",
+            "{}{}",
             self.base_path,
-            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3184,17 +2999,7 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_build", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3215,30 +3020,7 @@ impl BuildApi for Client {
                             serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3251,10 +3033,14 @@ impl BuildApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllBuildDeploymentsResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/deployments/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/builds/deployments/{build_id}",
+            "{}{}",
             self.base_path,
-            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3290,17 +3076,7 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_build_deployments", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3322,30 +3098,7 @@ impl BuildApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3354,10 +3107,14 @@ impl BuildApi for Client {
         param_build_id: uuid::Uuid,
         param_body: models::BuildUpdateRequest,
     ) -> Result<models::Build, ApiError> {
+        let operation_path = format!(
+            "/v1/builds/{build_id}",
+            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/builds/{build_id}",
+            "{}{}",
             self.base_path,
-            build_id = utf8_percent_encode(&param_build_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3378,68 +3135,39 @@ impl BuildApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_BUILD.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_build",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_BUILD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Build>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -3447,10 +3175,14 @@ impl CertificateApi for Client {
     type Error = ApiError;
 
     fn get_certificate(&self, param_cert_id: uuid::Uuid) -> Result<models::Certificate, ApiError> {
+        let operation_path = format!(
+            "/v1/certificates/{cert_id}",
+            cert_id = utf8_percent_encode(&param_cert_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/certificates/{cert_id}",
+            "{}{}",
             self.base_path,
-            cert_id = utf8_percent_encode(&param_cert_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3471,17 +3203,7 @@ impl CertificateApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_certificate", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3502,30 +3224,7 @@ impl CertificateApi for Client {
                             serde_json::from_str::<models::Certificate>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3533,7 +3232,12 @@ impl CertificateApi for Client {
         &self,
         param_body: models::NewCertificateRequest,
     ) -> Result<models::TaskResult, ApiError> {
-        let mut url = format!("{}/v1/certificates", self.base_path);
+        let operation_path = "/v1/certificates";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -3553,68 +3257,39 @@ impl CertificateApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::NEW_CERTIFICATE.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "new_certificate",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::NEW_CERTIFICATE.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -3625,7 +3300,12 @@ impl DatasetApi for Client {
         &self,
         param_body: models::CreateDatasetRequest,
     ) -> Result<models::Dataset, ApiError> {
-        let mut url = format!("{}/v1/datasets", self.base_path);
+        let operation_path = "/v1/datasets";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -3645,76 +3325,51 @@ impl DatasetApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::CREATE_DATASET.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_dataset",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_DATASET.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Dataset>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Dataset>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_dataset(&self, param_dataset_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/datasets/{dataset_id}",
+            "{}{}",
             self.base_path,
-            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3735,17 +3390,7 @@ impl DatasetApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_dataset", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -3758,30 +3403,7 @@ impl DatasetApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3792,7 +3414,12 @@ impl DatasetApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllDatasetsResponse, ApiError> {
-        let mut url = format!("{}/v1/datasets", self.base_path);
+        let operation_path = "/v1/datasets";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -3824,17 +3451,7 @@ impl DatasetApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_datasets", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3856,38 +3473,19 @@ impl DatasetApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_dataset(&self, param_dataset_id: uuid::Uuid) -> Result<models::Dataset, ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/datasets/{dataset_id}",
+            "{}{}",
             self.base_path,
-            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3908,17 +3506,7 @@ impl DatasetApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_dataset", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -3939,30 +3527,7 @@ impl DatasetApi for Client {
                             serde_json::from_str::<models::Dataset>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -3971,10 +3536,14 @@ impl DatasetApi for Client {
         param_dataset_id: uuid::Uuid,
         param_body: models::DatasetUpdateRequest,
     ) -> Result<models::Dataset, ApiError> {
+        let operation_path = format!(
+            "/v1/datasets/{dataset_id}",
+            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/datasets/{dataset_id}",
+            "{}{}",
             self.base_path,
-            dataset_id = utf8_percent_encode(&param_dataset_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -3995,23 +3564,110 @@ impl DatasetApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_DATASET.clone()));
+        self.execute(
+            "update_dataset",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_DATASET.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Dataset>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+}
+
+impl EventsApi for Client {
+    type Error = ApiError;
+
+    fn get_all_events(
+        &self,
+        param_actor_type: Option<String>,
+        param_action_type: Option<String>,
+        param_severity: Option<String>,
+        param_entity_id: Option<uuid::Uuid>,
+        param_from: Option<i64>,
+        param_to: Option<i64>,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, ApiError> {
+        let operation_path = "/v1/events";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        if let Some(actor_type) = param_actor_type {
+            query_string.append_pair("actor_type", &actor_type.to_string());
+        }
+        if let Some(action_type) = param_action_type {
+            query_string.append_pair("action_type", &action_type.to_string());
+        }
+        if let Some(severity) = param_severity {
+            query_string.append_pair("severity", &severity.to_string());
+        }
+        if let Some(entity_id) = param_entity_id {
+            query_string.append_pair("entity_id", &entity_id.to_string());
+        }
+        if let Some(from) = param_from {
+            query_string.append_pair("from", &from.to_string());
+        }
+        if let Some(to) = param_to {
+            query_string.append_pair("to", &to.to_string());
+        }
+        if let Some(limit) = param_limit {
+            query_string.append_pair("limit", &limit.to_string());
+        }
+        if let Some(offset) = param_offset {
+            query_string.append_pair("offset", &offset.to_string());
+        }
+        if let Some(sort_by) = param_sort_by {
+            query_string.append_pair("sort_by", &sort_by.to_string());
+        }
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
                     SimpleErrorType::Permanent,
-                )
-            })
+                ))
+            }
+        };
+
+        self.execute("get_all_events", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4029,33 +3685,11 @@ impl DatasetApi for Client {
                             )
                         })
                         .and_then(|body| {
-                            serde_json::from_str::<models::Dataset>(body).map_err(|e| e.into())
+                            serde_json::from_str::<models::GetAllEventsResponse>(body)
+                                .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 }
@@ -4064,10 +3698,14 @@ impl NodeApi for Client {
     type Error = ApiError;
 
     fn deactivate_node(&self, param_node_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/deactivate",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/nodes/{node_id}/deactivate",
+            "{}{}",
             self.base_path,
-            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4088,17 +3726,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("deactivate_node", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -4111,30 +3739,7 @@ impl NodeApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -4149,7 +3754,12 @@ impl NodeApi for Client {
         param_offset: Option<i32>,
         param_sort_by: Option<String>,
     ) -> Result<models::GetAllNodesResponse, ApiError> {
-        let mut url = format!("{}/v1/nodes", self.base_path);
+        let operation_path = "/v1/nodes";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -4193,17 +3803,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_nodes", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4225,38 +3825,19 @@ impl NodeApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_node(&self, param_node_id: uuid::Uuid) -> Result<models::Node, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/nodes/{node_id}",
+            "{}{}",
             self.base_path,
-            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4277,17 +3858,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_node", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4308,30 +3879,7 @@ impl NodeApi for Client {
                             serde_json::from_str::<models::Node>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -4339,10 +3887,14 @@ impl NodeApi for Client {
         &self,
         param_node_id: uuid::Uuid,
     ) -> Result<models::Certificate, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/certificate",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/nodes/{node_id}/certificate",
+            "{}{}",
             self.base_path,
-            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4363,17 +3915,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_node_certificate", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4394,30 +3936,7 @@ impl NodeApi for Client {
                             serde_json::from_str::<models::Certificate>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -4425,10 +3944,14 @@ impl NodeApi for Client {
         &self,
         param_node_id: uuid::Uuid,
     ) -> Result<models::CertificateDetails, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}/certificate-details",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/nodes/{node_id}/certificate-details",
+            "{}{}",
             self.base_path,
-            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4449,17 +3972,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_node_certificate_details", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4481,35 +3994,17 @@ impl NodeApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_nodes_unique_labels(&self) -> Result<models::LabelsCount, ApiError> {
-        let mut url = format!("{}/v1/nodes/unique_labels/count", self.base_path);
+        let operation_path = "/v1/nodes/unique_labels/count";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -4529,17 +4024,7 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_nodes_unique_labels", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -4560,30 +4045,7 @@ impl NodeApi for Client {
                             serde_json::from_str::<models::LabelsCount>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -4591,7 +4053,12 @@ impl NodeApi for Client {
         &self,
         param_body: models::NodeProvisionRequest,
     ) -> Result<models::TaskResult, ApiError> {
-        let mut url = format!("{}/v1/nodes", self.base_path);
+        let operation_path = "/v1/nodes";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -4611,68 +4078,39 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::PROVISION_NODE.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "provision_node",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::PROVISION_NODE.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn update_node(
@@ -4680,10 +4118,14 @@ impl NodeApi for Client {
         param_node_id: uuid::Uuid,
         param_body: models::NodeUpdateRequest,
     ) -> Result<models::Node, ApiError> {
+        let operation_path = format!(
+            "/v1/nodes/{node_id}",
+            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/nodes/{node_id}",
+            "{}{}",
             self.base_path,
-            node_id = utf8_percent_encode(&param_node_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4704,75 +4146,51 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::UPDATE_NODE.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_node",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_NODE.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Node>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Node>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn update_node_status(
         &self,
         param_body: models::NodeStatusRequest,
     ) -> Result<models::NodeStatusResponse, ApiError> {
-        let mut url = format!("{}/v1/node/status", self.base_path);
+        let operation_path = "/v1/node/status";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -4792,69 +4210,40 @@ impl NodeApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_NODE_STATUS.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_node_status",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_NODE_STATUS.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::NodeStatusResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::NodeStatusResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -4865,7 +4254,12 @@ impl RegistryApi for Client {
         &self,
         param_registry_request: models::RegistryRequest,
     ) -> Result<models::Registry, ApiError> {
-        let mut url = format!("{}/v1/registry", self.base_path);
+        let operation_path = "/v1/registry";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -4885,76 +4279,51 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_registry_request)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::CREATE_REGISTRY.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_registry",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_REGISTRY.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Registry>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Registry>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_registry(&self, param_registry_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/registry/{registry_id}",
+            "{}{}",
             self.base_path,
-            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -4975,17 +4344,7 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_registry", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -4998,35 +4357,17 @@ impl RegistryApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_all_registries(&self) -> Result<Vec<models::Registry>, ApiError> {
-        let mut url = format!("{}/v1/registry", self.base_path);
+        let operation_path = "/v1/registry";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -5046,17 +4387,7 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_registries", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5078,38 +4409,19 @@ impl RegistryApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_registry(&self, param_registry_id: uuid::Uuid) -> Result<models::Registry, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/registry/{registry_id}",
+            "{}{}",
             self.base_path,
-            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5130,17 +4442,7 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_registry", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5161,30 +4463,7 @@ impl RegistryApi for Client {
                             serde_json::from_str::<models::Registry>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -5192,10 +4471,14 @@ impl RegistryApi for Client {
         &self,
         param_app_id: uuid::Uuid,
     ) -> Result<models::AppRegistryResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/app/{app_id}",
+            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/registry/app/{app_id}",
+            "{}{}",
             self.base_path,
-            app_id = utf8_percent_encode(&param_app_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5216,17 +4499,7 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_registry_for_app", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5248,30 +4521,7 @@ impl RegistryApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -5279,7 +4529,12 @@ impl RegistryApi for Client {
         &self,
         param_image_name: String,
     ) -> Result<models::ImageRegistryResponse, ApiError> {
-        let mut url = format!("{}/v1/image/registry", self.base_path);
+        let operation_path = "/v1/image/registry";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
         query_string.append_pair("image_name", &param_image_name.to_string());
@@ -5300,17 +4555,7 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_registry_for_image", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5332,30 +4577,7 @@ impl RegistryApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -5364,10 +4586,14 @@ impl RegistryApi for Client {
         param_registry_id: uuid::Uuid,
         param_body: models::UpdateRegistryRequest,
     ) -> Result<models::Registry, ApiError> {
+        let operation_path = format!(
+            "/v1/registry/{registry_id}",
+            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/registry/{registry_id}",
+            "{}{}",
             self.base_path,
-            registry_id = utf8_percent_encode(&param_registry_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5388,68 +4614,39 @@ impl RegistryApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::UPDATE_REGISTRY.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_registry",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_REGISTRY.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::Registry>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Registry>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -5457,7 +4654,12 @@ impl SystemApi for Client {
     type Error = ApiError;
 
     fn get_manager_version(&self) -> Result<models::VersionResponse, ApiError> {
-        let mut url = format!("{}/v1/sys/version", self.base_path);
+        let operation_path = "/v1/sys/version";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -5477,17 +4679,7 @@ impl SystemApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_manager_version", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5509,30 +4701,7 @@ impl SystemApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 }
@@ -5552,7 +4721,12 @@ impl TaskApi for Client {
         param_sort_by: Option<String>,
         param_base_filters: Option<String>,
     ) -> Result<models::GetAllTasksResponse, ApiError> {
-        let mut url = format!("{}/v1/tasks", self.base_path);
+        let operation_path = "/v1/tasks";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -5599,17 +4773,7 @@ impl TaskApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_tasks", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5631,38 +4795,19 @@ impl TaskApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_task(&self, param_task_id: uuid::Uuid) -> Result<models::Task, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/tasks/{task_id}",
+            "{}{}",
             self.base_path,
-            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5683,17 +4828,7 @@ impl TaskApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_task", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5714,38 +4849,19 @@ impl TaskApi for Client {
                             serde_json::from_str::<models::Task>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_task_status(&self, param_task_id: uuid::Uuid) -> Result<models::TaskResult, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/status/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/tasks/status/{task_id}",
+            "{}{}",
             self.base_path,
-            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5766,17 +4882,7 @@ impl TaskApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_task_status", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -5797,30 +4903,7 @@ impl TaskApi for Client {
                             serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -5829,10 +4912,14 @@ impl TaskApi for Client {
         param_task_id: uuid::Uuid,
         param_body: models::TaskUpdateRequest,
     ) -> Result<models::TaskResult, ApiError> {
+        let operation_path = format!(
+            "/v1/tasks/{task_id}",
+            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/tasks/{task_id}",
+            "{}{}",
             self.base_path,
-            task_id = utf8_percent_encode(&param_task_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -5853,71 +4940,42 @@ impl TaskApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::UPDATE_TASK.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_task",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_TASK.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
-    }
-}
-
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::TaskResult>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+}
+
 impl ToolsApi for Client {
     type Error = ApiError;
 
@@ -5925,7 +4983,12 @@ impl ToolsApi for Client {
         &self,
         param_body: models::ConversionRequest,
     ) -> Result<models::ConversionResponse, ApiError> {
-        let mut url = format!("{}/v1/tools/converter/convert-app", self.base_path);
+        let operation_path = "/v1/tools/converter/convert-app";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -5945,70 +5008,41 @@ impl ToolsApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::CONVERT_APP.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "convert_app",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CONVERT_APP.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ConversionResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ConversionResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -6016,7 +5050,12 @@ impl UsersApi for Client {
     type Error = ApiError;
 
     fn accept_terms_and_conditions(&self) -> Result<(), ApiError> {
-        let mut url = format!("{}/v1/users/terms_and_conditions", self.base_path);
+        let operation_path = "/v1/users/terms_and_conditions";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6036,58 +5075,36 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
-
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "accept_terms_and_conditions",
+            Method::Patch,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn change_password(&self, param_body: models::PasswordChangeRequest) -> Result<(), ApiError> {
-        let mut url = format!("{}/v1/users/change_password", self.base_path);
+        let operation_path = "/v1/users/change_password";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6107,67 +5124,43 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::CHANGE_PASSWORD.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
 
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "change_password",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CHANGE_PASSWORD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn confirm_email(
         &self,
         param_body: models::ConfirmEmailRequest,
     ) -> Result<models::ConfirmEmailResponse, ApiError> {
-        let mut url = format!("{}/v1/users/confirm_email", self.base_path);
+        let operation_path = "/v1/users/confirm_email";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6187,73 +5180,49 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::CONFIRM_EMAIL.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "confirm_email",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CONFIRM_EMAIL.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ConfirmEmailResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ConfirmEmailResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn create_user(&self, param_body: models::SignupRequest) -> Result<models::User, ApiError> {
-        let mut url = format!("{}/v1/users", self.base_path);
+        let operation_path = "/v1/users";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6273,75 +5242,50 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::CREATE_USER.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                201 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_user",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_USER.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            201 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::User>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::User>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_user_account(&self, param_user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -6362,17 +5306,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_user_account", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -6385,38 +5319,19 @@ impl UsersApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn delete_user_from_account(&self, param_user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/accounts",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}/accounts",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -6437,17 +5352,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_user_from_account", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -6460,35 +5365,17 @@ impl UsersApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn forgot_password(&self, param_body: models::ForgotPasswordRequest) -> Result<(), ApiError> {
-        let mut url = format!("{}/v1/users/forgot_password", self.base_path);
+        let operation_path = "/v1/users/forgot_password";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6508,60 +5395,31 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::FORGOT_PASSWORD.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
 
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "forgot_password",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::FORGOT_PASSWORD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn get_all_users(
@@ -6571,7 +5429,12 @@ impl UsersApi for Client {
         param_offset: Option<i32>,
         param_sort_by: Option<String>,
     ) -> Result<models::GetAllUsersResponse, ApiError> {
-        let mut url = format!("{}/v1/users", self.base_path);
+        let operation_path = "/v1/users";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6603,17 +5466,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_users", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -6635,35 +5488,17 @@ impl UsersApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_logged_in_user(&self) -> Result<models::User, ApiError> {
-        let mut url = format!("{}/v1/user", self.base_path);
+        let operation_path = "/v1/user";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6683,17 +5518,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_logged_in_user", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -6714,38 +5539,19 @@ impl UsersApi for Client {
                             serde_json::from_str::<models::User>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_user(&self, param_user_id: uuid::Uuid) -> Result<models::User, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -6766,17 +5572,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_user", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -6797,35 +5593,17 @@ impl UsersApi for Client {
                             serde_json::from_str::<models::User>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn invite_user(&self, param_body: models::InviteUserRequest) -> Result<models::User, ApiError> {
-        let mut url = format!("{}/v1/users/invite", self.base_path);
+        let operation_path = "/v1/users/invite";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6845,75 +5623,51 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::INVITE_USER.clone()));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                201 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "invite_user",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::INVITE_USER.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            201 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::User>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::User>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn process_invitations(
         &self,
         param_body: models::ProcessInviteRequest,
     ) -> Result<(), ApiError> {
-        let mut url = format!("{}/v1/users/process_invite", self.base_path);
+        let operation_path = "/v1/users/process_invite";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -6933,66 +5687,40 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(
-            mimetypes::requests::PROCESS_INVITATIONS.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
 
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "process_invitations",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::PROCESS_INVITATIONS.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn resend_confirm_email(&self) -> Result<(), ApiError> {
-        let mut url = format!("{}/v1/users/resend_confirm_email", self.base_path);
+        let operation_path = "/v1/users/resend_confirm_email";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -7012,17 +5740,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("resend_confirm_email", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -7035,38 +5753,19 @@ impl UsersApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn resend_invitation(&self, param_user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/resend_invite",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}/resend_invite",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7087,17 +5786,7 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("resend_invitation", Method::Post, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -7110,30 +5799,7 @@ impl UsersApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -7142,10 +5808,14 @@ impl UsersApi for Client {
         param_user_id: uuid::Uuid,
         param_body: models::PasswordResetRequest,
     ) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/reset_password",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}/reset_password",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7166,60 +5836,31 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(mimetypes::requests::RESET_PASSWORD.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
 
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "reset_password",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::RESET_PASSWORD.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn update_user(
@@ -7227,10 +5868,14 @@ impl UsersApi for Client {
         param_user_id: uuid::Uuid,
         param_body: models::UpdateUserRequest,
     ) -> Result<models::User, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7251,68 +5896,39 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Patch, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(mimetypes::requests::UPDATE_USER.clone()));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_user",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_USER.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::User>(body).map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::User>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn validate_password_reset_token(
@@ -7320,10 +5936,14 @@ impl UsersApi for Client {
         param_user_id: uuid::Uuid,
         param_body: models::ValidateTokenRequest,
     ) -> Result<models::ValidateTokenResponse, ApiError> {
+        let operation_path = format!(
+            "/v1/users/{user_id}/validate_token",
+            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/users/{user_id}/validate_token",
+            "{}{}",
             self.base_path,
-            user_id = utf8_percent_encode(&param_user_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7344,71 +5964,40 @@ impl UsersApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(
-            mimetypes::requests::VALIDATE_PASSWORD_RESET_TOKEN.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "validate_password_reset_token",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::VALIDATE_PASSWORD_RESET_TOKEN.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::ValidateTokenResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::ValidateTokenResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -7419,7 +6008,12 @@ impl WorkflowApi for Client {
         &self,
         param_body: models::CreateWorkflowGraph,
     ) -> Result<models::WorkflowGraph, ApiError> {
-        let mut url = format!("{}/v1/workflows/draft/graphs", self.base_path);
+        let operation_path = "/v1/workflows/draft/graphs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -7439,79 +6033,51 @@ impl WorkflowApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(
-            mimetypes::requests::CREATE_WORKFLOW_GRAPH.clone(),
-        ));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_workflow_graph",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_WORKFLOW_GRAPH.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::WorkflowGraph>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::WorkflowGraph>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_workflow_graph(&self, param_graph_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/workflows/draft/graphs/{graph_id}",
+            "{}{}",
             self.base_path,
-            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7532,17 +6098,7 @@ impl WorkflowApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("delete_workflow_graph", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 204 => {
                     let mut body = Vec::new();
@@ -7555,30 +6111,7 @@ impl WorkflowApi for Client {
 
                     Ok(())
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -7592,7 +6125,12 @@ impl WorkflowApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllWorkflowGraphsResponse, ApiError> {
-        let mut url = format!("{}/v1/workflows/draft/graphs", self.base_path);
+        let operation_path = "/v1/workflows/draft/graphs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -7633,17 +6171,7 @@ impl WorkflowApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_all_workflow_graphs", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -7665,30 +6193,7 @@ impl WorkflowApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -7696,10 +6201,14 @@ impl WorkflowApi for Client {
         &self,
         param_graph_id: uuid::Uuid,
     ) -> Result<models::WorkflowGraph, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/workflows/draft/graphs/{graph_id}",
+            "{}{}",
             self.base_path,
-            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7720,17 +6229,7 @@ impl WorkflowApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_workflow_graph", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -7752,30 +6251,7 @@ impl WorkflowApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -7784,10 +6260,14 @@ impl WorkflowApi for Client {
         param_graph_id: uuid::Uuid,
         param_body: models::UpdateWorkflowGraph,
     ) -> Result<models::WorkflowGraph, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/draft/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/workflows/draft/graphs/{graph_id}",
+            "{}{}",
             self.base_path,
-            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -7808,71 +6288,39 @@ impl WorkflowApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Put, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
-
-        request = request.header(ContentType(
-            mimetypes::requests::UPDATE_WORKFLOW_GRAPH.clone(),
-        ));
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "update_workflow_graph",
+            Method::Put,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_WORKFLOW_GRAPH.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::WorkflowGraph>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::WorkflowGraph>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 }
 
@@ -7883,7 +6331,12 @@ impl WorkflowFinalApi for Client {
         &self,
         param_body: models::CreateFinalWorkflowGraph,
     ) -> Result<models::FinalWorkflow, ApiError> {
-        let mut url = format!("{}/v1/workflows/final/graphs", self.base_path);
+        let operation_path = "/v1/workflows/final/graphs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -7903,72 +6356,40 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         // Body parameter
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(
-            mimetypes::requests::CREATE_FINAL_WORKFLOW_GRAPH.clone(),
-        ));
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "create_final_workflow_graph",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_FINAL_WORKFLOW_GRAPH.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::FinalWorkflow>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::FinalWorkflow>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn delete_final_workflow_graph(
@@ -7976,12 +6397,16 @@ impl WorkflowFinalApi for Client {
         param_graph_id: uuid::Uuid,
         param_version: String,
     ) -> Result<(), ApiError> {
-        let mut url = format!(
-            "{}/v1/workflows/final/graphs/{graph_id}/{version}",
-            self.base_path,
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}/{version}",
             graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET),
             version = utf8_percent_encode(&param_version.to_string(), ID_ENCODE_SET)
         );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -8001,54 +6426,27 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Delete, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                204 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
-                        ApiError::new(
-                            format!("Failed to read response: {}", e),
-                            SimpleErrorType::Temporary,
-                        )
-                    })?;
-
-                    Ok(())
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+        self.execute(
+            "delete_final_workflow_graph",
+            Method::Delete,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            204 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+
+                Ok(())
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn get_all_final_workflow_graphs(
@@ -8060,7 +6458,12 @@ impl WorkflowFinalApi for Client {
         param_limit: Option<i32>,
         param_offset: Option<i32>,
     ) -> Result<models::GetAllFinalWorkflowGraphsResponse, ApiError> {
-        let mut url = format!("{}/v1/workflows/final/graphs", self.base_path);
+        let operation_path = "/v1/workflows/final/graphs";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -8098,63 +6501,36 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "get_all_final_workflow_graphs",
+            Method::Get,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::GetAllFinalWorkflowGraphsResponse>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::GetAllFinalWorkflowGraphsResponse>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn get_final_workflow_graph(
@@ -8162,12 +6538,16 @@ impl WorkflowFinalApi for Client {
         param_graph_id: uuid::Uuid,
         param_version: String,
     ) -> Result<models::VersionInFinalWorkflow, ApiError> {
-        let mut url = format!(
-            "{}/v1/workflows/final/graphs/{graph_id}/{version}",
-            self.base_path,
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}/{version}",
             graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET),
             version = utf8_percent_encode(&param_version.to_string(), ID_ENCODE_SET)
         );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -8187,17 +6567,7 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_final_workflow_graph", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -8219,30 +6589,7 @@ impl WorkflowFinalApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -8250,10 +6597,14 @@ impl WorkflowFinalApi for Client {
         &self,
         param_graph_id: uuid::Uuid,
     ) -> Result<models::FinalWorkflow, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/workflows/final/graphs/{graph_id}",
+            "{}{}",
             self.base_path,
-            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -8274,63 +6625,35 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
-            .and_then(|mut response| match response.status.to_u16() {
-                200 => {
-                    let mut body = Vec::new();
-                    response.read_to_end(&mut body).map_err(|e| {
+        self.execute(
+            "get_full_final_workflow_graph",
+            Method::Get,
+            url,
+            None,
+            None,
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
                         ApiError::new(
-                            format!("Failed to read response: {}", e),
+                            format!("Response was not valid UTF8: {}", e),
                             SimpleErrorType::Temporary,
                         )
-                    })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::FinalWorkflow>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
-            })
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::FinalWorkflow>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
     }
 
     fn update_final_workflow_graph(
@@ -8338,10 +6661,14 @@ impl WorkflowFinalApi for Client {
         param_graph_id: uuid::Uuid,
         param_body: models::CreateWorkflowVersionRequest,
     ) -> Result<models::VersionInFinalWorkflow, ApiError> {
+        let operation_path = format!(
+            "/v1/workflows/final/graphs/{graph_id}",
+            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/workflows/final/graphs/{graph_id}",
+            "{}{}",
             self.base_path,
-            graph_id = utf8_percent_encode(&param_graph_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -8362,27 +6689,140 @@ impl WorkflowFinalApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Post, url);
-        request = request.headers(self.headers.clone());
         let body = serde_json::to_string(&param_body)
             .expect("impossible to fail to serialize")
             .into_bytes();
-        request = request.body(body.as_slice());
 
-        request = request.header(ContentType(
-            mimetypes::requests::UPDATE_FINAL_WORKFLOW_GRAPH.clone(),
-        ));
+        self.execute(
+            "update_final_workflow_graph",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_FINAL_WORKFLOW_GRAPH.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::VersionInFinalWorkflow>(body)
+                            .map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+}
 
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
+impl ZoneApi for Client {
+    type Error = ApiError;
+
+    fn create_zone(&self, param_body: models::AddZoneRequest) -> Result<models::Zone, ApiError> {
+        let operation_path = "/v1/zones";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
+
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
                     SimpleErrorType::Permanent,
-                )
-            })
+                ))
+            }
+        };
+
+        // Body parameter
+        let body = serde_json::to_string(&param_body)
+            .expect("impossible to fail to serialize")
+            .into_bytes();
+
+        self.execute(
+            "create_zone",
+            Method::Post,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::CREATE_ZONE.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Zone>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
+
+    fn delete_zone(&self, param_zone_id: uuid::Uuid) -> Result<(), ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
+
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
+                    SimpleErrorType::Permanent,
+                ))
+            }
+        };
+
+        self.execute("delete_zone", Method::Delete, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
-                200 => {
+                204 => {
                     let mut body = Vec::new();
                     response.read_to_end(&mut body).map_err(|e| {
                         ApiError::new(
@@ -8390,54 +6830,22 @@ impl WorkflowFinalApi for Client {
                             SimpleErrorType::Temporary,
                         )
                     })?;
-                    str::from_utf8(&body)
-                        .map_err(|e| {
-                            ApiError::new(
-                                format!("Response was not valid UTF8: {}", e),
-                                SimpleErrorType::Temporary,
-                            )
-                        })
-                        .and_then(|body| {
-                            serde_json::from_str::<models::VersionInFinalWorkflow>(body)
-                                .map_err(|e| e.into())
-                        })
-                }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
+
+                    Ok(())
                 }
+                _ => Err(unexpected_response(response)),
             })
     }
-}
-
-impl ZoneApi for Client {
-    type Error = ApiError;
 
     fn get_zone(&self, param_zone_id: uuid::Uuid) -> Result<models::Zone, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/zones/{zone_id}",
+            "{}{}",
             self.base_path,
-            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -8458,17 +6866,7 @@ impl ZoneApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_zone", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -8489,30 +6887,7 @@ impl ZoneApi for Client {
                             serde_json::from_str::<models::Zone>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
@@ -8520,10 +6895,14 @@ impl ZoneApi for Client {
         &self,
         param_zone_id: uuid::Uuid,
     ) -> Result<models::ZoneJoinToken, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}/token",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
         let mut url = format!(
-            "{}/v1/zones/{zone_id}/token",
+            "{}{}",
             self.base_path,
-            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+            self.remap_operation_path(&operation_path)
         );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
@@ -8544,17 +6923,7 @@ impl ZoneApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_zone_join_token", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -8576,35 +6945,17 @@ impl ZoneApi for Client {
                                 .map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
 
     fn get_zones(&self) -> Result<Vec<models::Zone>, ApiError> {
-        let mut url = format!("{}/v1/zones", self.base_path);
+        let operation_path = "/v1/zones";
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(operation_path)
+        );
 
         let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
 
@@ -8624,17 +6975,7 @@ impl ZoneApi for Client {
             }
         };
 
-        let mut request = self.hyper_client.request(Method::Get, url);
-        request = request.headers(self.headers.clone());
-
-        request
-            .send()
-            .map_err(|e| {
-                ApiError::new(
-                    format!("No response received: {}", e),
-                    SimpleErrorType::Permanent,
-                )
-            })
+        self.execute("get_zones", Method::Get, url, None, None)
             .and_then(|mut response| match response.status.to_u16() {
                 200 => {
                     let mut body = Vec::new();
@@ -8655,32 +6996,77 @@ impl ZoneApi for Client {
                             serde_json::from_str::<Vec<models::Zone>>(body).map_err(|e| e.into())
                         })
                 }
-                code => {
-                    let headers = response.headers.clone();
-                    let mut body = Vec::new();
-                    let result = response.read_to_end(&mut body);
-                    let err_type = match response.status.is_server_error() {
-                        false => SimpleErrorType::Permanent,
-                        true => SimpleErrorType::Temporary,
-                    };
-                    Err(ApiError::new(
-                        format!(
-                            "Unexpected response code {}:\n{:?}\n\n{}",
-                            code,
-                            headers,
-                            match result {
-                                Ok(_) => match str::from_utf8(&body) {
-                                    Ok(body) => Cow::from(body),
-                                    Err(e) => Cow::from(format!("<Body was not UTF8: {:?}>", e)),
-                                },
-                                Err(e) => Cow::from(format!("<Failed to read body: {}>", e)),
-                            }
-                        ),
-                        err_type,
-                    ))
-                }
+                _ => Err(unexpected_response(response)),
             })
     }
+
+    fn update_zone(
+        &self,
+        param_zone_id: uuid::Uuid,
+        param_body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, ApiError> {
+        let operation_path = format!(
+            "/v1/zones/{zone_id}",
+            zone_id = utf8_percent_encode(&param_zone_id.to_string(), ID_ENCODE_SET)
+        );
+        let mut url = format!(
+            "{}{}",
+            self.base_path,
+            self.remap_operation_path(&operation_path)
+        );
+
+        let mut query_string = self::url::form_urlencoded::Serializer::new("".to_owned());
+
+        let query_string_str = query_string.finish();
+        if !query_string_str.is_empty() {
+            url += "?";
+            url += &query_string_str;
+        }
+
+        let url = match Url::from_str(&url) {
+            Ok(url) => url,
+            Err(err) => {
+                return Err(ApiError::new(
+                    format!("Unable to build URL: {}", err),
+                    SimpleErrorType::Permanent,
+                ))
+            }
+        };
+
+        let body = serde_json::to_string(&param_body)
+            .expect("impossible to fail to serialize")
+            .into_bytes();
+
+        self.execute(
+            "update_zone",
+            Method::Patch,
+            url,
+            Some(body.as_slice()),
+            Some(mimetypes::requests::UPDATE_ZONE.clone()),
+        )
+        .and_then(|mut response| match response.status.to_u16() {
+            200 => {
+                let mut body = Vec::new();
+                response.read_to_end(&mut body).map_err(|e| {
+                    ApiError::new(
+                        format!("Failed to read response: {}", e),
+                        SimpleErrorType::Temporary,
+                    )
+                })?;
+                str::from_utf8(&body)
+                    .map_err(|e| {
+                        ApiError::new(
+                            format!("Response was not valid UTF8: {}", e),
+                            SimpleErrorType::Temporary,
+                        )
+                    })
+                    .and_then(|body| {
+                        serde_json::from_str::<models::Zone>(body).map_err(|e| e.into())
+                    })
+            }
+            _ => Err(unexpected_response(response)),
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -8688,6 +7074,7 @@ pub enum ClientInitError {
     InvalidScheme,
     InvalidUri(hyper::error::ParseError),
     MissingHost,
+    TlsError(String),
 }
 
 impl From<hyper::error::ParseError> for ClientInitError {
@@ -8708,38 +7095,30 @@ impl error::Error for ClientInitError {
     }
 }
 
-fn deserialize_config_checked(
-    raw_config: &str,
-    expected_hash: &[u8; SHA256_BYTE_LENGTH],
-) -> Result<models::RuntimeAppConfig, ApiError> {
-    let result = serde_json::from_str::<models::RuntimeAppConfig>(&raw_config).map_err(|e| {
+fn deserialize_config(raw_config: &str) -> Result<models::RuntimeAppConfig, ApiError> {
+    serde_json::from_str::<models::RuntimeAppConfig>(raw_config).map_err(|e| {
         ApiError::new(
             format!("Failed to serialize RuntimeAppConfig to json: {}", e),
             SimpleErrorType::Permanent,
         )
-    })?;
+    })
+}
 
-    let hashed_config_part = serde_json::to_string(&result.config).map_err(|e| {
-        ApiError::new(
-            format!("Failed to serialize HashedConfig to json: {}", e),
-            SimpleErrorType::Permanent,
-        )
-    })?;
+pub(crate) fn deserialize_config_checked(
+    raw_config: &str,
+    expected_hash: &[u8; SHA256_BYTE_LENGTH],
+) -> Result<models::RuntimeAppConfig, ApiError> {
+    let result = deserialize_config(raw_config)?;
 
-    let hash = compute_sha256(hashed_config_part.as_bytes()).map_err(|e| {
-        ApiError::new(
-            format!("Unable to hash app config: {}", e),
-            SimpleErrorType::Permanent,
-        )
-    })?;
+    let hash = result.config.canonical_hash();
 
-    if hash != *expected_hash {
+    if *hash != *expected_hash {
         Err(ApiError::new(
             format!(
-                "App config hash mismatch. Expected {:?}, but got {:?}.",
-                hash, expected_hash
-            )
-            .to_string(),
+                "App config hash mismatch. Expected {}, but got {}.",
+                Sha256Hash::from(*expected_hash),
+                hash
+            ),
             SimpleErrorType::Permanent,
         ))
     } else {
@@ -8747,24 +7126,14 @@ fn deserialize_config_checked(
     }
 }
 
-fn compute_sha256(input: &[u8]) -> Result<[u8; SHA256_BYTE_LENGTH], ApiError> {
-    use mbedtls::hash::{Md, Type};
-    let mut digest = [0; SHA256_BYTE_LENGTH];
-    Md::hash(Type::Sha256, input, &mut digest).map_err(|e| {
-        ApiError::new(
-            format!("Unable to hash app config: {}", e),
-            SimpleErrorType::Permanent,
-        )
-    })?;
-
-    Ok(digest)
-}
-
 #[cfg(test)]
 mod tests {
+    use crate::{AppApi, AuthApi, SimpleErrorType, SystemApi};
     use crate::{ApplicationConfigApi, Client, Sha256Hash, SHA256_BYTE_LENGTH, SHA256_CHAR_LENGTH};
-    use client::deserialize_config_checked;
+    use client::{deserialize_config_checked, Credentials, PathMode, RetryPolicy};
+    use models::EventSeverity;
     use std::convert::{From, TryFrom};
+    use std::time::Duration;
 
     #[test]
     fn test_valid_hash() {
@@ -8792,16 +7161,18 @@ mod tests {
         assert!(result.is_ok())
     }
 
-    // commented out because changing config json manually and recomputing the hash using 'sha256sum' might produce faulty tests.
-    /*#[test]
+    #[test]
     fn test_valid_hash_with_data_sets() {
         // A modified version of RuntimeAppConfig with included datasets and sanitized personal data.
-        // The expected hash in this case comes from running 'sha256sum` utility over the json.
+        // The config part is in canonical form, so the expected hash is the 'sha256sum' of it.
         let json_data = r#"{"config":{"app_config":{},"labels":{"autoshutdown":"True","location":"East US","mxK4gaGv":"lLI6hgIA"},"zone_ca":["Certificate"],"workflow":{"workflow_id":"c375e923-1390-4440-b9e6-ad9977557e5e","app_name":"vZq7oNK9","port_map":{"input":{"2RAsgVoI":{"dataset":{"id":"e97447d5-6e1f-4cc8-9a56-cf4502f9ddd0","acct_id":"c5232c96-29bc-4c89-afcd-ee8e772df513","group_id":"5ce01a15-4c14-479c-834f-1ddd485dfeb2"}}},"output":{"bZbrS5DR":{"dataset":{"id":"0e7af6ec-4268-40ec-b341-cd17037dfb25","acct_id":"c5232c96-29bc-4c89-afcd-ee8e772df513","group_id":"5ce01a15-4c14-479c-834f-1ddd485dfeb2"}}}},"app_acct_id":"c5232c96-29bc-4c89-afcd-ee8e772df513","app_group_id":"5ce01a15-4c14-479c-834f-1ddd485dfeb2"}},"extra":{"connections":{"input":{"2RAsgVoI":{"dataset":{"location":"some-location","credentials":{"sdkms":{"credentials_url":"some-url","credentials_key_name":"some-key","sdkms_app_id":"0b73608a-0e2c-42e5-ba12-64f13e165120"}}}}},"output":{"bZbrS5DR":{"dataset":{"location":"some-location","credentials":{"sdkms":{"credentials_url":"some-url","credentials_key_name":"some-key","sdkms_app_id":"0b73608a-0e2c-42e5-ba12-64f13e165120"}}}}}}}}"#;
-        let expected_hash = Sha256Hash::try_from("2f4886ff1efa09773e3b656fb5b9a31d0439fdeecbe12d61a6767651466cbbc4").unwrap();
+        let expected_hash = Sha256Hash::try_from(
+            "2f4886ff1efa09773e3b656fb5b9a31d0439fdeecbe12d61a6767651466cbbc4",
+        )
+        .unwrap();
         let result = deserialize_config_checked(json_data, &expected_hash.0);
         assert!(result.is_ok())
-    }*/
+    }
 
     #[test]
     fn test_valid_hash_with_application() {
@@ -8867,4 +7238,368 @@ mod tests {
         let _ = client.get_runtime_application_config(&expected_hash);
         mock_remap_path.assert();
     }
+
+    #[test]
+    fn test_retry_temporary_failures() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_retry_policy(
+                RetryPolicy::new(3)
+                    .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+            );
+
+        let unavailable = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(503)
+            .expect(2)
+            .create();
+        let ok = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+
+        let version = client.get_manager_version().unwrap();
+        assert_eq!(version.version.as_deref(), Some("1.0"));
+        unavailable.assert();
+        ok.assert();
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_max_attempts() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_retry_policy(
+                RetryPolicy::new(2)
+                    .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+            );
+
+        let unavailable = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(2)
+            .create();
+
+        let err = client.get_manager_version().unwrap_err();
+        assert_eq!(err.error_type(), SimpleErrorType::Permanent);
+        unavailable.assert();
+    }
+
+    #[test]
+    fn test_no_retry_for_non_idempotent_requests() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_retry_policy(
+                RetryPolicy::new(3)
+                    .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+            );
+
+        let unavailable = server
+            .mock("POST", "/v1/sys/auth")
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let err = client.authenticate_user(None).unwrap_err();
+        assert_eq!(err.error_type(), SimpleErrorType::Temporary);
+        unavailable.assert();
+    }
+
+    #[test]
+    fn test_no_retry_by_default() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url()).unwrap();
+
+        let unavailable = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        assert!(client.get_manager_version().is_err());
+        unavailable.assert();
+    }
+
+    #[test]
+    fn test_error_carries_response() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url()).unwrap();
+
+        let body = r#"{"message":"Account not found","code":"NOT_FOUND","severity":"ERROR"}"#;
+        let not_found = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(404)
+            .with_header("X-Request-Id", "42")
+            .with_body(body)
+            .create();
+
+        let err = client.get_manager_version().unwrap_err();
+        assert_eq!(err.status(), Some(404));
+        assert_eq!(err.header("x-request-id"), Some("42"));
+        assert_eq!(err.body(), Some(body.as_bytes()));
+        let event = err.event().unwrap();
+        assert_eq!(event.message, "Account not found");
+        assert_eq!(event.severity, Some(EventSeverity::ERROR));
+        assert!(err.is_not_found());
+        assert!(!err.is_session_expired());
+        not_found.assert();
+    }
+
+    #[test]
+    fn test_all_operations_use_new_paths() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_new_paths();
+
+        let version = server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+
+        client.get_manager_version().unwrap();
+        version.assert();
+    }
+
+    #[test]
+    fn test_auto_path_mode_detects_new_paths() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_path_mode(PathMode::Auto);
+        assert!(!client.uses_new_paths());
+
+        let version = server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(3)
+            .create();
+
+        client.get_manager_version().unwrap();
+        client.clone().get_manager_version().unwrap();
+        assert!(client.uses_new_paths());
+        // One probe plus the two requests
+        version.assert();
+    }
+
+    #[test]
+    fn test_auto_path_mode_falls_back_to_legacy_paths() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_path_mode(PathMode::Auto);
+
+        let probe = server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(404)
+            .expect(1)
+            .create();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(2)
+            .create();
+
+        client.get_manager_version().unwrap();
+        client.get_manager_version().unwrap();
+        assert!(!client.uses_new_paths());
+        probe.assert();
+        version.assert();
+    }
+
+    #[test]
+    fn test_auto_path_mode_does_not_remember_inconclusive_probe() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_path_mode(PathMode::Auto);
+
+        let unavailable = server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(503)
+            .expect(1)
+            .create();
+        let legacy = server
+            .mock("GET", "/v1/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(2)
+            .create();
+
+        // The probe is not repeated right away.
+        client.get_manager_version().unwrap();
+        client.get_manager_version().unwrap();
+        assert!(!client.uses_new_paths());
+        assert_eq!(client.path_detection().new_paths, None);
+        unavailable.assert();
+        legacy.assert();
+
+        unavailable.remove();
+        let version = server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(200)
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(2)
+            .create();
+        client.path_detection().next_probe = None;
+        client.get_manager_version().unwrap();
+        assert!(client.uses_new_paths());
+        version.assert();
+    }
+
+    #[test]
+    fn test_auto_path_mode_falls_back_per_operation() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_path_mode(PathMode::Auto);
+        let app_id = uuid::Uuid::new_v4();
+
+        server
+            .mock("GET", "/api/v1/confidential_computing/sys/version")
+            .with_status(503)
+            .create();
+
+        // Objects that do not exist are not found under either prefix.
+        let missing_id = uuid::Uuid::new_v4();
+        let missing = server
+            .mock(
+                "DELETE",
+                mockito::Matcher::Regex(format!("/apps/{}$", missing_id)),
+            )
+            .with_status(404)
+            .expect(3)
+            .create();
+        assert!(client.delete_app(missing_id).unwrap_err().is_not_found());
+        assert_eq!(client.path_detection().new_paths, None);
+
+        let legacy = server
+            .mock("DELETE", format!("/v1/apps/{}", app_id).as_str())
+            .with_status(404)
+            .expect(1)
+            .create();
+        let new = server
+            .mock(
+                "DELETE",
+                format!("/api/v1/confidential_computing/apps/{}", app_id).as_str(),
+            )
+            .with_status(204)
+            .expect(2)
+            .create();
+
+        client.delete_app(app_id).unwrap();
+        assert!(client.uses_new_paths());
+        client.delete_app(app_id).unwrap();
+        legacy.assert();
+        new.assert();
+
+        // Once the prefix is known, a 404 is not sent again.
+        assert!(client.delete_app(missing_id).unwrap_err().is_not_found());
+        assert!(client.uses_new_paths());
+        missing.assert();
+    }
+
+    pub(super) fn auth_response(token: &str, token_expires_in: i64) -> String {
+        let now = chrono::Utc::now().timestamp();
+        format!(
+            r#"{{"access_token":"{}","session_info":{{"subject_id":"{}","session_expires_at":{},"session_token_expires_at":{}}}}}"#,
+            token,
+            uuid::Uuid::nil(),
+            now + 3600,
+            now + token_expires_in
+        )
+    }
+
+    #[test]
+    fn test_session_is_reopened_when_expired() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_credentials_provider(Credentials::Basic {
+                username: "user".to_owned(),
+                password: "pass".to_owned(),
+            });
+
+        let first_auth = server
+            .mock("POST", "/v1/sys/auth")
+            .match_header("authorization", "Basic dXNlcjpwYXNz")
+            .with_body(auth_response("first", 3600))
+            .expect(1)
+            .create();
+        let second_auth = server
+            .mock("POST", "/v1/sys/auth")
+            .match_header("authorization", "Basic dXNlcjpwYXNz")
+            .with_body(auth_response("second", 3600))
+            .expect(1)
+            .create();
+        let expired = server
+            .mock("GET", "/v1/sys/version")
+            .match_header("authorization", "Bearer first")
+            .with_status(401)
+            .with_body(r#"{"message":"Session expired","code":"SESSION_EXPIRED"}"#)
+            .expect(1)
+            .create();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .match_header("authorization", "Bearer second")
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(2)
+            .create();
+
+        client.get_manager_version().unwrap();
+        client.get_manager_version().unwrap();
+        first_auth.assert();
+        second_auth.assert();
+        expired.assert();
+        version.assert();
+    }
+
+    #[test]
+    fn test_session_is_refreshed_before_expiry() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_credentials_provider(Credentials::Bearer("api-key".to_owned()));
+
+        let auth = server
+            .mock("POST", "/v1/sys/auth")
+            .match_header("authorization", "Bearer api-key")
+            .with_body(auth_response("token", 10))
+            .expect(1)
+            .create();
+        let now = chrono::Utc::now().timestamp();
+        let refresh = server
+            .mock("POST", "/v1/sys/session/refresh")
+            .match_header("authorization", "Bearer token")
+            .with_body(format!(
+                r#"{{"session_info":{{"subject_id":"{}","session_expires_at":{},"session_token_expires_at":{}}}}}"#,
+                uuid::Uuid::nil(),
+                now + 3600,
+                now + 3600
+            ))
+            .expect(1)
+            .create();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .match_header("authorization", "Bearer token")
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(3)
+            .create();
+
+        // The first request opens a session whose token is about to expire,
+        // the second one refreshes it, the third one uses it as is.
+        for _ in 0..3 {
+            client.get_manager_version().unwrap();
+        }
+        auth.assert();
+        refresh.assert();
+        version.assert();
+    }
 }
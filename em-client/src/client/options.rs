@@ -0,0 +1,294 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use hyper::header::{Header, HeaderFormat, Headers};
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::timeout::{self, Timeouts};
+use super::Client;
+use {Api, ApiDecorator, ApiError};
+
+/// Header carrying the idempotency key of a request.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Options for individual operations of a `Client`, applied with
+/// `Client::with_options`.
+#[derive(Clone, Default)]
+pub struct RequestOptions {
+    /// Header names and values. `hyper::header::Headers` is not `Sync`.
+    headers: Vec<(String, Vec<u8>)>,
+    timeouts: Option<Timeouts>,
+    account_id: Option<Uuid>,
+    idempotency_key: Option<String>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        RequestOptions::default()
+    }
+
+    /// Send a header in addition to the client's headers, replacing a client
+    /// header with the same name.
+    pub fn with_header(self, name: &str, value: &str) -> Self {
+        self.with_raw_header(name, value.as_bytes().to_vec())
+    }
+
+    pub fn with_typed_header<H: Header + HeaderFormat>(self, header: H) -> Self {
+        let mut headers = Headers::new();
+        headers.set(header);
+        let value = headers
+            .get_raw(H::header_name())
+            .and_then(|raw| raw.first().cloned())
+            .unwrap_or_default();
+        self.with_raw_header(H::header_name(), value)
+    }
+
+    fn with_raw_header(mut self, name: &str, value: Vec<u8>) -> Self {
+        self.headers.retain(|(n, _)| !n.eq_ignore_ascii_case(name));
+        self.headers.push((name.to_owned(), value));
+        self
+    }
+
+    /// Use `timeouts` instead of the client's timeouts.
+    pub fn with_timeouts(self, timeouts: Timeouts) -> Self {
+        RequestOptions {
+            timeouts: Some(timeouts),
+            ..self
+        }
+    }
+
+    /// Perform the operations in `account_id`, as with `Client::for_account`.
+    pub fn with_account(self, account_id: Uuid) -> Self {
+        RequestOptions {
+            account_id: Some(account_id),
+            ..self
+        }
+    }
+
+    /// Send `key` in the `Idempotency-Key` header, so that the server
+    /// performs a request at most once. This also allows the client to retry
+    /// requests that are not idempotent by themselves, such as `POST`.
+    pub fn with_idempotency_key<S: Into<String>>(self, key: S) -> Self {
+        RequestOptions {
+            idempotency_key: Some(key.into()),
+            ..self
+        }
+    }
+
+    /// Names of the headers set by the options.
+    pub fn header_names(&self) -> impl Iterator<Item = &str> {
+        self.headers.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn timeouts(&self) -> Option<Timeouts> {
+        self.timeouts
+    }
+
+    pub fn account_id(&self) -> Option<Uuid> {
+        self.account_id
+    }
+
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+}
+
+impl fmt::Debug for RequestOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Header values may hold credentials.
+        let headers = self.header_names().collect::<Vec<_>>();
+        f.debug_struct("RequestOptions")
+            .field("headers", &headers)
+            .field("timeouts", &self.timeouts)
+            .field("account_id", &self.account_id)
+            .field("idempotency_key", &self.idempotency_key)
+            .finish()
+    }
+}
+
+// The options of the operation being performed on this thread. They are
+// taken by the first request of the operation, so that requests the client
+// makes on its own behalf, such as selecting an account, do not get them.
+thread_local! {
+    static ACTIVE: RefCell<Option<Arc<RequestOptions>>> = const { RefCell::new(None) };
+}
+
+/// Makes options available to the operation performed on this thread until
+/// dropped.
+pub(crate) struct Scope {
+    previous: Option<Arc<RequestOptions>>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+fn enter(options: Option<Arc<RequestOptions>>) -> Scope {
+    Scope {
+        previous: ACTIVE.with(|active| active.replace(options)),
+    }
+}
+
+/// Take the options of the current operation for one request. They are
+/// restored when the returned scope is dropped, e.g. for the next attempt.
+pub(crate) fn take() -> (Option<Arc<RequestOptions>>, Scope) {
+    let options = ACTIVE.with(|active| active.borrow().clone());
+    (options, enter(None))
+}
+
+/// Add the headers of `options` to `headers`.
+pub(crate) fn apply(options: &RequestOptions, headers: &mut Headers) {
+    for (name, value) in &options.headers {
+        headers.set_raw(name.clone(), vec![value.clone()]);
+    }
+    if let Some(ref key) = options.idempotency_key {
+        headers.set_raw(IDEMPOTENCY_KEY_HEADER, vec![key.as_bytes().to_vec()]);
+    }
+}
+
+/// A view of a `Client` whose operations use `RequestOptions`, returned by
+/// `Client::with_options`.
+pub struct OptionsOverride<'a> {
+    pub(crate) client: &'a Client,
+    pub(crate) options: Arc<RequestOptions>,
+}
+
+impl<'a> ApiDecorator for OptionsOverride<'a> {
+    type Error = ApiError;
+
+    fn dispatch<F, T>(&self, f: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(&dyn Api<Error = ApiError>) -> Result<T, ApiError>,
+    {
+        let _timeouts = self.options.timeouts.map(timeout::override_timeouts);
+        let with_options = |api: &dyn Api<Error = ApiError>| {
+            let _scope = enter(Some(self.options.clone()));
+            f(api)
+        };
+        match self.options.account_id {
+            Some(account_id) => self.client.for_account(account_id).dispatch(with_options),
+            None => with_options(self.client),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestOptions;
+    use client::{Client, RetryPolicy, Timeouts};
+    use hyper::header::UserAgent;
+    use models;
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use uuid::Uuid;
+    use Api;
+
+    #[test]
+    fn test_client_is_shareable() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Client>();
+        assert_send_sync::<Arc<Client>>();
+        assert_send_sync::<RequestOptions>();
+    }
+
+    #[test]
+    fn test_headers_and_idempotency_key() {
+        let mut server = mockito::Server::new();
+        let client = Arc::new(
+            Client::try_new_http(&server.url())
+                .unwrap()
+                .with_retry_policy(
+                    RetryPolicy::new(2)
+                        .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+                ),
+        );
+        let unavailable = server
+            .mock("POST", "/v1/accounts")
+            .match_header("x-trace", "abc")
+            .match_header("user-agent", "dashboard")
+            .match_header("idempotency-key", "create-1")
+            .with_status(503)
+            .expect(1)
+            .create();
+        let created = server
+            .mock("POST", "/v1/accounts")
+            .match_header("idempotency-key", "create-1")
+            .with_body(r#"{"name":"acct","acct_id":"00000000-0000-0000-0000-000000000000"}"#)
+            .expect(1)
+            .create();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .match_header("x-trace", mockito::Matcher::Missing)
+            .with_body(r#"{"version":"1.0"}"#)
+            .create();
+
+        let options = RequestOptions::new()
+            .with_header("X-Trace", "abc")
+            .with_typed_header(UserAgent("dashboard".to_owned()))
+            .with_idempotency_key("create-1");
+        let account = client
+            .with_options(options)
+            .create_account(models::AccountRequest::new("acct".to_owned()))
+            .unwrap();
+        assert_eq!(account.name, Some("acct".to_owned()));
+        unavailable.assert();
+        created.assert();
+
+        // The options do not stick to the client.
+        client.get_manager_version().unwrap();
+        version.assert();
+    }
+
+    #[test]
+    fn test_account_and_timeout_options() {
+        let mut server = mockito::Server::new();
+        let client = Client::try_new_http(&server.url()).unwrap();
+        let account_id = Uuid::new_v4();
+        let select = server
+            .mock(
+                "POST",
+                &*format!("/v1/accounts/select_account/{}", account_id),
+            )
+            .match_header("x-trace", mockito::Matcher::Missing)
+            .with_status(204)
+            .expect(1)
+            .create();
+        let zones = server
+            .mock("GET", "/v1/zones")
+            .match_header("x-trace", "abc")
+            .with_body("[]")
+            .expect(1)
+            .create();
+
+        let options = RequestOptions::new()
+            .with_header("X-Trace", "abc")
+            .with_account(account_id);
+        client.with_options(options).get_zones().unwrap();
+        select.assert();
+        zones.assert();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let _connections = listener.incoming().collect::<Vec<_>>();
+        });
+        let options = RequestOptions::new()
+            .with_timeouts(Timeouts::new().with_read_timeout(Duration::from_millis(50)));
+        let err = Client::try_new_http(&url)
+            .unwrap()
+            .with_options(options)
+            .get_manager_version()
+            .unwrap_err();
+        assert!(err.is_timeout());
+    }
+}
@@ -0,0 +1,359 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hyper::Url;
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use super::url::percent_encoding::percent_decode;
+use super::{timeout, ClientInitError};
+
+/// Largest CONNECT response head accepted from a proxy.
+const MAX_RESPONSE_HEAD: usize = 8192;
+
+/// An HTTP proxy through which `Client` tunnels its connections with
+/// `CONNECT`.
+///
+/// Like the connect timeout, the proxy is only used by the connectors of this
+/// crate, i.e. by clients created with `Client::try_new_http` or
+/// `Client::try_new_https`.
+#[derive(Clone)]
+pub struct Proxy {
+    host: String,
+    port: u16,
+    credentials: Option<(String, String)>,
+    no_proxy: Vec<String>,
+}
+
+impl fmt::Debug for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Proxy {{ host: {:?}, port: {}", self.host, self.port)?;
+        if let Some((ref username, _)) = self.credentials {
+            write!(f, ", username: {:?}, password: <redacted>", username)?;
+        }
+        write!(f, ", no_proxy: {:?} }}", self.no_proxy)
+    }
+}
+
+impl Proxy {
+    /// Use the proxy at `url`, e.g. "http://proxy.example.com:3128". The
+    /// scheme may be omitted. Credentials in the URL are used for basic
+    /// proxy authentication.
+    pub fn new(url: &str) -> Result<Proxy, ClientInitError> {
+        let url = match Url::from_str(url) {
+            Ok(ref parsed) if parsed.host_str().is_some() => parsed.clone(),
+            _ => Url::from_str(&format!("http://{}", url))?,
+        };
+        if url.scheme() != "http" {
+            return Err(ClientInitError::InvalidScheme);
+        }
+        let host = url
+            .host_str()
+            .ok_or(ClientInitError::MissingHost)?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_owned();
+        let credentials = match url.username() {
+            "" => None,
+            username => Some((decode(username), decode(url.password().unwrap_or_default()))),
+        };
+        Ok(Proxy {
+            host,
+            port: url.port_or_known_default().unwrap_or(80),
+            credentials,
+            no_proxy: Vec::new(),
+        })
+    }
+
+    /// The proxy configured by the `HTTPS_PROXY` environment variable, with
+    /// the exceptions listed in `NO_PROXY`. The lowercase variables are used
+    /// if the uppercase ones are not set.
+    pub fn from_env() -> Result<Option<Proxy>, ClientInitError> {
+        Self::from_vars(var("HTTPS_PROXY"), var("NO_PROXY"))
+    }
+
+    fn from_vars(
+        https_proxy: Option<String>,
+        no_proxy: Option<String>,
+    ) -> Result<Option<Proxy>, ClientInitError> {
+        let url = match https_proxy {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+        let proxy = Proxy::new(&url)?;
+        Ok(Some(match no_proxy {
+            Some(no_proxy) => proxy.with_no_proxy(&no_proxy),
+            None => proxy,
+        }))
+    }
+
+    /// Authenticate to the proxy with HTTP basic authentication.
+    pub fn with_basic_auth(self, username: &str, password: &str) -> Self {
+        Proxy {
+            credentials: Some((username.to_owned(), password.to_owned())),
+            ..self
+        }
+    }
+
+    /// Connect directly to the hosts in `no_proxy`, a comma-separated list in
+    /// the format of the `NO_PROXY` environment variable: host names, IP
+    /// addresses or domain suffixes such as `.example.com`, or `*` for all
+    /// hosts.
+    pub fn with_no_proxy(mut self, no_proxy: &str) -> Self {
+        self.no_proxy.extend(
+            no_proxy
+                .split(',')
+                .map(|entry| entry.trim().to_ascii_lowercase())
+                .filter(|entry| !entry.is_empty()),
+        );
+        self
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Whether connections to `host` go through the proxy.
+    pub fn applies_to(&self, host: &str) -> bool {
+        let host = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_ascii_lowercase();
+        !self.no_proxy.iter().any(|entry| {
+            let domain = entry.trim_start_matches('*').trim_start_matches('.');
+            entry == "*"
+                || host == domain
+                || (host.ends_with(domain) && host[..host.len() - domain.len()].ends_with('.'))
+        })
+    }
+
+    /// Open a tunnel through the proxy to `host:port`.
+    fn tunnel(&self, host: &str, port: u16) -> io::Result<TcpStream> {
+        let mut stream = timeout::connect_tcp(&self.host, self.port)?;
+        stream.set_read_timeout(timeout::read_limit()?)?;
+        stream.set_write_timeout(timeout::write_limit()?)?;
+
+        let authority = match host.contains(':') {
+            true => format!("[{}]:{}", host, port),
+            false => format!("{}:{}", host, port),
+        };
+        let mut request = format!("CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n", authority);
+        if let Some((ref username, ref password)) = self.credentials {
+            let token = STANDARD.encode(format!("{}:{}", username, password));
+            request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes())?;
+
+        // Read one byte at a time so that nothing sent through the tunnel is
+        // consumed.
+        let mut head = Vec::new();
+        let mut byte = [0];
+        while !head.ends_with(b"\r\n\r\n") {
+            if head.len() >= MAX_RESPONSE_HEAD {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    "Proxy response head is too large",
+                ));
+            }
+            if stream.read(&mut byte)? == 0 {
+                return Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "Proxy closed the connection",
+                ));
+            }
+            head.push(byte[0]);
+        }
+        let head = String::from_utf8_lossy(&head);
+        let status_line = head.lines().next().unwrap_or_default();
+        match status_line.split_whitespace().nth(1) {
+            Some(status) if status.starts_with('2') => Ok(stream),
+            Some("407") => Err(io::Error::new(
+                ErrorKind::PermissionDenied,
+                format!("Proxy authentication failed: {}", status_line),
+            )),
+            _ => Err(io::Error::new(
+                ErrorKind::ConnectionRefused,
+                format!("Proxy refused to connect to {}: {}", authority, status_line),
+            )),
+        }
+    }
+}
+
+fn var(name: &str) -> Option<String> {
+    env::var(name)
+        .or_else(|_| env::var(name.to_ascii_lowercase()))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
+
+fn decode(component: &str) -> String {
+    percent_decode(component.as_bytes())
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+// As with the timeouts, the connectors find the proxy of the client that
+// performs the current operation here.
+thread_local! {
+    static ACTIVE: RefCell<Option<Arc<Proxy>>> = const { RefCell::new(None) };
+}
+
+/// Routes the connections opened on this thread through `proxy` until
+/// dropped.
+pub(crate) struct Scope {
+    previous: Option<Arc<Proxy>>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+pub(crate) fn enter(proxy: Option<Arc<Proxy>>) -> Scope {
+    Scope {
+        previous: ACTIVE.with(|active| active.replace(proxy)),
+    }
+}
+
+/// Open a TCP connection to `host:port`, through the proxy of the current
+/// operation if there is one.
+pub(crate) fn connect(host: &str, port: u16) -> io::Result<TcpStream> {
+    match ACTIVE.with(|active| active.borrow().clone()) {
+        Some(ref proxy) if proxy.applies_to(host) => proxy.tunnel(host, port),
+        _ => timeout::connect_tcp(host, port),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Proxy;
+    use client::Client;
+    use std::io::{self, Read, Write};
+    use std::net::{Shutdown, TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use SystemApi;
+
+    /// A proxy that requires the credentials `user:secret` and records the
+    /// CONNECT requests it receives.
+    fn proxy_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut client = stream.unwrap();
+                let mut head = Vec::new();
+                let mut byte = [0];
+                while !head.ends_with(b"\r\n\r\n") && client.read(&mut byte).unwrap() == 1 {
+                    head.push(byte[0]);
+                }
+                let head = String::from_utf8(head).unwrap();
+                let request_line = head.lines().next().unwrap().to_owned();
+                recorded.lock().unwrap().push(request_line.clone());
+                // "user:secret"
+                if !head.contains("Proxy-Authorization: Basic dXNlcjpzZWNyZXQ=\r\n") {
+                    let _ = client.write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n");
+                    continue;
+                }
+                let target = request_line.split_whitespace().nth(1).unwrap();
+                let server = TcpStream::connect(target).unwrap();
+                client
+                    .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                    .unwrap();
+                pipe(client.try_clone().unwrap(), server.try_clone().unwrap());
+                pipe(server, client);
+            }
+        });
+        (url, requests)
+    }
+
+    fn pipe(mut from: TcpStream, mut to: TcpStream) {
+        thread::spawn(move || {
+            let _ = io::copy(&mut from, &mut to);
+            let _ = to.shutdown(Shutdown::Write);
+        });
+    }
+
+    #[test]
+    fn test_proxy_tunnel() {
+        let mut server = mockito::Server::new();
+        let version = server
+            .mock("GET", "/v1/sys/version")
+            .with_body(r#"{"version":"1.0"}"#)
+            .expect(2)
+            .create();
+        let (proxy_url, requests) = proxy_server();
+        let target = server.host_with_port();
+
+        let client = Client::try_new_http(&server.url())
+            .unwrap()
+            .with_proxy(Proxy::new(&proxy_url).unwrap());
+        assert!(client.get_manager_version().is_err());
+
+        let proxy_url = proxy_url.replace("http://", "http://user:secret@");
+        let client = client.with_proxy(Proxy::new(&proxy_url).unwrap());
+        assert!(client.get_manager_version().is_ok());
+
+        let client = Client::try_new_http(&server.url()).unwrap().with_proxy(
+            Proxy::new(&proxy_url)
+                .unwrap()
+                .with_no_proxy("example.com, 127.0.0.1"),
+        );
+        assert!(client.get_manager_version().is_ok());
+
+        version.assert();
+        let connect = format!("CONNECT {} HTTP/1.1", target);
+        assert_eq!(*requests.lock().unwrap(), vec![connect.clone(), connect]);
+    }
+
+    #[test]
+    fn test_proxy_configuration() {
+        let proxy = Proxy::from_vars(
+            Some("proxy.example.com:3128".to_owned()),
+            Some(".internal.example.com,localhost".to_owned()),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(proxy.host(), "proxy.example.com");
+        assert_eq!(proxy.port(), 3128);
+        assert!(proxy.credentials.is_none());
+        assert!(proxy.applies_to("em.example.com"));
+        assert!(proxy.applies_to("notinternal.example.com"));
+        assert!(!proxy.applies_to("em.internal.example.com"));
+        assert!(!proxy.applies_to("internal.example.com"));
+        assert!(!proxy.applies_to("LOCALHOST"));
+
+        let proxy = Proxy::new("http://us%40er:p%3Ass@[::1]").unwrap();
+        assert_eq!(proxy.host(), "::1");
+        assert_eq!(proxy.port(), 80);
+        assert_eq!(
+            proxy.credentials,
+            Some(("us@er".to_owned(), "p:ss".to_owned()))
+        );
+        assert!(!format!("{:?}", proxy).contains("p:ss"));
+        assert!(!proxy.with_no_proxy("*").applies_to("em.example.com"));
+
+        assert!(Proxy::from_vars(None, Some("localhost".to_owned()))
+            .unwrap()
+            .is_none());
+        assert!(Proxy::new("https://proxy.example.com").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use chrono::{DateTime, Utc};
+use hyper::header::Headers;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::str;
+use std::time::{Duration, Instant};
+
+/// Controls how `Client` retries requests that failed with a temporary error.
+///
+/// Only idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE`, `OPTIONS` and
+/// `TRACE`) are retried, and only when no response was received or the
+/// server answered with a 5xx status or `429 Too Many Requests`. The delay
+/// between attempts grows exponentially from `initial_backoff` up to
+/// `max_backoff`, unless the server asks for a specific delay with a
+/// `Retry-After` header on a 429 or 503 response. Delays asked for by the
+/// server are capped at `max_backoff` as well.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    /// No retries: every request is attempted exactly once.
+    fn default() -> Self {
+        RetryPolicy::new(1)
+    }
+}
+
+impl RetryPolicy {
+    /// A policy making at most `max_attempts` attempts per request, with
+    /// jittered backoff starting at 100ms and capped at 10s.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            deadline: None,
+        }
+    }
+
+    /// Set the delay before the first retry and the upper bound on delays.
+    pub fn with_backoff(self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        RetryPolicy {
+            initial_backoff,
+            max_backoff,
+            ..self
+        }
+    }
+
+    /// Enable or disable randomization of the delay between attempts.
+    pub fn with_jitter(self, jitter: bool) -> Self {
+        RetryPolicy { jitter, ..self }
+    }
+
+    /// Give up retrying once this much time has passed since the first attempt.
+    pub fn with_deadline(self, deadline: Duration) -> Self {
+        RetryPolicy {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    pub(crate) fn is_idempotent(method: &Method) -> bool {
+        matches!(
+            *method,
+            Method::Get
+                | Method::Head
+                | Method::Put
+                | Method::Delete
+                | Method::Options
+                | Method::Trace
+        )
+    }
+
+    pub(crate) fn is_retryable_status(status: u16) -> bool {
+        status == 429 || (500..600).contains(&status)
+    }
+
+    /// Delay to wait before attempt number `attempt + 1`, where `attempt`
+    /// counts the attempts made so far. Returns `None` if no further attempt
+    /// should be made.
+    pub(crate) fn next_delay(
+        &self,
+        attempt: u32,
+        started: Instant,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let delay = match retry_after {
+            Some(retry_after) => retry_after.min(self.max_backoff),
+            None => self.backoff(attempt),
+        };
+        if let Some(deadline) = self.deadline {
+            if started.elapsed() + delay >= deadline {
+                return None;
+            }
+        }
+        Some(delay)
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_backoff
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return delay;
+        }
+        // "Equal jitter": keep half of the delay, randomize the other half.
+        let half = delay / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// Parse the `Retry-After` header of a 429 or 503 response, which is either
+/// a number of seconds or an HTTP date.
+pub(crate) fn retry_after(status: StatusCode, headers: &Headers) -> Option<Duration> {
+    let status = status.to_u16();
+    if status != 429 && status != 503 {
+        return None;
+    }
+    let raw = headers.get_raw("Retry-After")?.first()?;
+    let value = str::from_utf8(raw).ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+/// A number in `[0, 1)`. Each `RandomState` is seeded with fresh keys, which
+/// is plenty for spreading out retries.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::new(10)
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .with_jitter(false);
+        let started = Instant::now();
+        assert_eq!(
+            policy.next_delay(1, started, None),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.next_delay(2, started, None),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.next_delay(3, started, None),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(
+            policy.next_delay(4, started, None),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(policy.next_delay(10, started, None), None);
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy =
+            RetryPolicy::new(3).with_backoff(Duration::from_millis(100), Duration::from_secs(1));
+        for _ in 0..100 {
+            let delay = policy.next_delay(1, Instant::now(), None).unwrap();
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_deadline_stops_retries() {
+        let policy = RetryPolicy::new(5)
+            .with_backoff(Duration::from_secs(1), Duration::from_secs(1))
+            .with_jitter(false)
+            .with_deadline(Duration::from_millis(500));
+        assert_eq!(policy.next_delay(1, Instant::now(), None), None);
+        assert_eq!(
+            policy.next_delay(1, Instant::now(), Some(Duration::from_millis(10))),
+            Some(Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn test_retry_after_is_capped() {
+        let policy = RetryPolicy::new(3)
+            .with_backoff(Duration::from_millis(100), Duration::from_secs(10))
+            .with_jitter(false);
+        assert_eq!(
+            policy.next_delay(1, Instant::now(), Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.next_delay(1, Instant::now(), Some(Duration::from_secs(86400))),
+            Some(Duration::from_secs(10))
+        );
+    }
+}
@@ -0,0 +1,226 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use chrono::Utc;
+use hyper::header::{Authorization, Basic, Bearer, Headers};
+use hyper::method::Method;
+use hyper::Url;
+use serde::de::DeserializeOwned;
+use serde_json;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use super::{unexpected_response, BufferedResponse, Client};
+use models;
+use ApiError;
+use SimpleErrorType;
+
+/// The session is refreshed when its token expires within this many seconds.
+const REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Credentials used to open a session with the Enclave Manager.
+#[derive(Clone)]
+pub enum Credentials {
+    /// Username and password, sent with HTTP basic authentication.
+    Basic { username: String, password: String },
+    /// A token sent with bearer authentication.
+    Bearer(String),
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Credentials::Basic { ref username, .. } => {
+                write!(
+                    f,
+                    "Basic {{ username: {:?}, password: <redacted> }}",
+                    username
+                )
+            }
+            Credentials::Bearer(_) => write!(f, "Bearer(<redacted>)"),
+        }
+    }
+}
+
+/// Supplies the credentials a `Client` uses whenever it needs to open a new
+/// session, so that credentials can be rotated without recreating the client.
+pub trait CredentialsProvider: Send + Sync {
+    fn credentials(&self) -> Result<Credentials, ApiError>;
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> Result<Credentials, ApiError> {
+        Ok(self.clone())
+    }
+}
+
+struct SessionState {
+    access_token: String,
+    info: Option<models::SessionInfo>,
+}
+
+/// Session shared by a `Client` and its clones.
+pub(crate) struct Session {
+    provider: Box<dyn CredentialsProvider>,
+    state: Mutex<Option<SessionState>>,
+    /// Number of sessions opened so far.
+    generation: AtomicUsize,
+}
+
+impl Session {
+    pub(crate) fn new(provider: Box<dyn CredentialsProvider>) -> Session {
+        Session {
+            provider,
+            state: Mutex::new(None),
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    /// Changes whenever a new session is opened, which loses the server-side
+    /// state of the previous session such as the selected account.
+    pub(crate) fn generation(&self) -> usize {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    fn state(&self) -> MutexGuard<'_, Option<SessionState>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Return a token for the current session, opening a session or
+    /// refreshing it first if needed.
+    pub(crate) fn access_token(&self, client: &Client) -> Result<String, ApiError> {
+        let mut state = self.state();
+        if let Some(ref mut current) = *state {
+            let (token_expires_at, expires_at) = match current.info {
+                Some(ref info) => (info.session_token_expires_at, info.session_expires_at),
+                // Without expiry information we can only wait for the server
+                // to reject the session.
+                None => return Ok(current.access_token.clone()),
+            };
+            let now = Utc::now().timestamp();
+            if now + REFRESH_MARGIN_SECS < token_expires_at {
+                return Ok(current.access_token.clone());
+            }
+            if now + REFRESH_MARGIN_SECS < expires_at {
+                match refresh(client, &current.access_token) {
+                    Ok(response) => {
+                        current.info = Some(response.session_info);
+                        return Ok(current.access_token.clone());
+                    }
+                    Err(err) => debug!("Failed to refresh session: {}", err),
+                }
+            }
+        }
+        self.authenticate(client, &mut state)
+    }
+
+    /// Open a new session after the server rejected `rejected_token`, unless
+    /// a concurrent request has already done so.
+    pub(crate) fn reauthenticate(
+        &self,
+        client: &Client,
+        rejected_token: &str,
+    ) -> Result<String, ApiError> {
+        let mut state = self.state();
+        if let Some(ref current) = *state {
+            if current.access_token != rejected_token {
+                return Ok(current.access_token.clone());
+            }
+        }
+        self.authenticate(client, &mut state)
+    }
+
+    fn authenticate(
+        &self,
+        client: &Client,
+        state: &mut Option<SessionState>,
+    ) -> Result<String, ApiError> {
+        *state = None;
+        let mut headers = client.default_headers();
+        match self.provider.credentials()? {
+            Credentials::Basic { username, password } => headers.set(Authorization(Basic {
+                username,
+                password: Some(password),
+            })),
+            Credentials::Bearer(token) => headers.set(bearer(&token)),
+        }
+        let response: models::AuthResponse =
+            post(client, "authenticate_user", "/v1/sys/auth", headers)?;
+        let access_token = response.access_token.ok_or_else(|| {
+            ApiError::new(
+                "Authentication response did not include an access token".to_owned(),
+                SimpleErrorType::Permanent,
+            )
+        })?;
+        *state = Some(SessionState {
+            access_token: access_token.clone(),
+            info: response.session_info,
+        });
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        Ok(access_token)
+    }
+}
+
+pub(crate) fn bearer(token: &str) -> Authorization<Bearer> {
+    Authorization(Bearer {
+        token: token.to_owned(),
+    })
+}
+
+/// Whether the server rejected a request because its session is invalid or
+/// has expired.
+pub(crate) fn is_session_error(response: &BufferedResponse) -> bool {
+    if !response.status.is_client_error() {
+        return false;
+    }
+    match serde_json::from_slice::<models::Event>(response.body()) {
+        Ok(event) => matches!(
+            event.code,
+            models::EventType::INVALID_SESSION | models::EventType::SESSION_EXPIRED
+        ),
+        Err(_) => false,
+    }
+}
+
+fn refresh(client: &Client, token: &str) -> Result<models::RefreshResponse, ApiError> {
+    let mut headers = client.default_headers();
+    headers.set(bearer(token));
+    post(
+        client,
+        "refresh_session",
+        "/v1/sys/session/refresh",
+        headers,
+    )
+}
+
+/// POST to `operation_path` without going through the session handling of
+/// `Client::execute`.
+fn post<T: DeserializeOwned>(
+    client: &Client,
+    operation: &'static str,
+    operation_path: &str,
+    headers: Headers,
+) -> Result<T, ApiError> {
+    let url = format!(
+        "{}{}",
+        client.base_path,
+        client.remap_operation_path(operation_path)
+    );
+    let url = Url::from_str(&url).map_err(|err| {
+        ApiError::new(
+            format!("Unable to build URL: {}", err),
+            SimpleErrorType::Permanent,
+        )
+    })?;
+    let response = client.send(operation, &Method::Post, &url, headers, None)?;
+    match response.status.to_u16() {
+        200 => serde_json::from_slice(response.body()).map_err(|e| e.into()),
+        _ => Err(unexpected_response(response)),
+    }
+}
@@ -0,0 +1,368 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use hyper;
+use hyper::net::{HttpStream, NetworkConnector, NetworkStream};
+use std::cell::Cell;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use super::{proxy, Client};
+use {Api, ApiDecorator, ApiError};
+
+/// Time limits for the operations performed by `Client`.
+///
+/// The connect timeout only applies to connections opened by the connectors
+/// of this crate, e.g. the one used by `Client::try_new_http`. The read and
+/// total timeouts also apply to custom connectors passed to
+/// `Client::try_new_with_connector`, but not to clients created with
+/// `Client::try_new_with_hyper_client`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timeouts {
+    connect: Option<Duration>,
+    read: Option<Duration>,
+    total: Option<Duration>,
+}
+
+impl Timeouts {
+    /// No time limits.
+    pub fn new() -> Self {
+        Timeouts::default()
+    }
+
+    /// Limit the time spent establishing a connection.
+    pub fn with_connect_timeout(self, timeout: Duration) -> Self {
+        Timeouts {
+            connect: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Limit the time spent waiting for data from the server.
+    pub fn with_read_timeout(self, timeout: Duration) -> Self {
+        Timeouts {
+            read: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Limit the time an operation takes in total, including retries and
+    /// re-authentication.
+    pub fn with_total_timeout(self, timeout: Duration) -> Self {
+        Timeouts {
+            total: Some(timeout),
+            ..self
+        }
+    }
+
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect
+    }
+
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read
+    }
+
+    pub fn total_timeout(&self) -> Option<Duration> {
+        self.total
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Active {
+    timeouts: Timeouts,
+    deadline: Option<Instant>,
+}
+
+// A sync `Client` performs an operation entirely on the calling thread, so
+// the connectors find the limits of the current operation here.
+thread_local! {
+    static ACTIVE: Cell<Option<Active>> = const { Cell::new(None) };
+    static OVERRIDE: Cell<Option<Timeouts>> = const { Cell::new(None) };
+}
+
+/// Applies timeouts to the operation performed on this thread until dropped.
+pub(crate) struct Scope {
+    previous: Option<Active>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| active.set(self.previous));
+    }
+}
+
+/// Start an operation limited by `timeouts`, unless overridden for the
+/// current request.
+pub(crate) fn enter(timeouts: Timeouts) -> Scope {
+    let timeouts = OVERRIDE.with(Cell::get).unwrap_or(timeouts);
+    let active = Active {
+        timeouts,
+        deadline: timeouts.total.map(|total| Instant::now() + total),
+    };
+    Scope {
+        previous: ACTIVE.with(|cell| cell.replace(Some(active))),
+    }
+}
+
+/// Replaces the client's timeouts for the operations started on this thread
+/// until dropped.
+pub(crate) struct OverrideScope {
+    previous: Option<Timeouts>,
+}
+
+impl Drop for OverrideScope {
+    fn drop(&mut self) {
+        OVERRIDE.with(|cell| cell.set(self.previous));
+    }
+}
+
+pub(crate) fn override_timeouts(timeouts: Timeouts) -> OverrideScope {
+    OverrideScope {
+        previous: OVERRIDE.with(|cell| cell.replace(Some(timeouts))),
+    }
+}
+
+/// Time left until the deadline of the current operation, if it has one.
+pub(crate) fn remaining() -> Option<Duration> {
+    let deadline = ACTIVE.with(Cell::get)?.deadline?;
+    Some(deadline.saturating_duration_since(Instant::now()))
+}
+
+/// The tighter of `timeout` and the time left until the deadline. Fails if
+/// the deadline has already passed.
+fn limit(timeout: Option<Duration>) -> io::Result<Option<Duration>> {
+    match remaining() {
+        Some(remaining) if remaining == Duration::from_secs(0) => Err(io::Error::new(
+            ErrorKind::TimedOut,
+            "operation deadline exceeded",
+        )),
+        Some(remaining) => Ok(Some(timeout.map_or(remaining, |t| t.min(remaining)))),
+        None => Ok(timeout),
+    }
+}
+
+fn current<F: Fn(&Timeouts) -> Option<Duration>>(f: F) -> Option<Duration> {
+    ACTIVE
+        .with(Cell::get)
+        .and_then(|active| f(&active.timeouts))
+}
+
+/// Limit for a single blocking read in the current operation.
+pub(crate) fn read_limit() -> io::Result<Option<Duration>> {
+    limit(current(Timeouts::read_timeout))
+}
+
+/// Limit for a single blocking write in the current operation.
+pub(crate) fn write_limit() -> io::Result<Option<Duration>> {
+    limit(None)
+}
+
+/// Whether an I/O error was caused by one of the timeouts.
+pub(crate) fn is_timeout(err: &io::Error) -> bool {
+    matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock)
+}
+
+/// Open a TCP connection, honouring the connect timeout and the deadline of
+/// the current operation.
+pub(crate) fn connect_tcp(host: &str, port: u16) -> io::Result<TcpStream> {
+    let timeout = match limit(current(Timeouts::connect_timeout))? {
+        Some(timeout) => timeout,
+        None => return TcpStream::connect((host, port)),
+    };
+    let mut last_err = None;
+    for addr in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        io::Error::new(
+            ErrorKind::InvalidInput,
+            "could not resolve to any addresses",
+        )
+    }))
+}
+
+/// Plain HTTP connector that honours the connect timeout and the proxy.
+pub(crate) struct HttpConnector;
+
+impl NetworkConnector for HttpConnector {
+    type Stream = HttpStream;
+
+    fn connect(&self, host: &str, port: u16, scheme: &str) -> hyper::Result<HttpStream> {
+        match scheme {
+            "http" => Ok(HttpStream(proxy::connect(host, port)?)),
+            _ => Err(io::Error::new(ErrorKind::InvalidInput, "Invalid scheme for Http").into()),
+        }
+    }
+}
+
+/// Wraps the streams of a connector so that they honour the read timeout and
+/// the deadline of the current operation.
+pub(crate) struct TimeoutConnector<C>(pub C);
+
+impl<C> NetworkConnector for TimeoutConnector<C>
+where
+    C: NetworkConnector,
+    C::Stream: NetworkStream,
+{
+    type Stream = TimeoutStream<C::Stream>;
+
+    fn connect(&self, host: &str, port: u16, scheme: &str) -> hyper::Result<Self::Stream> {
+        limit(None)?;
+        self.0.connect(host, port, scheme).map(TimeoutStream)
+    }
+}
+
+pub(crate) struct TimeoutStream<S>(S);
+
+impl<S: NetworkStream> Read for TimeoutStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.set_read_timeout(read_limit()?)?;
+        self.0.read(buf)
+    }
+}
+
+impl<S: NetworkStream> Write for TimeoutStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.set_write_timeout(write_limit()?)?;
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<S: NetworkStream> NetworkStream for TimeoutStream<S> {
+    fn peer_addr(&mut self) -> io::Result<SocketAddr> {
+        self.0.peer_addr()
+    }
+
+    // The timeouts are set before every read and write instead.
+    fn set_read_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_write_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self, how: Shutdown) -> io::Result<()> {
+        self.0.close(how)
+    }
+
+    fn set_previous_response_expected_no_content(&mut self, expected: bool) {
+        self.0.set_previous_response_expected_no_content(expected)
+    }
+
+    fn previous_response_expected_no_content(&self) -> bool {
+        self.0.previous_response_expected_no_content()
+    }
+}
+
+/// A view of a `Client` whose operations use different timeouts, returned by
+/// `Client::with_request_timeouts`.
+pub struct TimeoutOverride<'a> {
+    pub(crate) client: &'a Client,
+    pub(crate) timeouts: Timeouts,
+}
+
+impl<'a> ApiDecorator for TimeoutOverride<'a> {
+    type Error = ApiError;
+
+    fn dispatch<F, T>(&self, f: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(&dyn Api<Error = ApiError>) -> Result<T, ApiError>,
+    {
+        let _scope = override_timeouts(self.timeouts);
+        f(self.client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timeouts;
+    use client::{Client, PathMode};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use {Api, SimpleErrorType};
+
+    /// A server that accepts connections but never responds.
+    fn unresponsive_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let mut connections = Vec::new();
+            for stream in listener.incoming() {
+                connections.push(stream);
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        let client = Client::try_new_http(&unresponsive_server())
+            .unwrap()
+            .with_timeouts(Timeouts::new().with_read_timeout(Duration::from_millis(100)));
+
+        let started = Instant::now();
+        let err = client.get_manager_version().unwrap_err();
+        assert!(err.is_timeout());
+        assert_eq!(err.error_type(), SimpleErrorType::Temporary);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_path_probe_timeout() {
+        let client = Arc::new(
+            Client::try_new_http(&unresponsive_server())
+                .unwrap()
+                .with_path_mode(PathMode::Auto),
+        );
+
+        let started = Instant::now();
+        let err = client
+            .with_request_timeouts(Timeouts::new().with_read_timeout(Duration::from_millis(100)))
+            .get_manager_version()
+            .unwrap_err();
+        assert!(err.is_timeout());
+        assert!(!client.uses_new_paths());
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        // A probe in flight on another thread does not block this one.
+        let clone = client.clone();
+        let hung = thread::spawn(move || {
+            clone.path_detection().next_probe = None;
+            clone
+                .with_request_timeouts(Timeouts::new().with_read_timeout(Duration::from_secs(2)))
+                .get_manager_version()
+        });
+        thread::sleep(Duration::from_millis(200));
+        let started = Instant::now();
+        assert!(!client.uses_new_paths());
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(hung.join().unwrap().unwrap_err().is_timeout());
+    }
+
+    #[test]
+    fn test_request_timeout_override() {
+        let client = Client::try_new_http(&unresponsive_server()).unwrap();
+
+        let started = Instant::now();
+        let err = client
+            .with_request_timeouts(Timeouts::new().with_total_timeout(Duration::from_millis(100)))
+            .get_manager_version()
+            .unwrap_err();
+        assert!(err.is_timeout());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,456 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Writing the files of a runtime application config to the filesystem.
+//!
+//! The `app_config` of a `HashedConfig` maps file paths, such as
+//! `/opt/fortanix/enclave-os/app-config/rw/harmonize.txt`, to their base64
+//! contents. `ConfigFiles` writes them under a root directory, with the
+//! paths taken relative to the root.
+//!
+//! All paths and contents are checked before the first file is written, so
+//! an invalid config leaves the filesystem untouched. Each file is written
+//! to a temporary file next to it and renamed into place, so readers see
+//! either the old or the new contents.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use em_client::config_files::ConfigFiles;
+//! use em_client::Api;
+//!
+//! # let expected_hash = [0; 32];
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let config = client.get_runtime_application_config(&expected_hash)?;
+//! let written = ConfigFiles::new("/").with_mode(0o640).write(&config)?;
+//! println!("Wrote {:?}", written.written);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use models;
+use uuid::Uuid;
+
+/// Permissions of the files written unless configured otherwise.
+pub const DEFAULT_MODE: u32 = 0o600;
+
+/// Why the files of a config could not be written.
+#[derive(Debug)]
+pub enum ConfigFilesError {
+    /// The path of a file is empty, leaves the root directory or goes
+    /// through a symbolic link.
+    InvalidPath { path: String, reason: &'static str },
+    /// The contents of a file are not valid base64.
+    InvalidContents {
+        path: String,
+        error: base64::DecodeError,
+    },
+    /// Writing or removing a file failed.
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ConfigFilesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigFilesError::InvalidPath { ref path, reason } => {
+                write!(f, "Invalid config file path {:?}: {}", path, reason)
+            }
+            ConfigFilesError::InvalidContents {
+                ref path,
+                ref error,
+            } => write!(f, "Invalid contents of config file {:?}: {}", path, error),
+            ConfigFilesError::Io {
+                ref path,
+                ref error,
+            } => write!(f, "Failed to write {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl error::Error for ConfigFilesError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ConfigFilesError::InvalidPath { .. } => None,
+            ConfigFilesError::InvalidContents { ref error, .. } => Some(error),
+            ConfigFilesError::Io { ref error, .. } => Some(error),
+        }
+    }
+}
+
+/// The files changed by `ConfigFiles::write` or `ConfigFiles::replace`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrittenFiles {
+    pub written: Vec<PathBuf>,
+    /// Files of the previous config that are not part of the new one.
+    pub removed: Vec<PathBuf>,
+}
+
+/// Writes the files of runtime configs under a root directory.
+#[derive(Clone, Debug)]
+pub struct ConfigFiles {
+    root: PathBuf,
+    mode: u32,
+}
+
+impl ConfigFiles {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        ConfigFiles {
+            root: root.into(),
+            mode: DEFAULT_MODE,
+        }
+    }
+
+    /// Write files with the Unix permissions `mode` instead of
+    /// `DEFAULT_MODE`. Ignored on other platforms.
+    pub fn with_mode(self, mode: u32) -> Self {
+        ConfigFiles { mode, ..self }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The path a file of a config is written to.
+    pub fn path_of(&self, path: &str) -> Result<PathBuf, ConfigFilesError> {
+        let invalid = |reason| ConfigFilesError::InvalidPath {
+            path: path.to_owned(),
+            reason,
+        };
+        if path.contains('\0') {
+            return Err(invalid("contains a NUL character"));
+        }
+        let mut relative = PathBuf::new();
+        for component in Path::new(path).components() {
+            match component {
+                Component::Normal(name) => relative.push(name),
+                Component::RootDir | Component::CurDir => {}
+                Component::ParentDir => return Err(invalid("refers to a parent directory")),
+                Component::Prefix(_) => return Err(invalid("has a drive or UNC prefix")),
+            }
+        }
+        if relative.as_os_str().is_empty() {
+            return Err(invalid("does not name a file"));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    /// Write the files of `config`. Files of other configs are left in
+    /// place.
+    pub fn write(
+        &self,
+        config: &models::RuntimeAppConfig,
+    ) -> Result<WrittenFiles, ConfigFilesError> {
+        let files = self.decode(config)?;
+        for (path, (name, _)) in &files {
+            self.check_no_symlinks(path, name)?;
+        }
+        let mut written = Vec::with_capacity(files.len());
+        for (path, (_, contents)) in files {
+            self.write_file(&path, &contents)?;
+            written.push(path);
+        }
+        Ok(WrittenFiles {
+            written,
+            removed: Vec::new(),
+        })
+    }
+
+    /// Write the files of `config` and remove the files of `previous` that
+    /// are not part of it. Directories are left in place.
+    pub fn replace(
+        &self,
+        previous: &models::RuntimeAppConfig,
+        config: &models::RuntimeAppConfig,
+    ) -> Result<WrittenFiles, ConfigFilesError> {
+        let mut stale = Vec::new();
+        for name in previous.config.app_config.keys() {
+            let path = self.path_of(name)?;
+            if !config.config.app_config.contains_key(name) {
+                self.check_no_symlinks(&path, name)?;
+                stale.push(path);
+            }
+        }
+        let mut files = self.write(config)?;
+        for path in stale {
+            // The current config may name the same file differently.
+            if files.written.contains(&path) {
+                continue;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => files.removed.push(path),
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(ConfigFilesError::Io { path, error }),
+            }
+        }
+        Ok(files)
+    }
+
+    /// Check the paths and decode the contents of all files of `config`.
+    fn decode(
+        &self,
+        config: &models::RuntimeAppConfig,
+    ) -> Result<BTreeMap<PathBuf, (String, Vec<u8>)>, ConfigFilesError> {
+        let mut files = BTreeMap::new();
+        for (name, contents) in &config.config.app_config {
+            let path = self.path_of(name)?;
+            // Files without contents are written empty.
+            let contents = match contents.contents {
+                Some(ref contents) => STANDARD.decode(contents).map_err(|error| {
+                    ConfigFilesError::InvalidContents {
+                        path: name.clone(),
+                        error,
+                    }
+                })?,
+                None => Vec::new(),
+            };
+            if files.insert(path, (name.clone(), contents)).is_some() {
+                return Err(ConfigFilesError::InvalidPath {
+                    path: name.clone(),
+                    reason: "names the same file as another path",
+                });
+            }
+        }
+        Ok(files)
+    }
+
+    /// Fail if a symbolic link under the root could redirect `path`, named
+    /// `name` in the config, to outside of it.
+    fn check_no_symlinks(&self, path: &Path, name: &str) -> Result<(), ConfigFilesError> {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let mut current = self.root.clone();
+        for component in relative.components() {
+            current.push(component);
+            match fs::symlink_metadata(&current) {
+                Ok(ref metadata) if metadata.file_type().is_symlink() => {
+                    return Err(ConfigFilesError::InvalidPath {
+                        path: name.to_owned(),
+                        reason: "goes through a symbolic link",
+                    });
+                }
+                Ok(_) => {}
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => break,
+                Err(error) => {
+                    return Err(ConfigFilesError::Io {
+                        path: current,
+                        error,
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_file(&self, path: &Path, contents: &[u8]) -> Result<(), ConfigFilesError> {
+        let io_error = |error| ConfigFilesError::Io {
+            path: path.to_owned(),
+            error,
+        };
+        let parent = path.parent().unwrap_or(&self.root);
+        fs::create_dir_all(parent).map_err(io_error)?;
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        // Unique, so that concurrent writers do not share a temporary file.
+        let temporary = parent.join(format!(".{}.{}.tmp", file_name, Uuid::new_v4()));
+        let result = self
+            .write_temporary(&temporary, contents)
+            .and_then(|()| fs::rename(&temporary, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+        result.map_err(io_error)
+    }
+
+    fn write_temporary(&self, temporary: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(temporary)?;
+        set_mode(&file, self.mode)?;
+        file.write_all(contents)?;
+        file.sync_all()
+    }
+}
+
+#[cfg(unix)]
+fn set_mode(file: &File, mode: u32) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    file.set_permissions(fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(unix))]
+fn set_mode(_file: &File, _mode: u32) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigFiles, ConfigFilesError};
+    use models;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::thread;
+    use uuid::Uuid;
+
+    /// A fresh directory under the system's temporary directory.
+    fn root() -> PathBuf {
+        let root = env::temp_dir().join(format!("em-client-config-files-{}", Uuid::new_v4()));
+        fs::create_dir(&root).unwrap();
+        root
+    }
+
+    fn config(files: &[(&str, Option<&str>)]) -> models::RuntimeAppConfig {
+        let app_config = files
+            .iter()
+            .map(|&(path, contents)| {
+                let mut file = models::ApplicationConfigContents::new();
+                file.contents = contents.map(str::to_owned);
+                (path.to_owned(), file)
+            })
+            .collect();
+        models::RuntimeAppConfig::new(
+            models::HashedConfig::new(app_config, Default::default(), Default::default()),
+            models::ApplicationConfigExtra::new(),
+        )
+    }
+
+    #[test]
+    fn test_write_and_replace() {
+        let root = root();
+        let files = ConfigFiles::new(&root).with_mode(0o640);
+        let first = config(&[
+            ("/opt/app/rw/harmonize.txt", Some("aGVsbG8=")),
+            ("/opt/app/rw/old.txt", Some("")),
+            ("settings", None),
+        ]);
+        let written = files.write(&first).unwrap();
+        assert_eq!(written.written.len(), 3);
+        let harmonize = root.join("opt/app/rw/harmonize.txt");
+        assert_eq!(fs::read(&harmonize).unwrap(), b"hello");
+        assert_eq!(fs::read(root.join("settings")).unwrap(), b"");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&harmonize).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o640);
+        }
+
+        let second = config(&[
+            ("/opt/app/rw/harmonize.txt", Some("d29ybGQ=")),
+            ("settings", None),
+        ]);
+        let replaced = files.replace(&first, &second).unwrap();
+        assert_eq!(replaced.removed, [root.join("opt/app/rw/old.txt")]);
+        assert_eq!(fs::read(&harmonize).unwrap(), b"world");
+        let mut names: Vec<_> = fs::read_dir(root.join("opt/app/rw"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["harmonize.txt"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_configs_are_not_written() {
+        let root = root();
+        let files = ConfigFiles::new(&root);
+        for path in &["/opt/../../etc/passwd", "/", "a/./../b", "nul\0"] {
+            let err = files
+                .write(&config(&[("a.txt", Some("")), (path, Some(""))]))
+                .unwrap_err();
+            match err {
+                ConfigFilesError::InvalidPath { .. } => {}
+                err => panic!("unexpected error {:?}", err),
+            }
+        }
+        // Base64 must be padded and must not have whitespace or trailing bits.
+        for contents in &["aGVsbG8", "aGVs bG8=", "aGVsbG9=", "aGVsbG8=\n"] {
+            let err = files
+                .write(&config(&[("a.txt", Some("")), ("b.txt", Some(contents))]))
+                .unwrap_err();
+            match err {
+                ConfigFilesError::InvalidContents { ref path, .. } => assert_eq!(path, "b.txt"),
+                err => panic!("unexpected error {:?}", err),
+            }
+        }
+        let err = files
+            .write(&config(&[("/a.txt", Some("")), ("a.txt", Some(""))]))
+            .unwrap_err();
+        match err {
+            ConfigFilesError::InvalidPath { .. } => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 0);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_writers() {
+        let root = root();
+        let writers: Vec<_> = (0..8)
+            .map(|_| {
+                let root = root.clone();
+                thread::spawn(move || {
+                    let files = ConfigFiles::new(&root);
+                    for _ in 0..20 {
+                        files
+                            .write(&config(&[("a.txt", Some("aGVsbG8="))]))
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        assert_eq!(fs::read(root.join("a.txt")).unwrap(), b"hello");
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 1);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_are_not_followed() {
+        let (root, outside) = (root(), root());
+        ::std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+        let err = ConfigFiles::new(&root)
+            .write(&config(&[("/link/secret", Some("aGVsbG8="))]))
+            .unwrap_err();
+        match err {
+            ConfigFilesError::InvalidPath { .. } => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(fs::read_dir(&outside).unwrap().count(), 0);
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stale_symlinks_are_checked_before_writing() {
+        let (root, outside) = (root(), root());
+        ::std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+        let files = ConfigFiles::new(&root);
+        let previous = config(&[("/link/secret", Some(""))]);
+        let err = files
+            .replace(&previous, &config(&[("a.txt", Some(""))]))
+            .unwrap_err();
+        match err {
+            ConfigFilesError::InvalidPath { .. } => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert!(!root.join("a.txt").exists());
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! The hash enclaves pin application configs by.
+//!
+//! `get_runtime_application_config` only accepts a runtime config whose
+//! `config` part, a `HashedConfig`, has the expected hash. The `extra` part
+//! is not hashed. The hash is the SHA-256 of the canonical JSON form of the
+//! `HashedConfig`, which is the JSON of the parsed config serialized again:
+//!
+//! * There is no whitespace outside of strings.
+//! * Objects have their fields in the order `app_config`, `labels`,
+//!   `zone_ca`, `workflow`. Workflows have theirs in the order
+//!   `workflow_id`, `app_name`, `port_map`, `app_acct_id`, `app_group_id`,
+//!   and dataset ports in the order `id`, `acct_id`, `group_id`.
+//! * The keys of `app_config`, `labels` and the two levels of `port_map`
+//!   are sorted by their UTF-8 bytes.
+//! * `zone_ca` is sorted the same way and duplicates are removed.
+//! * Optional fields that are absent or `null`, such as `workflow` or the
+//!   `contents` of a file, are left out.
+//! * UUIDs are written in lowercase with hyphens.
+//! * Strings are written as UTF-8. Only `"`, `\` and control characters are
+//!   escaped: `\b`, `\f`, `\n`, `\r` and `\t` in their short form, the others
+//!   as `\u00XX` with lowercase hex digits. `/` and non-ASCII characters are
+//!   not escaped.
+//! * File contents are hashed as given, they are not decoded from base64.
+//!
+//! Unknown fields are rejected, except in the runtime config outside of the
+//! `config` part. Hashing a JSON file with a tool like `sha256sum` therefore
+//! only gives the pinned hash if the file is already canonical.
+//!
+//! ```
+//! use em_client::config_hash;
+//!
+//! let raw = r#"{ "labels": {}, "zone_ca": [], "app_config": {} }"#;
+//! let hash = config_hash::hash_config_json(raw).unwrap();
+//! assert_eq!(
+//!     hash.to_string(),
+//!     "c3fa49d025535abf69840f13cecc79e995dd51d715ec5659a05c8ede0cab7bc2"
+//! );
+//! ```
+use serde_json;
+
+use models;
+use Sha256Hash;
+use SHA256_BYTE_LENGTH;
+
+impl models::HashedConfig {
+    /// The hash an enclave pins this config by.
+    pub fn canonical_hash(&self) -> Sha256Hash {
+        sha256(canonical_json(self).as_bytes())
+    }
+}
+
+/// The canonical JSON form of `config`, as hashed by
+/// `HashedConfig::canonical_hash`.
+pub fn canonical_json(config: &models::HashedConfig) -> String {
+    // Maps only have string keys, so serializing cannot fail.
+    serde_json::to_string(config).expect("HashedConfig serializes to JSON")
+}
+
+/// Hash the JSON of a `HashedConfig`, in any formatting and field order.
+pub fn hash_config_json(raw: &str) -> Result<Sha256Hash, serde_json::Error> {
+    let config = serde_json::from_str::<models::HashedConfig>(raw)?;
+    Ok(config.canonical_hash())
+}
+
+/// Hash the JSON of a `RuntimeAppConfig` as the client does when it checks
+/// the config against an expected hash.
+pub fn hash_runtime_config_json(raw: &str) -> Result<Sha256Hash, serde_json::Error> {
+    let runtime_config = serde_json::from_str::<models::RuntimeAppConfig>(raw)?;
+    Ok(runtime_config.config.canonical_hash())
+}
+
+fn sha256(input: &[u8]) -> Sha256Hash {
+    use mbedtls::hash::{Md, Type};
+    let mut digest = [0; SHA256_BYTE_LENGTH];
+    // Hashing only fails for output buffers shorter than the digest.
+    Md::hash(Type::Sha256, input, &mut digest).expect("SHA-256 digest fits the buffer");
+    Sha256Hash(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_json, hash_config_json, hash_runtime_config_json};
+    use models;
+
+    #[test]
+    fn test_canonical_form() {
+        let raw = r#"{
+            "zone_ca": ["b", "a", "b"],
+            "labels": {"location": "Zürich", "env": "prod/eu"},
+            "app_config": {
+                "/etc/empty": {"contents": null},
+                "/etc/app.conf": {"contents": "aGVsbG8="}
+            }
+        }"#;
+        let config = serde_json::from_str::<models::HashedConfig>(raw).unwrap();
+        assert_eq!(
+            canonical_json(&config),
+            r#"{"app_config":{"/etc/app.conf":{"contents":"aGVsbG8="},"/etc/empty":{}},"labels":{"env":"prod/eu","location":"Zürich"},"zone_ca":["a","b"]}"#
+        );
+        assert_eq!(
+            config.canonical_hash().to_string(),
+            "2bbf51f06e34cedc153ba121e16b5bebfb7370e67fc0821da89885688cb6e6f0"
+        );
+    }
+
+    #[test]
+    fn test_workflow_vector() {
+        let raw = r#"{
+            "app_config": {},
+            "labels": {},
+            "zone_ca": ["ca"],
+            "workflow": {
+                "app_name": "app",
+                "port_map": {
+                    "output": {"out": {"dataset": {
+                        "group_id": "0a1b2c3d-0000-4000-8000-00000000000c",
+                        "id": "0A1B2C3D-0000-4000-8000-00000000000D"
+                    }}},
+                    "input": {"in": {"application": {}}}
+                },
+                "workflow_id": "0a1b2c3d-0000-4000-8000-00000000000a"
+            }
+        }"#;
+        assert_eq!(
+            hash_config_json(raw).unwrap().to_string(),
+            "7adc37f8c8a5f30c12093f430408e58a17c10638c1b2927c0c00ad1e218cbaa3"
+        );
+    }
+
+    #[test]
+    fn test_runtime_config_json() {
+        // The extra part is not hashed.
+        let runtime = |extra: &str| {
+            format!(
+                r#"{{"config":{{"app_config":{{}},"labels":{{}},"zone_ca":[]}},"extra":{}}}"#,
+                extra
+            )
+        };
+        let hash = hash_runtime_config_json(&runtime("{}")).unwrap();
+        assert_eq!(
+            hash.to_string(),
+            "c3fa49d025535abf69840f13cecc79e995dd51d715ec5659a05c8ede0cab7bc2"
+        );
+        let extra = r#"{"connections":{"input":{"in":{"application":{"workflow_domain":"in.example.com"}}}}}"#;
+        assert_eq!(hash_runtime_config_json(&runtime(extra)).unwrap(), hash);
+
+        let unknown = r#"{"app_config":{},"labels":{},"zone_ca":[],"ports":[]}"#;
+        assert!(hash_config_json(unknown).is_err());
+    }
+}
@@ -0,0 +1,485 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Picking up changes to the runtime config of an application while it runs.
+//!
+//! A `ConfigWatcher` fetches the runtime config on every poll and accepts it
+//! only if its canonical hash is one of the allowed hashes. Configs with
+//! other hashes are reported and ignored, so the watcher keeps the last good
+//! config. Only the hashed part of a config is compared, so changes to the
+//! `extra` part alone are not reported.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use em_client::config_watch::ConfigWatcher;
+//! use em_client::wait::WaitOptions;
+//! use em_client::Sha256Hash;
+//! use std::convert::TryFrom;
+//! use std::time::Duration;
+//!
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let allowed = Sha256Hash::try_from(
+//!     "c3fa49d025535abf69840f13cecc79e995dd51d715ec5659a05c8ede0cab7bc2",
+//! )?;
+//! let mut watcher = ConfigWatcher::new(client, vec![allowed]);
+//! let options = WaitOptions::new().with_interval(Duration::from_secs(60));
+//! watcher.watch(&options, |change| match change {
+//!     Ok(change) => println!("Labels changed: {:?}", change.diff.labels),
+//!     Err(err) => eprintln!("Keeping the current config: {}", err),
+//! });
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::error;
+use std::fmt;
+use std::time::Instant;
+
+use models;
+use wait::WaitOptions;
+use Sha256Hash;
+
+/// Where a `ConfigWatcher` gets the runtime config from. The config is
+/// checked by the watcher, not by the source.
+pub trait RuntimeConfigSource {
+    type Error;
+
+    fn fetch_runtime_config(&self) -> Result<models::RuntimeAppConfig, Self::Error>;
+}
+
+#[cfg(feature = "client")]
+impl RuntimeConfigSource for ::Client {
+    type Error = ::ApiError;
+
+    fn fetch_runtime_config(&self) -> Result<models::RuntimeAppConfig, ::ApiError> {
+        self.get_unverified_runtime_application_config()
+    }
+}
+
+impl<F, E> RuntimeConfigSource for F
+where
+    F: Fn() -> Result<models::RuntimeAppConfig, E>,
+{
+    type Error = E;
+
+    fn fetch_runtime_config(&self) -> Result<models::RuntimeAppConfig, E> {
+        self()
+    }
+}
+
+/// A value before and after a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// Changes to a map of a config.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapDiff<V> {
+    pub added: BTreeMap<String, V>,
+    pub removed: BTreeMap<String, V>,
+    pub changed: BTreeMap<String, Change<V>>,
+}
+
+impl<V> Default for MapDiff<V> {
+    fn default() -> Self {
+        MapDiff {
+            added: BTreeMap::new(),
+            removed: BTreeMap::new(),
+            changed: BTreeMap::new(),
+        }
+    }
+}
+
+impl<V: Clone + PartialEq> MapDiff<V> {
+    fn new(old: &BTreeMap<String, V>, new: &BTreeMap<String, V>) -> Self {
+        let mut diff = MapDiff::default();
+        for (key, value) in new {
+            match old.get(key) {
+                None => {
+                    diff.added.insert(key.clone(), value.clone());
+                }
+                Some(old) if old != value => {
+                    let change = Change {
+                        old: old.clone(),
+                        new: value.clone(),
+                    };
+                    diff.changed.insert(key.clone(), change);
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, value) in old {
+            if !new.contains_key(key) {
+                diff.removed.insert(key.clone(), value.clone());
+            }
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Changes to a set of a config.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SetDiff {
+    pub added: BTreeSet<String>,
+    pub removed: BTreeSet<String>,
+}
+
+impl SetDiff {
+    fn new(old: &BTreeSet<String>, new: &BTreeSet<String>) -> Self {
+        SetDiff {
+            added: new.difference(old).cloned().collect(),
+            removed: old.difference(new).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// The changes between two versions of a `HashedConfig`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigDiff {
+    /// Files by path.
+    pub app_config: MapDiff<models::ApplicationConfigContents>,
+    pub labels: MapDiff<String>,
+    /// Zone CA certificates.
+    pub zone_ca: SetDiff,
+    pub workflow: Option<Change<Option<models::ApplicationConfigWorkflow>>>,
+}
+
+impl ConfigDiff {
+    pub fn new(old: &models::HashedConfig, new: &models::HashedConfig) -> Self {
+        let workflow = match old.workflow == new.workflow {
+            true => None,
+            false => Some(Change {
+                old: old.workflow.clone(),
+                new: new.workflow.clone(),
+            }),
+        };
+        ConfigDiff {
+            app_config: MapDiff::new(&old.app_config, &new.app_config),
+            labels: MapDiff::new(&old.labels, &new.labels),
+            zone_ca: SetDiff::new(&old.zone_ca, &new.zone_ca),
+            workflow,
+        }
+    }
+
+    /// The changes from an empty config, i.e. everything in `config` added.
+    fn initial(config: &models::HashedConfig) -> Self {
+        let empty = models::HashedConfig::new(BTreeMap::new(), BTreeMap::new(), BTreeSet::new());
+        ConfigDiff::new(&empty, config)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.app_config.is_empty()
+            && self.labels.is_empty()
+            && self.zone_ca.is_empty()
+            && self.workflow.is_none()
+    }
+}
+
+/// A new config accepted by a `ConfigWatcher`.
+#[derive(Clone, Debug)]
+pub struct ConfigChange {
+    pub hash: Sha256Hash,
+    /// The last good config before this one, if any.
+    pub previous: Option<models::RuntimeAppConfig>,
+    pub config: models::RuntimeAppConfig,
+    /// The changes from the previous config, or from an empty config for the
+    /// first one.
+    pub diff: ConfigDiff,
+}
+
+/// Why a poll of a `ConfigWatcher` did not give a config.
+#[derive(Debug)]
+pub enum WatchError<E> {
+    /// The config has a hash that is not allowed.
+    HashMismatch(Sha256Hash),
+    /// Fetching the config failed.
+    Api(E),
+}
+
+impl<E: fmt::Display> fmt::Display for WatchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WatchError::HashMismatch(ref hash) => {
+                write!(f, "Runtime config hash {} is not allowed", hash)
+            }
+            WatchError::Api(ref err) => write!(f, "Failed to fetch the runtime config: {}", err),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for WatchError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            WatchError::Api(ref err) => Some(err),
+            WatchError::HashMismatch(_) => None,
+        }
+    }
+}
+
+/// Polls a source for the runtime config, keeping the last config with an
+/// allowed hash.
+pub struct ConfigWatcher<S> {
+    source: S,
+    allowed: HashSet<Sha256Hash>,
+    current: Option<(Sha256Hash, models::RuntimeAppConfig)>,
+}
+
+impl<S: RuntimeConfigSource> ConfigWatcher<S> {
+    pub fn new<I: IntoIterator<Item = Sha256Hash>>(source: S, allowed: I) -> Self {
+        ConfigWatcher {
+            source,
+            allowed: allowed.into_iter().collect(),
+            current: None,
+        }
+    }
+
+    /// Start from `config`, e.g. the config the application was started
+    /// with, so that only later changes are reported. Fails if the config
+    /// does not have an allowed hash.
+    pub fn with_current(
+        self,
+        config: models::RuntimeAppConfig,
+    ) -> Result<Self, WatchError<S::Error>> {
+        let hash = config.config.canonical_hash();
+        if !self.allowed.contains(&hash) {
+            return Err(WatchError::HashMismatch(hash));
+        }
+        Ok(ConfigWatcher {
+            current: Some((hash, config)),
+            ..self
+        })
+    }
+
+    /// Accept configs with `hash` from now on.
+    pub fn allow(&mut self, hash: Sha256Hash) {
+        self.allowed.insert(hash);
+    }
+
+    /// Stop accepting configs with `hash`. The current config is kept even
+    /// if it has this hash.
+    pub fn disallow(&mut self, hash: &Sha256Hash) {
+        self.allowed.remove(hash);
+    }
+
+    /// The last good config.
+    pub fn current(&self) -> Option<&models::RuntimeAppConfig> {
+        self.current.as_ref().map(|(_, config)| config)
+    }
+
+    pub fn into_current(self) -> Option<models::RuntimeAppConfig> {
+        self.current.map(|(_, config)| config)
+    }
+
+    /// Fetch the config once. Returns the change if the config differs from
+    /// the last good config and has an allowed hash.
+    pub fn poll(&mut self) -> Result<Option<ConfigChange>, WatchError<S::Error>> {
+        let config = self
+            .source
+            .fetch_runtime_config()
+            .map_err(WatchError::Api)?;
+        let hash = config.config.canonical_hash();
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|(current, _)| *current == hash)
+        {
+            return Ok(None);
+        }
+        if !self.allowed.contains(&hash) {
+            return Err(WatchError::HashMismatch(hash));
+        }
+        let previous = self.current.take().map(|(_, config)| config);
+        let diff = match previous {
+            Some(ref previous) => ConfigDiff::new(&previous.config, &config.config),
+            None => ConfigDiff::initial(&config.config),
+        };
+        self.current = Some((hash, config.clone()));
+        Ok(Some(ConfigChange {
+            hash,
+            previous,
+            config,
+            diff,
+        }))
+    }
+
+    /// Poll every interval of `options` until cancelled or the timeout of
+    /// `options` passes, calling `callback` with every change and every
+    /// failed poll.
+    pub fn watch<F>(&mut self, options: &WaitOptions, mut callback: F)
+    where
+        F: FnMut(Result<&ConfigChange, &WatchError<S::Error>>),
+    {
+        let deadline = options.timeout().map(|timeout| Instant::now() + timeout);
+        while !options.is_cancelled() {
+            match self.poll() {
+                Ok(Some(change)) => callback(Ok(&change)),
+                Ok(None) => {}
+                Err(err) => callback(Err(&err)),
+            }
+            let now = Instant::now();
+            let sleep = match deadline {
+                Some(deadline) if deadline <= now => return,
+                Some(deadline) => options.interval().min(deadline - now),
+                None => options.interval(),
+            };
+            if !options.sleep(sleep) {
+                return;
+            }
+        }
+    }
+}
+
+impl<S> fmt::Debug for ConfigWatcher<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConfigWatcher")
+            .field("allowed", &self.allowed)
+            .field("current", &self.current.as_ref().map(|(hash, _)| hash))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigWatcher, WatchError};
+    use models;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::time::Duration;
+    use wait::{CancellationToken, WaitOptions};
+
+    fn config(files: &[(&str, &str)], labels: &[(&str, &str)]) -> models::RuntimeAppConfig {
+        let app_config = files
+            .iter()
+            .map(|&(path, contents)| {
+                let mut file = models::ApplicationConfigContents::new();
+                file.contents = Some(contents.to_owned());
+                (path.to_owned(), file)
+            })
+            .collect();
+        let labels = labels
+            .iter()
+            .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+        let zone_ca = Some("zone ca".to_owned()).into_iter().collect();
+        models::RuntimeAppConfig::new(
+            models::HashedConfig::new(app_config, labels, zone_ca),
+            models::ApplicationConfigExtra::new(),
+        )
+    }
+
+    #[test]
+    fn test_poll() {
+        let first = config(&[("a.txt", "YQ=="), ("b.txt", "Yg==")], &[("env", "test")]);
+        let second = config(&[("a.txt", "QQ=="), ("c.txt", "Yw==")], &[("env", "test")]);
+        let rogue = config(&[], &[("env", "rogue")]);
+        let versions = RefCell::new(VecDeque::from(vec![
+            first.clone(),
+            first.clone(),
+            rogue.clone(),
+            second.clone(),
+        ]));
+        let source = || versions.borrow_mut().pop_front().ok_or("unavailable");
+        let allowed = vec![
+            first.config.canonical_hash(),
+            second.config.canonical_hash(),
+        ];
+        match ConfigWatcher::new(source, allowed.clone()).with_current(rogue.clone()) {
+            Err(WatchError::HashMismatch(hash)) => assert_eq!(hash, rogue.config.canonical_hash()),
+            other => panic!("unexpected result {:?}", other.map(|_| ())),
+        }
+        let mut watcher = ConfigWatcher::new(source, allowed);
+
+        let change = watcher.poll().unwrap().unwrap();
+        assert!(change.previous.is_none());
+        assert_eq!(change.diff.app_config.added.len(), 2);
+        assert_eq!(change.diff.zone_ca.added.len(), 1);
+        assert!(watcher.poll().unwrap().is_none());
+
+        match watcher.poll().unwrap_err() {
+            WatchError::HashMismatch(hash) => assert_eq!(hash, rogue.config.canonical_hash()),
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(watcher.current(), Some(&first));
+
+        let change = watcher.poll().unwrap().unwrap();
+        assert_eq!(change.previous, Some(first));
+        let diff = change.diff;
+        assert_eq!(diff.app_config.added.keys().collect::<Vec<_>>(), ["c.txt"]);
+        assert_eq!(
+            diff.app_config.removed.keys().collect::<Vec<_>>(),
+            ["b.txt"]
+        );
+        assert_eq!(
+            diff.app_config.changed["a.txt"].new.contents.as_deref(),
+            Some("QQ==")
+        );
+        assert!(diff.labels.is_empty() && diff.zone_ca.is_empty() && diff.workflow.is_none());
+
+        match watcher.poll().unwrap_err() {
+            WatchError::Api(err) => assert_eq!(err, "unavailable"),
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(watcher.into_current(), Some(second));
+    }
+
+    #[test]
+    fn test_watch() {
+        let initial = config(&[], &[("env", "test")]);
+        let updated = config(&[], &[("env", "prod")]);
+        let polls = RefCell::new(0);
+        let token = CancellationToken::new();
+        let source = || {
+            *polls.borrow_mut() += 1;
+            match *polls.borrow() {
+                1 => Ok(initial.clone()),
+                2 => Err("unavailable"),
+                _ => Ok(updated.clone()),
+            }
+        };
+        let allowed = vec![
+            initial.config.canonical_hash(),
+            updated.config.canonical_hash(),
+        ];
+        let mut watcher = ConfigWatcher::new(source, allowed)
+            .with_current(initial.clone())
+            .unwrap();
+        let options = WaitOptions::new()
+            .with_interval(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5))
+            .with_cancellation(token.clone());
+
+        let mut events = Vec::new();
+        watcher.watch(&options, |change| {
+            match change {
+                Ok(change) => events.push(format!("{:?}", change.diff.labels.changed["env"])),
+                Err(err) => events.push(err.to_string()),
+            }
+            token.cancel();
+        });
+        assert_eq!(events, ["Failed to fetch the runtime config: unavailable"]);
+        assert_eq!(watcher.current(), Some(&initial));
+
+        let mut events = Vec::new();
+        let token = CancellationToken::new();
+        let options = options.with_cancellation(token.clone());
+        watcher.watch(&options, |change| {
+            events.push(change.unwrap().diff.labels.changed["env"].clone());
+            token.cancel();
+        });
+        assert_eq!(events.len(), 1);
+        assert_eq!((&*events[0].old, &*events[0].new), ("test", "prod"));
+        assert_eq!(watcher.current(), Some(&updated));
+    }
+}
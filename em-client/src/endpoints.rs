@@ -0,0 +1,444 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! The datasets and applications the ports of a workflow application are
+//! connected to.
+//!
+//! The `port_map` of the workflow in a runtime config tells which dataset or
+//! application each input and output port is for. Where to reach it is in
+//! the `connections` of the `extra` part, under the same direction and port
+//! name. `Endpoints` joins the two, and fails if a port has no connection,
+//! a connection has no port, or the two are of different kinds.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use em_client::endpoints::{Endpoint, Endpoints};
+//! use em_client::Api;
+//!
+//! # let expected_hash = [0; 32];
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let config = client.get_runtime_application_config(&expected_hash)?;
+//! for (port, endpoint) in Endpoints::resolve(&config)?.inputs {
+//!     match endpoint {
+//!         Endpoint::Dataset(dataset) => println!("{} reads {}", port, dataset.location),
+//!         Endpoint::Application(app) => println!("{} listens for {}", port, app.workflow_domain),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+
+use uuid::Uuid;
+
+use models;
+
+/// Whether a port is read from or written to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PortDirection {
+    Input,
+    Output,
+}
+
+impl PortDirection {
+    /// The key of the direction in `port_map` and `connections`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            PortDirection::Input => "input",
+            PortDirection::Output => "output",
+        }
+    }
+
+    fn parse(direction: &str) -> Option<Self> {
+        match direction {
+            "input" => Some(PortDirection::Input),
+            "output" => Some(PortDirection::Output),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a port is connected to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortKind {
+    Dataset,
+    Application,
+}
+
+impl fmt::Display for PortKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PortKind::Dataset => f.write_str("dataset"),
+            PortKind::Application => f.write_str("application"),
+        }
+    }
+}
+
+/// Why the ports of a workflow could not be matched with their connections.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EndpointError {
+    /// `port_map` or `connections` has a direction other than `input` and
+    /// `output`.
+    UnknownDirection(String),
+    /// A port of the workflow has no connection.
+    MissingConnection {
+        direction: PortDirection,
+        port: String,
+    },
+    /// A connection is for a port the workflow does not have.
+    UnexpectedConnection {
+        direction: PortDirection,
+        port: String,
+    },
+    /// The connection of a port is not of the kind of the port, or is of
+    /// more than one kind.
+    KindMismatch {
+        direction: PortDirection,
+        port: String,
+        expected: PortKind,
+    },
+    /// The connection of a dataset port has no credentials.
+    MissingCredentials {
+        direction: PortDirection,
+        port: String,
+    },
+}
+
+impl fmt::Display for EndpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EndpointError::UnknownDirection(ref direction) => {
+                write!(f, "Unknown port direction {:?}", direction)
+            }
+            EndpointError::MissingConnection {
+                direction,
+                ref port,
+            } => write!(f, "No connection for {} port {:?}", direction, port),
+            EndpointError::UnexpectedConnection {
+                direction,
+                ref port,
+            } => write!(
+                f,
+                "Connection for {} port {:?}, which is not in the workflow",
+                direction, port
+            ),
+            EndpointError::KindMismatch {
+                direction,
+                ref port,
+                expected,
+            } => write!(
+                f,
+                "Connection for {} port {:?} is not a single {} connection",
+                direction, port, expected
+            ),
+            EndpointError::MissingCredentials {
+                direction,
+                ref port,
+            } => write!(
+                f,
+                "Dataset connection for {} port {:?} has no credentials",
+                direction, port
+            ),
+        }
+    }
+}
+
+impl error::Error for EndpointError {}
+
+/// A dataset a port reads or writes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatasetEndpoint {
+    pub id: Uuid,
+    pub acct_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    pub location: String,
+    /// Where to get the credentials for `location`.
+    pub credentials: models::ApplicationConfigSdkmsCredentials,
+}
+
+/// Another application of the workflow a port talks to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApplicationEndpoint {
+    pub acct_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    /// The domain of the application within the workflow.
+    pub workflow_domain: String,
+}
+
+/// What a port is connected to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Endpoint {
+    Dataset(DatasetEndpoint),
+    Application(ApplicationEndpoint),
+}
+
+impl Endpoint {
+    pub fn kind(&self) -> PortKind {
+        match *self {
+            Endpoint::Dataset(_) => PortKind::Dataset,
+            Endpoint::Application(_) => PortKind::Application,
+        }
+    }
+}
+
+/// The endpoints of the ports of a workflow application, by port name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Endpoints {
+    pub inputs: BTreeMap<String, Endpoint>,
+    pub outputs: BTreeMap<String, Endpoint>,
+}
+
+impl Endpoints {
+    /// Match the ports of the workflow of `config` with their connections.
+    /// An application outside of a workflow has no ports.
+    pub fn resolve(config: &models::RuntimeAppConfig) -> Result<Self, EndpointError> {
+        match config.config.workflow {
+            Some(ref workflow) => Endpoints::resolve_workflow(workflow, &config.extra),
+            None => Endpoints::resolve_ports(&BTreeMap::new(), &config.extra),
+        }
+    }
+
+    pub fn resolve_workflow(
+        workflow: &models::ApplicationConfigWorkflow,
+        extra: &models::ApplicationConfigExtra,
+    ) -> Result<Self, EndpointError> {
+        Endpoints::resolve_ports(&workflow.port_map, extra)
+    }
+
+    pub fn get(&self, direction: PortDirection, port: &str) -> Option<&Endpoint> {
+        match direction {
+            PortDirection::Input => self.inputs.get(port),
+            PortDirection::Output => self.outputs.get(port),
+        }
+    }
+
+    fn resolve_ports(
+        port_map: &BTreeMap<String, BTreeMap<String, models::ApplicationConfigPort>>,
+        extra: &models::ApplicationConfigExtra,
+    ) -> Result<Self, EndpointError> {
+        let no_connections = BTreeMap::new();
+        let connections = extra.connections.as_ref().unwrap_or(&no_connections);
+        let mut endpoints = Endpoints::default();
+        for (direction, ports) in port_map {
+            let direction = parse_direction(direction)?;
+            let connections = connections.get(direction.as_str());
+            for (port, port_config) in ports {
+                let connection = connections
+                    .and_then(|connections| connections.get(port))
+                    .ok_or_else(|| EndpointError::MissingConnection {
+                        direction,
+                        port: port.clone(),
+                    })?;
+                let endpoint = endpoint(direction, port, port_config, connection)?;
+                match direction {
+                    PortDirection::Input => endpoints.inputs.insert(port.clone(), endpoint),
+                    PortDirection::Output => endpoints.outputs.insert(port.clone(), endpoint),
+                };
+            }
+        }
+        for (direction, connections) in connections {
+            let direction = parse_direction(direction)?;
+            if let Some(port) = connections
+                .keys()
+                .find(|port| endpoints.get(direction, port).is_none())
+            {
+                return Err(EndpointError::UnexpectedConnection {
+                    direction,
+                    port: port.clone(),
+                });
+            }
+        }
+        Ok(endpoints)
+    }
+}
+
+fn parse_direction(direction: &str) -> Result<PortDirection, EndpointError> {
+    PortDirection::parse(direction)
+        .ok_or_else(|| EndpointError::UnknownDirection(direction.to_owned()))
+}
+
+fn endpoint(
+    direction: PortDirection,
+    port: &str,
+    port_config: &models::ApplicationConfigPort,
+    connection: &models::ApplicationConfigConnection,
+) -> Result<Endpoint, EndpointError> {
+    match (port_config, &connection.dataset, &connection.application) {
+        (models::ApplicationConfigPort::Dataset(dataset), Some(connection), None) => {
+            let credentials = connection.credentials.sdkms.clone().ok_or_else(|| {
+                EndpointError::MissingCredentials {
+                    direction,
+                    port: port.to_owned(),
+                }
+            })?;
+            Ok(Endpoint::Dataset(DatasetEndpoint {
+                id: dataset.id,
+                acct_id: dataset.acct_id,
+                group_id: dataset.group_id,
+                location: connection.location.clone(),
+                credentials,
+            }))
+        }
+        (models::ApplicationConfigPort::Application(app), None, Some(connection)) => {
+            Ok(Endpoint::Application(ApplicationEndpoint {
+                acct_id: app.acct_id,
+                group_id: app.group_id,
+                workflow_domain: connection.workflow_domain.clone(),
+            }))
+        }
+        (models::ApplicationConfigPort::Dataset(_), _, _) => Err(EndpointError::KindMismatch {
+            direction,
+            port: port.to_owned(),
+            expected: PortKind::Dataset,
+        }),
+        (models::ApplicationConfigPort::Application(_), _, _) => Err(EndpointError::KindMismatch {
+            direction,
+            port: port.to_owned(),
+            expected: PortKind::Application,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Endpoint, EndpointError, Endpoints, PortDirection, PortKind};
+    use models;
+    use serde_json::json;
+
+    fn config(
+        port_map: serde_json::Value,
+        connections: serde_json::Value,
+    ) -> models::RuntimeAppConfig {
+        serde_json::from_value(json!({
+            "config": {
+                "app_config": {},
+                "labels": {},
+                "zone_ca": [],
+                "workflow": {
+                    "workflow_id": "c375e923-1390-4440-b9e6-ad9977557e5e",
+                    "app_name": "app",
+                    "port_map": port_map,
+                },
+            },
+            "extra": {"connections": connections},
+        }))
+        .unwrap()
+    }
+
+    fn dataset_connection() -> serde_json::Value {
+        json!({"dataset": {
+            "location": "s3://bucket/in",
+            "credentials": {"sdkms": {
+                "credentials_url": "https://sdkms.example.com",
+                "credentials_key_name": "in-key",
+                "sdkms_app_id": "0b73608a-0e2c-42e5-ba12-64f13e165120",
+            }},
+        }})
+    }
+
+    #[test]
+    fn test_resolve() {
+        let config = config(
+            json!({
+                "input": {"in": {"dataset": {"id": "e97447d5-6e1f-4cc8-9a56-cf4502f9ddd0"}}},
+                "output": {"peer": {"application": {}}},
+            }),
+            json!({
+                "input": {"in": dataset_connection()},
+                "output": {"peer": {"application": {"workflow_domain": "peer.workflow.example.com"}}},
+            }),
+        );
+        let endpoints = Endpoints::resolve(&config).unwrap();
+        match endpoints.get(PortDirection::Input, "in") {
+            Some(Endpoint::Dataset(dataset)) => {
+                assert_eq!(
+                    dataset.id.to_string(),
+                    "e97447d5-6e1f-4cc8-9a56-cf4502f9ddd0"
+                );
+                assert_eq!(dataset.location, "s3://bucket/in");
+                assert_eq!(dataset.credentials.credentials_key_name, "in-key");
+            }
+            endpoint => panic!("unexpected endpoint {:?}", endpoint),
+        }
+        match endpoints.get(PortDirection::Output, "peer") {
+            Some(Endpoint::Application(app)) => {
+                assert_eq!(app.workflow_domain, "peer.workflow.example.com")
+            }
+            endpoint => panic!("unexpected endpoint {:?}", endpoint),
+        }
+        assert_eq!(endpoints.inputs.len() + endpoints.outputs.len(), 2);
+
+        let mut config = config;
+        config.config.workflow = None;
+        config.extra.connections = None;
+        assert_eq!(Endpoints::resolve(&config), Ok(Endpoints::default()));
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let dataset_port =
+            json!({"in": {"dataset": {"id": "e97447d5-6e1f-4cc8-9a56-cf4502f9ddd0"}}});
+        let resolve =
+            |port_map, connections| Endpoints::resolve(&config(port_map, connections)).unwrap_err();
+
+        let err = resolve(json!({"input": dataset_port}), json!({}));
+        assert_eq!(
+            err,
+            EndpointError::MissingConnection {
+                direction: PortDirection::Input,
+                port: "in".to_owned(),
+            }
+        );
+        assert_eq!(err.to_string(), r#"No connection for input port "in""#);
+
+        let err = resolve(
+            json!({"input": dataset_port}),
+            json!({"input": {"in": dataset_connection()}, "output": {"out": dataset_connection()}}),
+        );
+        assert_eq!(
+            err,
+            EndpointError::UnexpectedConnection {
+                direction: PortDirection::Output,
+                port: "out".to_owned(),
+            }
+        );
+
+        let err = resolve(
+            json!({"input": dataset_port}),
+            json!({"input": {"in": {"application": {"workflow_domain": "in.example.com"}}}}),
+        );
+        assert_eq!(
+            err,
+            EndpointError::KindMismatch {
+                direction: PortDirection::Input,
+                port: "in".to_owned(),
+                expected: PortKind::Dataset,
+            }
+        );
+
+        let err = resolve(
+            json!({"input": dataset_port}),
+            json!({"input": {"in": {"dataset": {"location": "s3://bucket/in", "credentials": {}}}}}),
+        );
+        assert_eq!(
+            err,
+            EndpointError::MissingCredentials {
+                direction: PortDirection::Input,
+                port: "in".to_owned(),
+            }
+        );
+
+        let err = resolve(json!({"inout": dataset_port}), json!({}));
+        assert_eq!(err, EndpointError::UnknownDirection("inout".to_owned()));
+    }
+}
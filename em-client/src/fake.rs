@@ -0,0 +1,3195 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! An in-memory Enclave Manager for testing code that is generic over `Api`.
+//!
+//! `FakeEnclaveManager` implements every category trait, and therefore
+//! `Api`, against state kept in memory. Objects get sequential IDs and
+//! timestamps from a fake clock, so tests are deterministic. Listings support
+//! the same filters, `sort_by` and `limit`/`offset` paging as the server.
+//!
+//! The fake starts with one user, logged in and a manager of one account,
+//! which is selected and has one zone. All objects are visible regardless
+//! of the selected account, and every call is made on behalf of that user.
+//!
+//! Creating a build, provisioning a node or requesting a certificate opens a
+//! task in the `INPROGRESS` state. Approving the task with `update_task`
+//! whitelists the build, attests the node or issues the certificate; denying
+//! it rejects them. `set_task_status` moves a task to any state, e.g.
+//! `FAILED`, with the same effect on the object it belongs to.
+//!
+//! Logging in and approving or denying tasks add events to the audit log
+//! returned by `get_all_events`.
+//!
+//! ```
+//! use em_client::fake::FakeEnclaveManager;
+//! use em_client::models::EventType;
+//! use em_client::Api;
+//!
+//! let fake = FakeEnclaveManager::new();
+//! fake.fail_next("get_manager_version", 503, EventType::UNDERGOING_MAINTENANCE);
+//! assert_eq!(fake.get_manager_version().unwrap_err().status(), Some(503));
+//! assert!(fake.get_manager_version().is_ok());
+//! assert_eq!(fake.calls("get_manager_version"), 2);
+//! ```
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{self, Value};
+
+use models;
+use uuid;
+use {
+    AccountsApi, ApiError, AppApi, ApplicationConfigApi, ApprovalRequestsApi, AuthApi, BuildApi,
+    CertificateApi, DatasetApi, EventsApi, NodeApi, RegistryApi, SimpleErrorType, SystemApi,
+    TaskApi, ToolsApi, UsersApi, WorkflowApi, WorkflowFinalApi, ZoneApi,
+};
+
+/// Version reported by `get_manager_version`.
+pub const FAKE_MANAGER_VERSION: &str = "fake";
+
+/// Email and password of the user the fake starts with.
+pub const FAKE_USER_EMAIL: &str = "admin@example.com";
+pub const FAKE_USER_PASSWORD: &str = "password";
+
+/// Zone settings returned to nodes by `update_node_status`.
+const NODE_REFRESH_INTERVAL: i64 = 86400;
+const NODE_RENEWAL_THRESHOLD: i32 = 15;
+
+/// Lifetime of sessions, approval requests and issued certificates.
+const SESSION_LIFETIME: i64 = 3600;
+const APPROVAL_REQUEST_LIFETIME: i64 = 86400;
+const CERTIFICATE_LIFETIME: i64 = 365 * 86400;
+
+/// An in-memory implementation of the Enclave Manager API.
+pub struct FakeEnclaveManager {
+    state: Mutex<State>,
+}
+
+struct Injected {
+    status: u16,
+    code: models::EventType,
+    always: bool,
+}
+
+struct Deployment {
+    build_id: uuid::Uuid,
+    info: models::AppNodeInfo,
+}
+
+struct State {
+    next_id: u64,
+    clock: i64,
+    user_id: uuid::Uuid,
+    selected_account: Option<uuid::Uuid>,
+    calls: HashMap<String, usize>,
+    failures: HashMap<String, VecDeque<Injected>>,
+    accounts: BTreeMap<uuid::Uuid, models::Account>,
+    apps: BTreeMap<uuid::Uuid, models::App>,
+    application_configs: BTreeMap<String, models::ApplicationConfigResponse>,
+    approval_requests: BTreeMap<uuid::Uuid, models::ApprovalRequest>,
+    builds: BTreeMap<uuid::Uuid, models::Build>,
+    certificates: BTreeMap<uuid::Uuid, models::Certificate>,
+    datasets: BTreeMap<uuid::Uuid, models::Dataset>,
+    deployments: Vec<Deployment>,
+    events: Vec<models::AuditEvent>,
+    final_workflows: BTreeMap<uuid::Uuid, models::FinalWorkflow>,
+    nodes: BTreeMap<uuid::Uuid, models::Node>,
+    passwords: HashMap<uuid::Uuid, String>,
+    registries: BTreeMap<uuid::Uuid, models::Registry>,
+    tasks: BTreeMap<uuid::Uuid, models::Task>,
+    users: BTreeMap<uuid::Uuid, models::User>,
+    workflows: BTreeMap<uuid::Uuid, models::WorkflowGraph>,
+    zones: BTreeMap<uuid::Uuid, models::Zone>,
+}
+
+impl FakeEnclaveManager {
+    pub fn new() -> Self {
+        let mut state = State {
+            next_id: 1,
+            clock: 1_600_000_000,
+            user_id: uuid::Uuid::nil(),
+            selected_account: None,
+            calls: HashMap::new(),
+            failures: HashMap::new(),
+            accounts: BTreeMap::new(),
+            apps: BTreeMap::new(),
+            application_configs: BTreeMap::new(),
+            approval_requests: BTreeMap::new(),
+            builds: BTreeMap::new(),
+            certificates: BTreeMap::new(),
+            datasets: BTreeMap::new(),
+            deployments: Vec::new(),
+            events: Vec::new(),
+            final_workflows: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+            passwords: HashMap::new(),
+            registries: BTreeMap::new(),
+            tasks: BTreeMap::new(),
+            users: BTreeMap::new(),
+            workflows: BTreeMap::new(),
+            zones: BTreeMap::new(),
+        };
+
+        let user_id = state.new_id();
+        let now = state.now();
+        let mut user = models::User::new(user_id, FAKE_USER_EMAIL.to_owned());
+        user.created_at = Some(now);
+        user.last_logged_in_at = Some(now);
+        user.email_verified = Some(true);
+        user.status = Some(models::UserStatus::ACTIVE);
+        user.roles = Some(vec![models::AccessRoles::MANAGER]);
+        user.user_account_status = Some(models::UserAccountStatus::ACTIVE);
+        user.accepted_latest_terms_and_conditions = Some(true);
+        state.users.insert(user_id, user);
+        state
+            .passwords
+            .insert(user_id, FAKE_USER_PASSWORD.to_owned());
+        state.user_id = user_id;
+
+        let account = state.insert_account("Default Account".to_owned());
+        state.selected_account = Some(account.acct_id);
+
+        FakeEnclaveManager {
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Make the next call of `operation` fail with `status` and an error
+    /// payload of type `code`. Calling this repeatedly fails as many calls.
+    pub fn fail_next(&self, operation: &str, status: u16, code: models::EventType) {
+        self.inject(operation, status, code, false)
+    }
+
+    /// Make every call of `operation` fail, after the failures already
+    /// queued by `fail_next`, until `clear_failures` is called.
+    pub fn fail_always(&self, operation: &str, status: u16, code: models::EventType) {
+        self.inject(operation, status, code, true)
+    }
+
+    pub fn clear_failures(&self) {
+        self.lock().failures.clear();
+    }
+
+    /// The number of times `operation` was called, including failed calls.
+    pub fn calls(&self, operation: &str) -> usize {
+        self.lock().calls.get(operation).cloned().unwrap_or(0)
+    }
+
+    /// Move a task to `status`, updating the object it belongs to as if the
+    /// task had been approved (`SUCCESS`) or not (`DENIED`, `FAILED`).
+    pub fn set_task_status(
+        &self,
+        task_id: uuid::Uuid,
+        status: models::TaskStatusType,
+    ) -> Result<models::Task, ApiError> {
+        let mut state = self.lock();
+        state.task(task_id)?;
+        state.set_task_status(task_id, status);
+        state.task(task_id).cloned()
+    }
+
+    /// Run a whitelisted build on a running node, as the node agent would
+    /// after the enclave starts.
+    pub fn deploy_build(
+        &self,
+        build_id: uuid::Uuid,
+        node_id: uuid::Uuid,
+    ) -> Result<models::AppNodeInfo, ApiError> {
+        let mut state = self.lock();
+        if state.build(build_id)?.status.status != models::BuildStatusType::WHITELISTED {
+            return Err(invalid_state(format!(
+                "Build {} is not whitelisted",
+                build_id
+            )));
+        }
+        let node = state.node(node_id)?;
+        if node.status.status != models::NodeStatusType::RUNNING {
+            return Err(invalid_state(format!("Node {} is not running", node_id)));
+        }
+        let node_name = node.name.clone();
+        let (_, certificate) = state.issue_certificate(None);
+        let now = state.now();
+        let mut info = models::AppNodeInfo::new(certificate, now, node_id);
+        info.node_name = Some(node_name);
+        info.status = Some(models::AppStatus {
+            status: Some(models::AppStatusType::RUNNING),
+            status_updated_at: Some(now),
+            attested_at: Some(now),
+        });
+        info.is_debug = Some(false);
+        state
+            .deployments
+            .retain(|d| !(d.build_id == build_id && d.info.node_id == node_id));
+        state.deployments.push(Deployment {
+            build_id,
+            info: info.clone(),
+        });
+        if let Some(build) = state.builds.get_mut(&build_id) {
+            build.deployment_status = Some(models::BuildDeploymentStatus::new(
+                models::BuildDeploymentStatusType::DEPLOYED,
+                now,
+            ));
+        }
+        info.build_info = state.builds.get(&build_id).cloned();
+        Ok(info)
+    }
+
+    fn inject(&self, operation: &str, status: u16, code: models::EventType, always: bool) {
+        self.lock()
+            .failures
+            .entry(operation.to_owned())
+            .or_default()
+            .push_back(Injected {
+                status,
+                code,
+                always,
+            });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panicking test must not poison the fake for the others.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record a call of `operation` and fail it if an error was injected.
+    fn enter(&self, operation: &str) -> Result<MutexGuard<'_, State>, ApiError> {
+        let mut state = self.lock();
+        *state.calls.entry(operation.to_owned()).or_insert(0) += 1;
+        let injected = match state.failures.get_mut(operation) {
+            Some(queue) => match queue.front() {
+                Some(front) if front.always => Some((front.status, front.code)),
+                Some(_) => queue.pop_front().map(|i| (i.status, i.code)),
+                None => None,
+            },
+            None => None,
+        };
+        match injected {
+            Some((status, code)) => Err(error(
+                status,
+                code,
+                format!("Injected failure of {}", operation),
+            )),
+            None => Ok(state),
+        }
+    }
+}
+
+impl Default for FakeEnclaveManager {
+    fn default() -> Self {
+        FakeEnclaveManager::new()
+    }
+}
+
+/// An error as the client reports it for a response with `status` and an
+/// `Event` payload.
+fn error(status: u16, code: models::EventType, message: String) -> ApiError {
+    let event = models::Event::new(message.clone(), code);
+    let body = serde_json::to_vec(&event).expect("Event serializes");
+    let error_type = match status {
+        500..=599 => SimpleErrorType::Temporary,
+        _ => SimpleErrorType::Permanent,
+    };
+    ApiError::new(
+        format!("Unexpected response code {}: {}", status, message),
+        error_type,
+    )
+    .with_response(
+        status,
+        vec![("Content-Type".to_owned(), "application/json".to_owned())],
+        body,
+    )
+}
+
+fn not_found<T: ToString>(kind: &str, id: T) -> ApiError {
+    error(
+        404,
+        models::EventType::NOT_FOUND,
+        format!("{} {} not found", kind, id.to_string()),
+    )
+}
+
+fn bad_request(message: String) -> ApiError {
+    error(400, models::EventType::BAD_REQUEST, message)
+}
+
+fn invalid_state(message: String) -> ApiError {
+    error(409, models::EventType::INVALID_STATE, message)
+}
+
+fn already_exists(kind: &str, name: &str) -> ApiError {
+    error(
+        409,
+        models::EventType::UNIQUE_VIOLATION,
+        format!("{} {} already exists", kind, name),
+    )
+}
+
+fn auth_failed() -> ApiError {
+    error(
+        401,
+        models::EventType::AUTH_FAILED,
+        "Authentication failed".to_owned(),
+    )
+}
+
+/// A certificate in PEM form whose contents only identify it.
+fn fake_pem(id: uuid::Uuid) -> String {
+    use base64::Engine;
+    let der = base64::engine::general_purpose::STANDARD.encode(id.as_bytes());
+    format!(
+        "-----BEGIN CERTIFICATE-----\n{}\n-----END CERTIFICATE-----\n",
+        der
+    )
+}
+
+/// A 64 character hex string derived from `id`, used for fake measurements.
+fn fake_digest(id: uuid::Uuid) -> String {
+    id.simple().to_string().repeat(2)
+}
+
+impl State {
+    fn new_id(&mut self) -> uuid::Uuid {
+        let mut bytes = [0; 16];
+        bytes[8..].copy_from_slice(&self.next_id.to_be_bytes());
+        self.next_id += 1;
+        uuid::Uuid::from_uuid_bytes(bytes)
+    }
+
+    /// The fake clock advances by one second on every reading.
+    fn now(&mut self) -> i64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn user(&self) -> Result<&models::User, ApiError> {
+        self.users
+            .get(&self.user_id)
+            .ok_or_else(|| not_found("User", self.user_id))
+    }
+
+    fn account_id(&self) -> Result<uuid::Uuid, ApiError> {
+        self.selected_account.ok_or_else(|| {
+            error(
+                400,
+                models::EventType::NO_ACCOUNT_SELECTED,
+                "No account selected".to_owned(),
+            )
+        })
+    }
+
+    fn insert_account(&mut self, name: String) -> models::Account {
+        let acct_id = self.new_id();
+        let mut account = models::Account::new(acct_id);
+        account.name = Some(name);
+        account.created_at = Some(self.now());
+        account.roles = Some(vec![models::AccessRoles::MANAGER]);
+        account.status = Some(models::UserAccountStatus::ACTIVE);
+        account.approval_state = Some(models::AccountApprovalState::APPROVED);
+        self.accounts.insert(acct_id, account.clone());
+
+        let zone_id = self.new_id();
+        let mut zone = models::Zone::new(
+            acct_id,
+            fake_pem(zone_id),
+            zone_id,
+            "Default Zone".to_owned(),
+            NODE_REFRESH_INTERVAL,
+            NODE_RENEWAL_THRESHOLD,
+        );
+        zone.description = Some("Default zone of the account".to_owned());
+        self.zones.insert(zone_id, zone);
+        account
+    }
+
+    fn app(&self, app_id: uuid::Uuid) -> Result<&models::App, ApiError> {
+        self.apps
+            .get(&app_id)
+            .ok_or_else(|| not_found("App", app_id))
+    }
+
+    fn application_config(
+        &self,
+        config_id: &str,
+    ) -> Result<&models::ApplicationConfigResponse, ApiError> {
+        self.application_configs
+            .get(config_id)
+            .ok_or_else(|| not_found("Application config", config_id))
+    }
+
+    fn approval_request(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> Result<&models::ApprovalRequest, ApiError> {
+        self.approval_requests
+            .get(&request_id)
+            .ok_or_else(|| not_found("Approval request", request_id))
+    }
+
+    fn build(&self, build_id: uuid::Uuid) -> Result<&models::Build, ApiError> {
+        self.builds
+            .get(&build_id)
+            .ok_or_else(|| not_found("Build", build_id))
+    }
+
+    fn dataset(&self, dataset_id: uuid::Uuid) -> Result<&models::Dataset, ApiError> {
+        self.datasets
+            .get(&dataset_id)
+            .ok_or_else(|| not_found("Dataset", dataset_id))
+    }
+
+    fn final_workflow(&self, graph_id: uuid::Uuid) -> Result<&models::FinalWorkflow, ApiError> {
+        self.final_workflows
+            .get(&graph_id)
+            .ok_or_else(|| not_found("Final workflow graph", graph_id))
+    }
+
+    fn node(&self, node_id: uuid::Uuid) -> Result<&models::Node, ApiError> {
+        self.nodes
+            .get(&node_id)
+            .ok_or_else(|| not_found("Node", node_id))
+    }
+
+    fn registry(&self, registry_id: uuid::Uuid) -> Result<&models::Registry, ApiError> {
+        self.registries
+            .get(&registry_id)
+            .ok_or_else(|| not_found("Registry", registry_id))
+    }
+
+    fn task(&self, task_id: uuid::Uuid) -> Result<&models::Task, ApiError> {
+        self.tasks
+            .get(&task_id)
+            .ok_or_else(|| not_found("Task", task_id))
+    }
+
+    fn workflow(&self, graph_id: uuid::Uuid) -> Result<&models::WorkflowGraph, ApiError> {
+        self.workflows
+            .get(&graph_id)
+            .ok_or_else(|| not_found("Workflow graph", graph_id))
+    }
+
+    fn zone(&self, zone_id: uuid::Uuid) -> Result<&models::Zone, ApiError> {
+        self.zones
+            .get(&zone_id)
+            .ok_or_else(|| not_found("Zone", zone_id))
+    }
+
+    fn find_user(&self, user_id: uuid::Uuid) -> Result<&models::User, ApiError> {
+        self.users
+            .get(&user_id)
+            .ok_or_else(|| not_found("User", user_id))
+    }
+
+    /// The zone of the selected account, or of any account if none is.
+    fn default_zone(&self) -> Option<&models::Zone> {
+        let acct_id = self.selected_account;
+        self.zones
+            .values()
+            .find(|zone| Some(zone.acct_id) == acct_id)
+            .or_else(|| self.zones.values().next())
+    }
+
+    /// The app as returned by the API, with its deployments.
+    fn app_view(&self, app: &models::App) -> models::App {
+        let nodes = self
+            .deployments
+            .iter()
+            .filter(|d| {
+                self.builds
+                    .get(&d.build_id)
+                    .is_some_and(|build| build.app_id == Some(app.app_id))
+            })
+            .map(|d| self.deployment_view(d))
+            .collect();
+        models::App {
+            nodes: Some(nodes),
+            ..app.clone()
+        }
+    }
+
+    /// The node as returned by the API, with the apps running on it.
+    fn node_view(&self, node: &models::Node) -> models::Node {
+        let apps = self
+            .deployments
+            .iter()
+            .filter(|d| d.info.node_id == node.node_id)
+            .map(|d| self.deployment_view(d))
+            .collect();
+        models::Node {
+            apps,
+            ..node.clone()
+        }
+    }
+
+    fn deployment_view(&self, deployment: &Deployment) -> models::AppNodeInfo {
+        models::AppNodeInfo {
+            build_info: self.builds.get(&deployment.build_id).cloned(),
+            ..deployment.info.clone()
+        }
+    }
+
+    fn deployment(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> Result<(&Deployment, &models::Build), ApiError> {
+        self.node(node_id)?;
+        self.app(app_id)?;
+        self.deployments
+            .iter()
+            .filter(|d| d.info.node_id == node_id)
+            .filter_map(|d| self.builds.get(&d.build_id).map(|build| (d, build)))
+            .find(|&(_, build)| build.app_id == Some(app_id))
+            .ok_or_else(|| {
+                not_found(
+                    "Deployment",
+                    format!("of app {} on node {}", app_id, node_id),
+                )
+            })
+    }
+
+    /// Store an issued certificate and return its ID and contents.
+    fn issue_certificate(&mut self, csr: Option<String>) -> (uuid::Uuid, models::Certificate) {
+        let certificate_id = self.new_id();
+        let certificate = models::Certificate {
+            certificate_id: Some(certificate_id),
+            status: Some(models::CertificateStatusType::ISSUED),
+            csr,
+            certificate: Some(fake_pem(certificate_id)),
+        };
+        self.certificates
+            .insert(certificate_id, certificate.clone());
+        (certificate_id, certificate)
+    }
+
+    fn certificate_details(
+        &mut self,
+        subject_name: String,
+        enclave_info: Option<models::EnclaveInfo>,
+    ) -> models::CertificateDetails {
+        let now = self.now();
+        let mut details = models::CertificateDetails::new(
+            subject_name,
+            "Fake Enclave Manager".to_owned(),
+            now + CERTIFICATE_LIFETIME,
+            now,
+            "00000000000000000000000000000000".to_owned(),
+            "OK".to_owned(),
+        );
+        details.enclave_info = enclave_info;
+        details
+    }
+
+    fn create_task(
+        &mut self,
+        task_type: models::TaskType,
+        entity_id: uuid::Uuid,
+        description: String,
+    ) -> uuid::Uuid {
+        let task_id = self.new_id();
+        let now = self.now();
+        let mut requester_info = models::RequesterInfo::new(models::RequesterType::USER);
+        requester_info.user_id = Some(self.user_id);
+        requester_info.user_name = self.users.get(&self.user_id).map(|u| u.user_email.clone());
+        let mut task = models::Task::new(
+            task_id,
+            requester_info,
+            entity_id,
+            task_type,
+            models::TaskStatus::new(now, now, models::TaskStatusType::INPROGRESS),
+            Vec::new(),
+        );
+        task.description = Some(description);
+        self.tasks.insert(task_id, task);
+        task_id
+    }
+
+    /// Add an event caused by the current user to the audit log.
+    fn record_event(
+        &mut self,
+        action_type: models::EventActionType,
+        entity_id: uuid::Uuid,
+        message: String,
+    ) {
+        let event_id = self.new_id();
+        let now = self.now();
+        let mut event = models::AuditEvent::new(
+            event_id,
+            models::EventActorType::USER,
+            action_type,
+            models::EventSeverity::INFO,
+            message,
+            now,
+        );
+        event.acct_id = self.selected_account;
+        event.actor_id = Some(self.user_id);
+        event.entity_id = Some(entity_id);
+        self.events.push(event);
+    }
+
+    fn task_result(&self, task_id: uuid::Uuid) -> Result<models::TaskResult, ApiError> {
+        let task = self.task(task_id)?;
+        let mut result = models::TaskResult::new();
+        result.task_id = Some(task_id);
+        result.task_type = Some(task.task_type);
+        result.task_status = Some(task.status.clone());
+        match task.task_type {
+            models::TaskType::BUILD_WHITELIST => result.build_id = Some(task.entity_id),
+            models::TaskType::CERTIFICATE_ISSUANCE => result.certificate_id = Some(task.entity_id),
+            models::TaskType::NODE_ATTESTATION => result.node_id = Some(task.entity_id),
+            models::TaskType::DOMAIN_WHITELIST => {}
+        }
+        Ok(result)
+    }
+
+    /// Move a task to `status` and update the object it belongs to.
+    fn set_task_status(&mut self, task_id: uuid::Uuid, status: models::TaskStatusType) {
+        let now = self.now();
+        let (task_type, entity_id) = match self.tasks.get_mut(&task_id) {
+            Some(task) => {
+                task.status.status = status;
+                task.status.status_updated_at = now;
+                (task.task_type, task.entity_id)
+            }
+            None => return,
+        };
+        let success = status == models::TaskStatusType::SUCCESS;
+        match task_type {
+            models::TaskType::BUILD_WHITELIST => {
+                if let Some(build) = self.builds.get_mut(&entity_id) {
+                    let build_status = match status {
+                        models::TaskStatusType::SUCCESS => models::BuildStatusType::WHITELISTED,
+                        models::TaskStatusType::INPROGRESS => models::BuildStatusType::PENDING,
+                        _ => models::BuildStatusType::REJECTED,
+                    };
+                    build.status = models::BuildStatus::new(build_status, now);
+                    build.updated_at = Some(now);
+                    if status != models::TaskStatusType::INPROGRESS {
+                        build.pending_task_id = None;
+                    }
+                }
+            }
+            models::TaskType::NODE_ATTESTATION => {
+                let certificate = match success {
+                    true => Some(self.issue_certificate(None).1),
+                    false => None,
+                };
+                if let Some(node) = self.nodes.get_mut(&entity_id) {
+                    node.status.status = match status {
+                        models::TaskStatusType::SUCCESS => models::NodeStatusType::RUNNING,
+                        models::TaskStatusType::INPROGRESS => models::NodeStatusType::INPROGRESS,
+                        _ => models::NodeStatusType::FAILED,
+                    };
+                    node.status.status_updated_at = now;
+                    if let Some(certificate) = certificate {
+                        node.attested_at = Some(now);
+                        node.certificate = certificate.certificate;
+                    }
+                }
+            }
+            models::TaskType::CERTIFICATE_ISSUANCE => {
+                if let Some(certificate) = self.certificates.get_mut(&entity_id) {
+                    certificate.status = Some(match status {
+                        models::TaskStatusType::SUCCESS => models::CertificateStatusType::ISSUED,
+                        models::TaskStatusType::INPROGRESS => {
+                            models::CertificateStatusType::PENDING
+                        }
+                        _ => models::CertificateStatusType::REJECTED,
+                    });
+                    if success {
+                        certificate.certificate = Some(fake_pem(entity_id));
+                    }
+                }
+            }
+            models::TaskType::DOMAIN_WHITELIST => {
+                if let Some(app) = self.apps.get_mut(&entity_id) {
+                    if success {
+                        app.whitelisted_domains = app.allowed_domains.clone();
+                    }
+                    if status != models::TaskStatusType::INPROGRESS {
+                        app.pending_task_id = None;
+                        app.domains_added = None;
+                        app.domains_removed = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The runtime config the server hands to the enclave for `config`.
+    fn runtime_config(
+        &self,
+        config: &models::ApplicationConfigResponse,
+    ) -> models::RuntimeAppConfig {
+        let zone_ca = config
+            .zone
+            .as_ref()
+            .and_then(|zone| self.zones.get(&zone.id))
+            .or_else(|| self.default_zone())
+            .map(|zone| zone.certificate.clone())
+            .into_iter()
+            .collect();
+        let hashed =
+            models::HashedConfig::new(config.app_config.clone(), config.labels.clone(), zone_ca);
+        models::RuntimeAppConfig::new(hashed, models::ApplicationConfigExtra::new())
+    }
+
+    /// Check that `password` belongs to the logged in user.
+    fn check_password(&self, user_id: uuid::Uuid, password: &str) -> Result<(), ApiError> {
+        match self.passwords.get(&user_id) {
+            Some(expected) if expected == password => Ok(()),
+            _ => Err(auth_failed()),
+        }
+    }
+
+    fn session_info(&mut self) -> models::SessionInfo {
+        let now = self.now();
+        models::SessionInfo::new(self.user_id, now + SESSION_LIFETIME, now + SESSION_LIFETIME)
+    }
+}
+
+/// The reset and confirmation tokens issued by the fake are the user ID.
+fn check_token(user_id: uuid::Uuid, token: &str) -> Result<(), ApiError> {
+    match token == user_id.to_string() {
+        true => Ok(()),
+        false => Err(error(
+            400,
+            models::EventType::INVALID_VALUE,
+            "Invalid token".to_owned(),
+        )),
+    }
+}
+
+/// Case-insensitive substring match; an absent filter matches everything.
+fn contains<S: AsRef<str>>(value: Option<S>, filter: &Option<String>) -> bool {
+    match *filter {
+        Some(ref filter) => value.is_some_and(|value| {
+            value
+                .as_ref()
+                .to_lowercase()
+                .contains(&filter.to_lowercase())
+        }),
+        None => true,
+    }
+}
+
+fn equals<T: ToString>(value: Option<T>, filter: &Option<String>) -> bool {
+    match *filter {
+        Some(ref filter) => value.is_some_and(|value| value.to_string() == *filter),
+        None => true,
+    }
+}
+
+/// `all_search` matches if any of the searchable fields contains it.
+fn any_contains(values: &[Option<&str>], filter: &Option<String>) -> bool {
+    filter.is_none() || values.iter().any(|value| contains(*value, filter))
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Int(i64),
+    Str(String),
+}
+
+impl From<&str> for SortKey {
+    fn from(value: &str) -> Self {
+        SortKey::Str(value.to_owned())
+    }
+}
+
+impl From<i64> for SortKey {
+    fn from(value: i64) -> Self {
+        SortKey::Int(value)
+    }
+}
+
+/// Sort `items` as requested by a `sort_by` parameter of the form
+/// `<field>[:asc|:desc]`. `key` returns `None` for fields that cannot be
+/// sorted by.
+fn sort<T, F>(items: &mut [T], sort_by: &Option<String>, key: F) -> Result<(), ApiError>
+where
+    F: Fn(&T, &str) -> Option<SortKey>,
+{
+    let sort_by = match *sort_by {
+        Some(ref sort_by) => sort_by,
+        None => return Ok(()),
+    };
+    let mut parts = sort_by.splitn(2, ':');
+    let field = parts.next().unwrap_or("");
+    let descending = match parts.next() {
+        None | Some("asc") => false,
+        Some("desc") => true,
+        Some(_) => return Err(invalid_query("sort_by", sort_by)),
+    };
+    if let Some(first) = items.first() {
+        if key(first, field).is_none() {
+            return Err(invalid_query("sort_by", sort_by));
+        }
+    }
+    items.sort_by(|a, b| {
+        let ordering = key(a, field).cmp(&key(b, field));
+        match descending {
+            true => ordering.reverse(),
+            false => ordering,
+        }
+    });
+    Ok(())
+}
+
+fn invalid_query(name: &str, value: &str) -> ApiError {
+    error(
+        400,
+        models::EventType::INVALID_QUERY_PARAMETER,
+        format!("Invalid value {:?} for query parameter {}", value, name),
+    )
+}
+
+/// Apply `limit` and `offset` to the filtered items. Without a limit all
+/// items from the offset on are returned.
+fn page<T>(
+    items: Vec<T>,
+    total_count: usize,
+    limit: Option<i32>,
+    offset: Option<i32>,
+) -> Result<(Option<models::SearchMetadata>, Vec<T>), ApiError> {
+    let filtered_count = items.len();
+    let offset = match offset {
+        Some(offset) if offset < 0 => return Err(invalid_query("offset", &offset.to_string())),
+        Some(offset) => offset as usize,
+        None => 0,
+    };
+    let limit = match limit {
+        Some(limit) if limit <= 0 => return Err(invalid_query("limit", &limit.to_string())),
+        Some(limit) => limit as usize,
+        None => filtered_count.saturating_sub(offset).max(1),
+    };
+    let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+    let metadata = models::SearchMetadata::new(
+        (offset / limit + 1) as isize,
+        filtered_count.div_ceil(limit) as isize,
+        limit as isize,
+        total_count as isize,
+        filtered_count as isize,
+    );
+    Ok((Some(metadata), items))
+}
+
+/// Apply a JSON patch made of `add`, `replace` and `remove` operations to
+/// the JSON form of `target`.
+fn patch<T: Serialize + DeserializeOwned>(
+    target: &T,
+    patch: Vec<models::PatchDocument>,
+) -> Result<T, ApiError> {
+    let mut value = serde_json::to_value(target)
+        .map_err(|e| bad_request(format!("Invalid patch target: {}", e)))?;
+    for document in patch {
+        let invalid = || bad_request(format!("Invalid patch path {:?}", document.path));
+        let split = document.path.rfind('/').ok_or_else(invalid)?;
+        let (parent, key) = document.path.split_at(split);
+        let key = key[1..].replace("~1", "/").replace("~0", "~");
+        let parent = value.pointer_mut(parent).ok_or_else(invalid)?;
+        if parent.is_null() {
+            *parent = Value::Object(Default::default());
+        }
+        let object = parent.as_object_mut().ok_or_else(invalid)?;
+        match document.op {
+            models::PatchOperation::ADD | models::PatchOperation::REPLACE => {
+                let new = document.value.clone().unwrap_or(Value::Null);
+                object.insert(key, new);
+            }
+            models::PatchOperation::REMOVE => {
+                object.remove(&key).ok_or_else(invalid)?;
+            }
+        }
+    }
+    serde_json::from_value(value).map_err(|e| bad_request(format!("Invalid patch: {}", e)))
+}
+
+/// Count the label pairs of a set of objects.
+fn count_labels<'a, I>(labels: I) -> models::LabelsCount
+where
+    I: Iterator<Item = &'a HashMap<String, String>>,
+{
+    let mut counts = BTreeMap::new();
+    for labels in labels {
+        for (key, value) in labels {
+            *counts.entry((key.clone(), value.clone())).or_insert(0) += 1;
+        }
+    }
+    models::LabelsCount::new(
+        counts
+            .into_iter()
+            .map(|((key, value), count)| models::LabelCount::new(key, value, count))
+            .collect(),
+    )
+}
+
+impl AccountsApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_account(&self, body: models::AccountRequest) -> Result<models::Account, ApiError> {
+        let mut state = self.enter("create_account")?;
+        if state
+            .accounts
+            .values()
+            .any(|account| account.name.as_ref() == Some(&body.name))
+        {
+            return Err(already_exists("Account", &body.name));
+        }
+        let mut account = state.insert_account(body.name);
+        account.custom_logo = body.custom_logo;
+        state.accounts.insert(account.acct_id, account.clone());
+        Ok(account)
+    }
+
+    fn delete_account(&self, account_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_account")?;
+        state
+            .accounts
+            .remove(&account_id)
+            .ok_or_else(|| not_found("Account", account_id))?;
+        state.zones.retain(|_, zone| zone.acct_id != account_id);
+        if state.selected_account == Some(account_id) {
+            state.selected_account = None;
+        }
+        Ok(())
+    }
+
+    fn get_account(&self, account_id: uuid::Uuid) -> Result<models::Account, ApiError> {
+        let state = self.enter("get_account")?;
+        state
+            .accounts
+            .get(&account_id)
+            .cloned()
+            .ok_or_else(|| not_found("Account", account_id))
+    }
+
+    fn get_accounts(&self) -> Result<models::AccountListResponse, ApiError> {
+        let state = self.enter("get_accounts")?;
+        let mut response = models::AccountListResponse::new();
+        response.items = Some(state.accounts.values().cloned().collect());
+        Ok(response)
+    }
+
+    fn select_account(&self, account_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("select_account")?;
+        if !state.accounts.contains_key(&account_id) {
+            return Err(not_found("Account", account_id));
+        }
+        state.selected_account = Some(account_id);
+        Ok(())
+    }
+
+    fn update_account(
+        &self,
+        account_id: uuid::Uuid,
+        body: models::AccountUpdateRequest,
+    ) -> Result<models::Account, ApiError> {
+        let mut state = self.enter("update_account")?;
+        let account = state
+            .accounts
+            .get_mut(&account_id)
+            .ok_or_else(|| not_found("Account", account_id))?;
+        if body.name.is_some() {
+            account.name = body.name;
+        }
+        if body.custom_logo.is_some() {
+            account.custom_logo = body.custom_logo;
+        }
+        if body.features.is_some() {
+            account.features = body.features;
+        }
+        Ok(account.clone())
+    }
+}
+
+impl AppApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn add_application(&self, body: models::AppRequest) -> Result<models::App, ApiError> {
+        let mut state = self.enter("add_application")?;
+        if state.apps.values().any(|app| app.name == body.name) {
+            return Err(already_exists("App", &body.name));
+        }
+        let app_id = state.new_id();
+        let now = state.now();
+        let mut app = models::App::new(
+            body.name,
+            app_id,
+            body.input_image_name,
+            body.output_image_name,
+            body.isvprodid,
+            body.isvsvn,
+            body.mem_size,
+            body.threads,
+        );
+        app.created_at = Some(now);
+        app.updated_at = Some(now);
+        app.description = body.description;
+        app.whitelisted_domains = body.allowed_domains.clone();
+        app.allowed_domains = body.allowed_domains;
+        app.advanced_settings = body.advanced_settings;
+        app.labels = body.labels;
+        state.apps.insert(app_id, app.clone());
+        Ok(state.app_view(&app))
+    }
+
+    fn delete_app(&self, app_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_app")?;
+        state
+            .apps
+            .remove(&app_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("App", app_id))
+    }
+
+    fn get_all_apps(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllAppsResponse, ApiError> {
+        let state = self.enter("get_all_apps")?;
+        let mut items: Vec<_> = state
+            .apps
+            .values()
+            .filter(|app| contains(Some(&app.name), &name))
+            .filter(|app| contains(app.description.as_ref(), &description))
+            .filter(|app| {
+                any_contains(
+                    &[
+                        Some(&app.name),
+                        app.description.as_deref(),
+                        Some(&app.input_image_name),
+                        Some(&app.output_image_name),
+                    ],
+                    &all_search,
+                )
+            })
+            .map(|app| state.app_view(app))
+            .collect();
+        sort(&mut items, &sort_by, |app, field| match field {
+            "name" => Some(app.name.as_str().into()),
+            "created_at" => Some(app.created_at.unwrap_or(0).into()),
+            "updated_at" => Some(app.updated_at.unwrap_or(0).into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.apps.len(), limit, offset)?;
+        Ok(models::GetAllAppsResponse { metadata, items })
+    }
+
+    fn get_app(&self, app_id: uuid::Uuid) -> Result<models::App, ApiError> {
+        let state = self.enter("get_app")?;
+        state.app(app_id).map(|app| state.app_view(app))
+    }
+
+    fn get_app_certificate(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> Result<models::Certificate, ApiError> {
+        let state = self.enter("get_app_certificate")?;
+        state
+            .deployment(node_id, app_id)
+            .map(|(deployment, _)| deployment.info.certificate.clone())
+    }
+
+    fn get_app_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> Result<models::CertificateDetails, ApiError> {
+        let mut state = self.enter("get_app_node_certificate_details")?;
+        let enclave_info = state.deployment(node_id, app_id)?.1.enclave_info.clone();
+        let name = state.app(app_id)?.name.clone();
+        Ok(state.certificate_details(name, enclave_info))
+    }
+
+    fn get_apps_unique_labels(&self) -> Result<models::LabelsCount, ApiError> {
+        let state = self.enter("get_apps_unique_labels")?;
+        Ok(count_labels(
+            state.apps.values().filter_map(|app| app.labels.as_ref()),
+        ))
+    }
+
+    fn update_app(
+        &self,
+        app_id: uuid::Uuid,
+        body: models::AppBodyUpdateRequest,
+    ) -> Result<models::App, ApiError> {
+        let mut state = self.enter("update_app")?;
+        let mut app = state.app(app_id)?.clone();
+        if let Some(labels) = body.labels {
+            app = patch(&app, labels)?;
+        }
+        app.description = body.description.or(app.description);
+        app.input_image_name = body.input_image_name.unwrap_or(app.input_image_name);
+        app.output_image_name = body.output_image_name.unwrap_or(app.output_image_name);
+        app.isvsvn = body.isvsvn.unwrap_or(app.isvsvn);
+        app.isvprodid = body.isvprodid.unwrap_or(app.isvprodid);
+        app.mem_size = body.mem_size.unwrap_or(app.mem_size);
+        app.threads = body.threads.unwrap_or(app.threads);
+        app.advanced_settings = body.advanced_settings.or(app.advanced_settings);
+        app.updated_at = Some(state.now());
+
+        // Newly allowed domains need to be whitelisted by a task.
+        if let Some(allowed_domains) = body.allowed_domains {
+            let whitelisted = app.whitelisted_domains.clone().unwrap_or_default();
+            let added: Vec<_> = allowed_domains
+                .iter()
+                .filter(|domain| !whitelisted.contains(domain))
+                .cloned()
+                .collect();
+            let removed: Vec<_> = whitelisted
+                .iter()
+                .filter(|domain| !allowed_domains.contains(domain))
+                .cloned()
+                .collect();
+            app.allowed_domains = Some(allowed_domains.clone());
+            if added.is_empty() {
+                app.whitelisted_domains = Some(allowed_domains);
+            } else {
+                let task_id = state.create_task(
+                    models::TaskType::DOMAIN_WHITELIST,
+                    app_id,
+                    format!("Whitelist domains of {}", app.name),
+                );
+                if let Some(task) = state.tasks.get_mut(&task_id) {
+                    task.domains_added = Some(added.clone());
+                    task.domains_removed = Some(removed.clone());
+                }
+                app.pending_task_id = Some(task_id);
+                app.domains_added = Some(added);
+                app.domains_removed = Some(removed);
+            }
+        }
+        state.apps.insert(app_id, app.clone());
+        Ok(state.app_view(&app))
+    }
+}
+
+impl ApplicationConfigApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_application_config(
+        &self,
+        body: models::ApplicationConfig,
+    ) -> Result<models::ApplicationConfigResponse, ApiError> {
+        let mut state = self.enter("create_application_config")?;
+        if let Some(ref zone) = body.zone {
+            state.zone(zone.id)?;
+        }
+        let config_id = state.new_id().to_string();
+        let now = state.now();
+        let mut config = models::ApplicationConfigResponse::new(
+            config_id.clone(),
+            now,
+            now,
+            body.name,
+            body.description,
+            body.app_config,
+            body.labels,
+            body.ports,
+        );
+        config.zone = body.zone;
+        state.application_configs.insert(config_id, config.clone());
+        Ok(config)
+    }
+
+    fn delete_application_config(&self, config_id: String) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_application_config")?;
+        state
+            .application_configs
+            .remove(&config_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("Application config", config_id))
+    }
+
+    fn get_all_application_configs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        image_id: Option<uuid::Uuid>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllApplicationConfigsResponse, ApiError> {
+        let state = self.enter("get_all_application_configs")?;
+        // Configs used by a workflow are matched to images through the
+        // app objects of the final workflows.
+        let image_configs: Option<Vec<String>> = image_id.map(|image_id| {
+            state
+                .final_workflows
+                .values()
+                .flat_map(|workflow| workflow.versions.values())
+                .flat_map(|version| version.runtime_configs.values())
+                .filter(|app| app.image_id == image_id)
+                .map(|app| app.config_id.clone())
+                .collect()
+        });
+        let items: Vec<_> = state
+            .application_configs
+            .values()
+            .filter(|config| contains(Some(&config.name), &name))
+            .filter(|config| contains(Some(&config.description), &description))
+            .filter(|config| {
+                image_configs
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&config.config_id))
+            })
+            .cloned()
+            .collect();
+        let (metadata, items) = page(items, state.application_configs.len(), limit, offset)?;
+        Ok(models::GetAllApplicationConfigsResponse { metadata, items })
+    }
+
+    fn get_application_config(
+        &self,
+        config_id: String,
+    ) -> Result<models::ApplicationConfigResponse, ApiError> {
+        let state = self.enter("get_application_config")?;
+        state.application_config(&config_id).cloned()
+    }
+
+    fn get_runtime_application_config(
+        &self,
+        expected_hash: &[u8; 32],
+    ) -> Result<models::RuntimeAppConfig, ApiError> {
+        let state = self.enter("get_runtime_application_config")?;
+        for config in state.application_configs.values() {
+            let runtime_config = state.runtime_config(config);
+            if *runtime_config.config.canonical_hash() == *expected_hash {
+                return Ok(runtime_config);
+            }
+        }
+        Err(not_found(
+            "Application config with hash",
+            expected_hash
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        ))
+    }
+
+    fn get_specific_runtime_application_config(
+        &self,
+        config_id: String,
+    ) -> Result<models::RuntimeAppConfig, ApiError> {
+        let state = self.enter("get_specific_runtime_application_config")?;
+        let config = state.application_config(&config_id)?;
+        Ok(state.runtime_config(config))
+    }
+
+    fn update_application_config(
+        &self,
+        config_id: String,
+        body: models::UpdateApplicationConfigRequest,
+    ) -> Result<models::ApplicationConfigResponse, ApiError> {
+        let mut state = self.enter("update_application_config")?;
+        state.application_config(&config_id)?;
+        let now = state.now();
+        let config = state
+            .application_configs
+            .get_mut(&config_id)
+            .expect("config exists");
+        if let Some(name) = body.name {
+            config.name = name;
+        }
+        if let Some(description) = body.description {
+            config.description = description;
+        }
+        if let Some(ports) = body.ports {
+            config.ports = ports;
+        }
+        config.updated_at = now;
+        Ok(config.clone())
+    }
+}
+
+impl ApprovalRequestsApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn approve_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::ApproveRequest>,
+    ) -> Result<models::ApprovalRequest, ApiError> {
+        let mut state = self.enter("approve_approval_request")?;
+        if let Some(password) = body.as_ref().and_then(|body| body.password.as_ref()) {
+            state.check_password(state.user_id, password)?;
+        }
+        let user_id = state.user_id;
+        let request = state
+            .approval_requests
+            .get_mut(&request_id)
+            .ok_or_else(|| not_found("Approval request", request_id))?;
+        if request.status != models::ApprovalRequestStatus::PENDING {
+            return Err(invalid_state(format!(
+                "Approval request {} is {}",
+                request_id, request.status
+            )));
+        }
+        request.status = models::ApprovalRequestStatus::APPROVED;
+        request.approvers.push(models::Entity {
+            user: Some(user_id),
+        });
+        if let Some(body) = body.and_then(|body| body.body) {
+            request.body = Some(body);
+        }
+        Ok(request.clone())
+    }
+
+    fn create_approval_request(
+        &self,
+        body: models::ApprovalRequestRequest,
+    ) -> Result<models::ApprovalRequest, ApiError> {
+        let mut state = self.enter("create_approval_request")?;
+        let acct_id = state.account_id()?;
+        let request_id = state.new_id();
+        let now = state.now();
+        let mut request = models::ApprovalRequest::new(
+            request_id,
+            models::Entity {
+                user: Some(state.user_id),
+            },
+            now,
+            acct_id,
+            body.operation,
+            body.method.unwrap_or_else(|| "POST".to_owned()),
+            Vec::new(),
+            models::ApprovalRequestStatus::PENDING,
+            now + APPROVAL_REQUEST_LIFETIME,
+        );
+        request.body = body.body;
+        request.description = body.description;
+        request.reviewers = Some(vec![models::Entity {
+            user: Some(state.user_id),
+        }]);
+        state.approval_requests.insert(request_id, request.clone());
+        Ok(request)
+    }
+
+    fn delete_approval_request(&self, request_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_approval_request")?;
+        state
+            .approval_requests
+            .remove(&request_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("Approval request", request_id))
+    }
+
+    fn deny_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::DenyRequest>,
+    ) -> Result<models::ApprovalRequest, ApiError> {
+        let mut state = self.enter("deny_approval_request")?;
+        let user_id = state.user_id;
+        let request = state
+            .approval_requests
+            .get_mut(&request_id)
+            .ok_or_else(|| not_found("Approval request", request_id))?;
+        if request.status != models::ApprovalRequestStatus::PENDING {
+            return Err(invalid_state(format!(
+                "Approval request {} is {}",
+                request_id, request.status
+            )));
+        }
+        request.status = models::ApprovalRequestStatus::DENIED;
+        request.denier = Some(models::Entity {
+            user: Some(user_id),
+        });
+        request.denial_reason = body.and_then(|body| body.reason);
+        Ok(request.clone())
+    }
+
+    fn get_all_approval_requests(
+        &self,
+        requester: Option<uuid::Uuid>,
+        reviewer: Option<uuid::Uuid>,
+        subject: Option<uuid::Uuid>,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllApprovalRequests, ApiError> {
+        let state = self.enter("get_all_approval_requests")?;
+        let has_entity = |entities: Option<&Vec<models::Entity>>, user: uuid::Uuid| {
+            entities.is_some_and(|entities| entities.iter().any(|entity| entity.user == Some(user)))
+        };
+        let mut items: Vec<_> = state
+            .approval_requests
+            .values()
+            .filter(|r| requester.is_none_or(|user| r.requester.user == Some(user)))
+            .filter(|r| reviewer.is_none_or(|user| has_entity(r.reviewers.as_ref(), user)))
+            .filter(|r| {
+                subject.is_none_or(|subject| {
+                    r.subjects.as_ref().is_some_and(|subjects| {
+                        subjects.iter().any(|s| s.workflow == Some(subject))
+                    })
+                })
+            })
+            .filter(|r| equals(Some(r.status), &status))
+            .filter(|r| any_contains(&[Some(&r.operation), r.description.as_deref()], &all_search))
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |r, field| match field {
+            "created_at" => Some(r.created_at.into()),
+            "expiry" => Some(r.expiry.into()),
+            "status" => Some(r.status.to_string().as_str().into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.approval_requests.len(), limit, offset)?;
+        Ok(models::GetAllApprovalRequests { metadata, items })
+    }
+
+    fn get_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> Result<models::ApprovalRequest, ApiError> {
+        let state = self.enter("get_approval_request")?;
+        state.approval_request(request_id).cloned()
+    }
+
+    fn get_approval_request_result(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> Result<models::ApprovableResult, ApiError> {
+        let state = self.enter("get_approval_request_result")?;
+        let request = state.approval_request(request_id)?;
+        match request.status {
+            models::ApprovalRequestStatus::APPROVED => Ok(models::ApprovableResult::new(
+                200,
+                request.body.clone().unwrap_or(Value::Null),
+            )),
+            status => Err(invalid_state(format!(
+                "Approval request {} is {}",
+                request_id, status
+            ))),
+        }
+    }
+}
+
+impl AuthApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn auth_discover(
+        &self,
+        _body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, ApiError> {
+        let _state = self.enter("auth_discover")?;
+        // The fake has no identity providers, every user logs in with a password.
+        let mut method = models::AuthMethod::new();
+        method.password = Some(Value::Object(Default::default()));
+        Ok(models::AuthDiscoverResponse::new(vec![method]))
+    }
+
+    fn authenticate_user(
+        &self,
+        body: Option<models::AuthRequest>,
+    ) -> Result<models::AuthResponse, ApiError> {
+        let mut state = self.enter("authenticate_user")?;
+        if let Some(body) = body {
+            let email = body.oauth_auth_code.email;
+            let user_id = state
+                .users
+                .values()
+                .find(|user| user.user_email == email)
+                .map(|user| user.user_id)
+                .ok_or_else(auth_failed)?;
+            state.user_id = user_id;
+        }
+        let now = state.now();
+        let user_id = state.user_id;
+        if let Some(user) = state.users.get_mut(&user_id) {
+            user.last_logged_in_at = Some(now);
+        }
+        let message = format!("User {} logged in", user_id);
+        state.record_event(models::EventActionType::USER_AUTH, user_id, message);
+        let mut response = models::AuthResponse::new();
+        response.access_token = Some(format!("fake-session-{}", now));
+        response.session_info = Some(state.session_info());
+        Ok(response)
+    }
+
+    fn refresh_session(&self) -> Result<models::RefreshResponse, ApiError> {
+        let mut state = self.enter("refresh_session")?;
+        Ok(models::RefreshResponse::new(state.session_info()))
+    }
+}
+
+impl FakeEnclaveManager {
+    fn check_zone_name(state: &State, acct_id: uuid::Uuid, name: &str) -> Result<(), ApiError> {
+        match state
+            .zones
+            .values()
+            .any(|zone| zone.acct_id == acct_id && zone.name == name)
+        {
+            true => Err(already_exists("Zone", name)),
+            false => Ok(()),
+        }
+    }
+
+    fn insert_build(
+        state: &mut State,
+        mut build: models::Build,
+    ) -> Result<models::Build, ApiError> {
+        let build_id = state.new_id();
+        let now = state.now();
+        build.build_id = Some(build_id);
+        build.created_at = Some(now);
+        build.updated_at = Some(now);
+        build.status = models::BuildStatus::new(models::BuildStatusType::PENDING, now);
+        build.deployment_status = Some(models::BuildDeploymentStatus::new(
+            models::BuildDeploymentStatusType::UNDEPLOYED,
+            now,
+        ));
+        if let Some(app_id) = build.app_id {
+            let app = state.app(app_id)?;
+            build.app_name = Some(app.name.clone());
+            build.app_description = app.description.clone();
+            build.mem_size = build.mem_size.or(Some(app.mem_size));
+            build.threads = build.threads.or(Some(app.threads));
+            build.advanced_settings = build
+                .advanced_settings
+                .or_else(|| app.advanced_settings.clone());
+        }
+        let description = format!(
+            "Whitelist build of {}",
+            build.app_name.as_deref().unwrap_or("unknown app")
+        );
+        let task_id = state.create_task(models::TaskType::BUILD_WHITELIST, build_id, description);
+        build.pending_task_id = Some(task_id);
+        state.builds.insert(build_id, build.clone());
+        Ok(build)
+    }
+}
+
+impl BuildApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn convert_app_build(
+        &self,
+        body: models::ConvertAppBuildRequest,
+    ) -> Result<models::Build, ApiError> {
+        let mut state = self.enter("convert_app_build")?;
+        let app = state.app(body.app_id)?.clone();
+        let digest = fake_digest(state.new_id());
+        let mut build = models::Build::new(models::BuildStatus::new(
+            models::BuildStatusType::PENDING,
+            0,
+        ));
+        build.docker_info = Some(models::DockerInfo {
+            docker_image_name: app.output_image_name.clone(),
+            docker_version: body
+                .output_docker_version
+                .or(body.docker_version)
+                .unwrap_or_else(|| "latest".to_owned()),
+            docker_image_sha: Some(digest.clone()),
+            docker_image_size: None,
+        });
+        build.enclave_info = Some(models::EnclaveInfo::new(
+            digest.clone(),
+            digest,
+            app.isvprodid,
+            app.isvsvn,
+        ));
+        build.app_id = Some(app.app_id);
+        build.mem_size = body.mem_size;
+        build.threads = body.threads;
+        FakeEnclaveManager::insert_build(&mut state, build)
+    }
+
+    fn create_build(&self, body: models::CreateBuildRequest) -> Result<models::Build, ApiError> {
+        let mut state = self.enter("create_build")?;
+        let mut build = models::Build::new(models::BuildStatus::new(
+            models::BuildStatusType::PENDING,
+            0,
+        ));
+        build.docker_info = body.docker_info;
+        build.enclave_info = Some(models::EnclaveInfo::new(
+            body.mrenclave,
+            body.mrsigner,
+            body.isvprodid,
+            body.isvsvn,
+        ));
+        build.app_id = body.app_id;
+        build.app_name = body.app_name;
+        build.mem_size = body.mem_size;
+        build.threads = body.threads;
+        build.advanced_settings = body.advanced_settings;
+        FakeEnclaveManager::insert_build(&mut state, build)
+    }
+
+    fn delete_build(&self, build_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_build")?;
+        state
+            .builds
+            .remove(&build_id)
+            .ok_or_else(|| not_found("Build", build_id))?;
+        state.deployments.retain(|d| d.build_id != build_id);
+        Ok(())
+    }
+
+    fn get_all_builds(
+        &self,
+        all_search: Option<String>,
+        docker_image_name: Option<String>,
+        config_id: Option<String>,
+        deployed_status: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllBuildsResponse, ApiError> {
+        let state = self.enter("get_all_builds")?;
+        let mut items: Vec<_> = state
+            .builds
+            .values()
+            .filter(|b| {
+                any_contains(
+                    &[
+                        b.app_name.as_deref(),
+                        b.build_name.as_deref(),
+                        b.docker_info.as_ref().map(|d| d.docker_image_name.as_str()),
+                    ],
+                    &all_search,
+                )
+            })
+            .filter(|b| {
+                equals(
+                    b.docker_info.as_ref().map(|d| &d.docker_image_name),
+                    &docker_image_name,
+                )
+            })
+            .filter(|b| {
+                config_id.as_ref().is_none_or(|id| {
+                    b.configs
+                        .as_ref()
+                        .is_some_and(|configs| configs.contains_key(id))
+                })
+            })
+            .filter(|b| {
+                equals(
+                    b.deployment_status.as_ref().map(|d| d.status),
+                    &deployed_status,
+                )
+            })
+            .filter(|b| equals(Some(b.status.status), &status))
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |b, field| match field {
+            "app_name" => Some(b.app_name.as_deref().unwrap_or("").into()),
+            "created_at" => Some(b.created_at.unwrap_or(0).into()),
+            "updated_at" => Some(b.updated_at.unwrap_or(0).into()),
+            "status" => Some(b.status.status.to_string().as_str().into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.builds.len(), limit, offset)?;
+        Ok(models::GetAllBuildsResponse { metadata, items })
+    }
+
+    fn get_build(&self, build_id: uuid::Uuid) -> Result<models::Build, ApiError> {
+        let state = self.enter("get_build")?;
+        state.build(build_id).cloned()
+    }
+
+    fn get_build_deployments(
+        &self,
+        build_id: uuid::Uuid,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllBuildDeploymentsResponse, ApiError> {
+        let state = self.enter("get_build_deployments")?;
+        state.build(build_id)?;
+        let deployments: Vec<_> = state
+            .deployments
+            .iter()
+            .filter(|d| d.build_id == build_id)
+            .map(|d| state.deployment_view(d))
+            .collect();
+        let total_count = deployments.len();
+        let mut items: Vec<_> = deployments
+            .into_iter()
+            .filter(|d| equals(d.status.as_ref().and_then(|s| s.status), &status))
+            .filter(|d| any_contains(&[d.node_name.as_deref()], &all_search))
+            .collect();
+        sort(&mut items, &sort_by, |d, field| match field {
+            "node_name" => Some(d.node_name.as_deref().unwrap_or("").into()),
+            "created_at" => Some(d.created_at.into()),
+            "status" => Some(
+                d.status
+                    .as_ref()
+                    .and_then(|s| s.status)
+                    .map(|s| s.to_string())
+                    .unwrap_or_default()
+                    .as_str()
+                    .into(),
+            ),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, total_count, limit, offset)?;
+        Ok(models::GetAllBuildDeploymentsResponse { metadata, items })
+    }
+
+    fn update_build(
+        &self,
+        build_id: uuid::Uuid,
+        body: models::BuildUpdateRequest,
+    ) -> Result<models::Build, ApiError> {
+        let mut state = self.enter("update_build")?;
+        state.build(build_id)?;
+        let now = state.now();
+        let build = state.builds.get_mut(&build_id).expect("build exists");
+        if body.configs.is_some() {
+            build.configs = body.configs;
+        }
+        build.updated_at = Some(now);
+        Ok(build.clone())
+    }
+}
+
+impl CertificateApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn get_certificate(&self, cert_id: uuid::Uuid) -> Result<models::Certificate, ApiError> {
+        let state = self.enter("get_certificate")?;
+        state
+            .certificates
+            .get(&cert_id)
+            .cloned()
+            .ok_or_else(|| not_found("Certificate", cert_id))
+    }
+
+    fn new_certificate(
+        &self,
+        body: models::NewCertificateRequest,
+    ) -> Result<models::TaskResult, ApiError> {
+        let mut state = self.enter("new_certificate")?;
+        if let Some(node_id) = body.node_id {
+            state.node(node_id)?;
+        }
+        let certificate_id = state.new_id();
+        state.certificates.insert(
+            certificate_id,
+            models::Certificate {
+                certificate_id: Some(certificate_id),
+                status: Some(models::CertificateStatusType::PENDING),
+                csr: body.csr,
+                certificate: None,
+            },
+        );
+        let task_id = state.create_task(
+            models::TaskType::CERTIFICATE_ISSUANCE,
+            certificate_id,
+            "Issue certificate".to_owned(),
+        );
+        let mut result = state.task_result(task_id)?;
+        result.node_id = body.node_id;
+        Ok(result)
+    }
+}
+
+impl DatasetApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_dataset(
+        &self,
+        body: models::CreateDatasetRequest,
+    ) -> Result<models::Dataset, ApiError> {
+        let mut state = self.enter("create_dataset")?;
+        if state.datasets.values().any(|d| d.name == body.name) {
+            return Err(already_exists("Dataset", &body.name));
+        }
+        let dataset_id = state.new_id();
+        let now = state.now();
+        let mut credentials = models::DatasetCredentials::new();
+        credentials.sdkms = Some(models::SdkmsCredentials::new(
+            "https://sdkms.example.com".to_owned(),
+            format!("dataset-{}-credentials", dataset_id),
+        ));
+        let dataset = models::Dataset::new(
+            dataset_id,
+            body.name,
+            state.user_id,
+            now,
+            now,
+            body.description,
+            body.location,
+            body.labels,
+            credentials,
+        );
+        state.datasets.insert(dataset_id, dataset.clone());
+        Ok(dataset)
+    }
+
+    fn delete_dataset(&self, dataset_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_dataset")?;
+        state
+            .datasets
+            .remove(&dataset_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("Dataset", dataset_id))
+    }
+
+    fn get_all_datasets(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllDatasetsResponse, ApiError> {
+        let state = self.enter("get_all_datasets")?;
+        let items: Vec<_> = state
+            .datasets
+            .values()
+            .filter(|d| contains(Some(&d.name), &name))
+            .filter(|d| contains(Some(&d.description), &description))
+            .cloned()
+            .collect();
+        let (metadata, items) = page(items, state.datasets.len(), limit, offset)?;
+        Ok(models::GetAllDatasetsResponse { metadata, items })
+    }
+
+    fn get_dataset(&self, dataset_id: uuid::Uuid) -> Result<models::Dataset, ApiError> {
+        let state = self.enter("get_dataset")?;
+        state.dataset(dataset_id).cloned()
+    }
+
+    fn update_dataset(
+        &self,
+        dataset_id: uuid::Uuid,
+        body: models::DatasetUpdateRequest,
+    ) -> Result<models::Dataset, ApiError> {
+        let mut state = self.enter("update_dataset")?;
+        state.dataset(dataset_id)?;
+        let now = state.now();
+        let dataset = state.datasets.get_mut(&dataset_id).expect("dataset exists");
+        if let Some(name) = body.name {
+            dataset.name = name;
+        }
+        if let Some(description) = body.description {
+            dataset.description = description;
+        }
+        if let Some(labels) = body.labels {
+            dataset.labels = labels;
+        }
+        if let Some(location) = body.location {
+            dataset.location = location;
+        }
+        dataset.updated_at = now;
+        Ok(dataset.clone())
+    }
+}
+
+impl EventsApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, ApiError> {
+        let state = self.enter("get_all_events")?;
+        let mut items: Vec<_> = state
+            .events
+            .iter()
+            .filter(|e| equals(Some(e.actor_type), &actor_type))
+            .filter(|e| equals(Some(e.action_type), &action_type))
+            .filter(|e| equals(Some(e.severity), &severity))
+            .filter(|e| entity_id.is_none() || e.entity_id == entity_id)
+            .filter(|e| from.is_none_or(|from| e.created_at >= from))
+            .filter(|e| to.is_none_or(|to| e.created_at <= to))
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |e, field| match field {
+            "created_at" => Some(e.created_at.into()),
+            "severity" => Some(e.severity.to_string().as_str().into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.events.len(), limit, offset)?;
+        Ok(models::GetAllEventsResponse { metadata, items })
+    }
+}
+
+impl NodeApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("deactivate_node")?;
+        state.node(node_id)?;
+        let now = state.now();
+        let node = state.nodes.get_mut(&node_id).expect("node exists");
+        node.status.status = models::NodeStatusType::DEACTIVATED;
+        node.status.status_updated_at = now;
+        Ok(())
+    }
+
+    fn get_all_nodes(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        sgx_version: Option<String>,
+        all_search: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllNodesResponse, ApiError> {
+        let state = self.enter("get_all_nodes")?;
+        let mut items: Vec<_> = state
+            .nodes
+            .values()
+            .filter(|n| contains(Some(&n.name), &name))
+            .filter(|n| contains(n.description.as_ref(), &description))
+            .filter(|n| equals(n.sgx_info.version.as_ref(), &sgx_version))
+            .filter(|n| {
+                any_contains(
+                    &[
+                        Some(&n.name),
+                        n.description.as_deref(),
+                        n.ipaddress.as_deref(),
+                        n.host_id.as_deref(),
+                    ],
+                    &all_search,
+                )
+            })
+            .filter(|n| equals(Some(n.status.status), &status))
+            .map(|n| state.node_view(n))
+            .collect();
+        sort(&mut items, &sort_by, |n, field| match field {
+            "name" => Some(n.name.as_str().into()),
+            "status" => Some(n.status.status.to_string().as_str().into()),
+            "attested_at" => Some(n.attested_at.unwrap_or(0).into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.nodes.len(), limit, offset)?;
+        Ok(models::GetAllNodesResponse { metadata, items })
+    }
+
+    fn get_node(&self, node_id: uuid::Uuid) -> Result<models::Node, ApiError> {
+        let state = self.enter("get_node")?;
+        state.node(node_id).map(|node| state.node_view(node))
+    }
+
+    fn get_node_certificate(&self, node_id: uuid::Uuid) -> Result<models::Certificate, ApiError> {
+        let state = self.enter("get_node_certificate")?;
+        let node = state.node(node_id)?;
+        match node.certificate {
+            Some(ref certificate) => {
+                let mut result = models::Certificate::new();
+                result.status = Some(models::CertificateStatusType::ISSUED);
+                result.certificate = Some(certificate.clone());
+                Ok(result)
+            }
+            None => Err(not_found("Certificate of node", node_id)),
+        }
+    }
+
+    fn get_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+    ) -> Result<models::CertificateDetails, ApiError> {
+        let mut state = self.enter("get_node_certificate_details")?;
+        let node = state.node(node_id)?;
+        if node.certificate.is_none() {
+            return Err(not_found("Certificate of node", node_id));
+        }
+        let name = node.name.clone();
+        Ok(state.certificate_details(name, None))
+    }
+
+    fn get_nodes_unique_labels(&self) -> Result<models::LabelsCount, ApiError> {
+        let state = self.enter("get_nodes_unique_labels")?;
+        Ok(count_labels(
+            state.nodes.values().filter_map(|node| node.labels.as_ref()),
+        ))
+    }
+
+    fn provision_node(
+        &self,
+        body: models::NodeProvisionRequest,
+    ) -> Result<models::TaskResult, ApiError> {
+        let mut state = self.enter("provision_node")?;
+        let acct_id = state.account_id()?;
+        if state.nodes.values().any(|node| node.name == body.name) {
+            return Err(already_exists("Node", &body.name));
+        }
+        let zone_id = state.default_zone().map(|zone| zone.zone_id);
+        let node_id = state.new_id();
+        let now = state.now();
+        let mut sgx_info = models::SgxInfo::new();
+        sgx_info.version = Some(body.sgx_version);
+        let mut node = models::Node::new(
+            body.name,
+            acct_id,
+            node_id,
+            models::NodeStatus::new(models::NodeStatusType::INPROGRESS, now, now),
+            Vec::new(),
+            sgx_info,
+        );
+        node.description = body.description;
+        node.ipaddress = Some(body.ipaddress);
+        node.host_id = body.host_id;
+        node.zone_id = zone_id;
+        node.attestation_type = body
+            .attestation_request
+            .and_then(|request| request.attestation_type);
+        node.error_report = body.error_report;
+        let description = format!("Attest node {}", node.name);
+        state.nodes.insert(node_id, node);
+        let task_id = state.create_task(models::TaskType::NODE_ATTESTATION, node_id, description);
+        state.task_result(task_id)
+    }
+
+    fn update_node(
+        &self,
+        node_id: uuid::Uuid,
+        body: models::NodeUpdateRequest,
+    ) -> Result<models::Node, ApiError> {
+        let mut state = self.enter("update_node")?;
+        let node = patch(state.node(node_id)?, body.patch)?;
+        state.nodes.insert(node_id, node.clone());
+        Ok(state.node_view(&node))
+    }
+
+    fn update_node_status(
+        &self,
+        body: models::NodeStatusRequest,
+    ) -> Result<models::NodeStatusResponse, ApiError> {
+        let mut state = self.enter("update_node_status")?;
+        let now = state.now();
+        let node = state
+            .nodes
+            .values_mut()
+            .find(|node| node.name == body.name)
+            .ok_or_else(|| not_found("Node", &body.name))?;
+        if let Some(status) = body.status {
+            node.status.status = status.status;
+            node.status.status_updated_at = now;
+        }
+        node.status.last_seen_at = Some(now);
+        node.ipaddress = Some(body.ipaddress);
+        node.sgx_info.version = Some(body.sgx_version);
+        let zone_id = node.zone_id;
+        let zone = zone_id.and_then(|zone_id| state.zones.get(&zone_id));
+        Ok(models::NodeStatusResponse::new(
+            zone.map_or(NODE_REFRESH_INTERVAL, |zone| zone.node_refresh_interval),
+            zone.map_or(NODE_RENEWAL_THRESHOLD, |zone| zone.node_renewal_threshold),
+        ))
+    }
+}
+
+/// The registry serving `image_name`, i.e. the one whose URL is a prefix of
+/// the image name.
+fn registry_for_image<'a>(state: &'a State, image_name: &str) -> Option<&'a models::Registry> {
+    state
+        .registries
+        .values()
+        .filter(|registry| image_name.starts_with(registry.url.trim_end_matches('/')))
+        .max_by_key(|registry| registry.url.len())
+}
+
+impl RegistryApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_registry(
+        &self,
+        registry_request: models::RegistryRequest,
+    ) -> Result<models::Registry, ApiError> {
+        let mut state = self.enter("create_registry")?;
+        if state
+            .registries
+            .values()
+            .any(|registry| registry.url == registry_request.url)
+        {
+            return Err(already_exists("Registry", &registry_request.url));
+        }
+        let registry_id = state.new_id();
+        let mut registry = models::Registry::new(registry_request.url, registry_id);
+        registry.description = registry_request.description;
+        registry.username = registry_request
+            .credential
+            .default
+            .and_then(|auth| auth.username);
+        state.registries.insert(registry_id, registry.clone());
+        Ok(registry)
+    }
+
+    fn delete_registry(&self, registry_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_registry")?;
+        state
+            .registries
+            .remove(&registry_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("Registry", registry_id))
+    }
+
+    fn get_all_registries(&self) -> Result<Vec<models::Registry>, ApiError> {
+        let state = self.enter("get_all_registries")?;
+        Ok(state.registries.values().cloned().collect())
+    }
+
+    fn get_registry(&self, registry_id: uuid::Uuid) -> Result<models::Registry, ApiError> {
+        let state = self.enter("get_registry")?;
+        state.registry(registry_id).cloned()
+    }
+
+    fn get_registry_for_app(
+        &self,
+        app_id: uuid::Uuid,
+    ) -> Result<models::AppRegistryResponse, ApiError> {
+        let state = self.enter("get_registry_for_app")?;
+        let app = state.app(app_id)?;
+        let mut response = models::AppRegistryResponse::new();
+        response.input_image_registry = registry_for_image(&state, &app.input_image_name).cloned();
+        response.output_image_registry =
+            registry_for_image(&state, &app.output_image_name).cloned();
+        Ok(response)
+    }
+
+    fn get_registry_for_image(
+        &self,
+        image_name: String,
+    ) -> Result<models::ImageRegistryResponse, ApiError> {
+        let state = self.enter("get_registry_for_image")?;
+        let mut response = models::ImageRegistryResponse::new();
+        response.registry = registry_for_image(&state, &image_name).cloned();
+        Ok(response)
+    }
+
+    fn update_registry(
+        &self,
+        registry_id: uuid::Uuid,
+        body: models::UpdateRegistryRequest,
+    ) -> Result<models::Registry, ApiError> {
+        let mut state = self.enter("update_registry")?;
+        let registry = patch(state.registry(registry_id)?, body.into())?;
+        state.registries.insert(registry_id, registry.clone());
+        Ok(registry)
+    }
+}
+
+impl SystemApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn get_manager_version(&self) -> Result<models::VersionResponse, ApiError> {
+        let _state = self.enter("get_manager_version")?;
+        let mut response = models::VersionResponse::new();
+        response.version = Some(FAKE_MANAGER_VERSION.to_owned());
+        Ok(response)
+    }
+}
+
+impl TaskApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    /// `base_filters` is ignored.
+    fn get_all_tasks(
+        &self,
+        task_type: Option<String>,
+        status: Option<String>,
+        requester: Option<String>,
+        approver: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+        base_filters: Option<String>,
+    ) -> Result<models::GetAllTasksResponse, ApiError> {
+        let state = self.enter("get_all_tasks")?;
+        let mut items: Vec<_> = state
+            .tasks
+            .values()
+            .filter(|t| equals(Some(t.task_type), &task_type))
+            .filter(|t| equals(Some(t.status.status), &status))
+            .filter(|t| equals(t.requester_info.user_id, &requester))
+            .filter(|t| {
+                approver.is_none()
+                    || t.approvals
+                        .iter()
+                        .any(|a| equals(Some(a.user_id), &approver))
+            })
+            .filter(|t| any_contains(&[t.description.as_deref()], &all_search))
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |t, field| match field {
+            "task_type" => Some(t.task_type.to_string().as_str().into()),
+            "status" => Some(t.status.status.to_string().as_str().into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.tasks.len(), limit, offset)?;
+        Ok(models::GetAllTasksResponse { metadata, items })
+    }
+
+    fn get_task(&self, task_id: uuid::Uuid) -> Result<models::Task, ApiError> {
+        let state = self.enter("get_task")?;
+        state.task(task_id).cloned()
+    }
+
+    fn get_task_status(&self, task_id: uuid::Uuid) -> Result<models::TaskResult, ApiError> {
+        let state = self.enter("get_task_status")?;
+        state.task_result(task_id)
+    }
+
+    fn update_task(
+        &self,
+        task_id: uuid::Uuid,
+        body: models::TaskUpdateRequest,
+    ) -> Result<models::TaskResult, ApiError> {
+        let mut state = self.enter("update_task")?;
+        let task_status = state.task(task_id)?.status.status;
+        if task_status != models::TaskStatusType::INPROGRESS {
+            return Err(invalid_state(format!(
+                "Task {} is {}",
+                task_id, task_status
+            )));
+        }
+        let mut approval = models::ApprovalInfo::new(state.user_id);
+        approval.user_name = state.user().ok().map(|user| user.user_email.clone());
+        approval.status = Some(body.status);
+        state
+            .tasks
+            .get_mut(&task_id)
+            .expect("task exists")
+            .approvals
+            .push(approval);
+        let status = match body.status {
+            models::ApprovalStatus::APPROVED => models::TaskStatusType::SUCCESS,
+            models::ApprovalStatus::DENIED => models::TaskStatusType::DENIED,
+        };
+        state.set_task_status(task_id, status);
+        let message = format!("Task {} {}", task_id, body.status);
+        state.record_event(models::EventActionType::USER_APPROVAL, task_id, message);
+        state.task_result(task_id)
+    }
+}
+
+impl ToolsApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn convert_app(
+        &self,
+        body: models::ConversionRequest,
+    ) -> Result<models::ConversionResponse, ApiError> {
+        let mut state = self.enter("convert_app")?;
+        let digest = fake_digest(state.new_id());
+        let mut response = models::ConversionResponse::new();
+        response.new_image = Some(body.output_image_name);
+        response.image_sha = Some(digest.clone());
+        response.isvprodid = Some(0);
+        response.isvsvn = Some(0);
+        response.mrenclave = Some(digest.clone());
+        response.mrsigner = Some(digest);
+        Ok(response)
+    }
+}
+
+impl UsersApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn accept_terms_and_conditions(&self) -> Result<(), ApiError> {
+        let mut state = self.enter("accept_terms_and_conditions")?;
+        let user_id = state.user_id;
+        let user = state
+            .users
+            .get_mut(&user_id)
+            .ok_or_else(|| not_found("User", user_id))?;
+        user.accepted_latest_terms_and_conditions = Some(true);
+        Ok(())
+    }
+
+    fn change_password(&self, body: models::PasswordChangeRequest) -> Result<(), ApiError> {
+        let mut state = self.enter("change_password")?;
+        let user_id = state.user_id;
+        state.check_password(user_id, &body.current_password)?;
+        state.passwords.insert(user_id, body.new_password);
+        Ok(())
+    }
+
+    /// The confirmation token is the ID of the user.
+    fn confirm_email(
+        &self,
+        body: models::ConfirmEmailRequest,
+    ) -> Result<models::ConfirmEmailResponse, ApiError> {
+        let mut state = self.enter("confirm_email")?;
+        let user = state
+            .users
+            .values_mut()
+            .find(|user| user.user_id.to_string() == body.confirm_token)
+            .ok_or_else(|| {
+                error(
+                    400,
+                    models::EventType::INVALID_VALUE,
+                    "Invalid token".to_owned(),
+                )
+            })?;
+        user.email_verified = Some(true);
+        user.status = Some(models::UserStatus::ACTIVE);
+        Ok(models::ConfirmEmailResponse::new(user.user_email.clone()))
+    }
+
+    fn create_user(&self, body: models::SignupRequest) -> Result<models::User, ApiError> {
+        let mut state = self.enter("create_user")?;
+        if state
+            .users
+            .values()
+            .any(|user| user.user_email == body.user_email)
+        {
+            return Err(error(
+                409,
+                models::EventType::USER_ALREADY_EXISTS,
+                format!("User {} already exists", body.user_email),
+            ));
+        }
+        let user_id = state.new_id();
+        let mut user = models::User::new(user_id, body.user_email);
+        user.first_name = body.first_name;
+        user.last_name = body.last_name;
+        user.created_at = Some(state.now());
+        user.email_verified = Some(false);
+        user.status = Some(models::UserStatus::PENDING);
+        user.accepted_latest_terms_and_conditions = Some(false);
+        state.users.insert(user_id, user.clone());
+        state.passwords.insert(user_id, body.user_password);
+        Ok(user)
+    }
+
+    fn delete_user_account(&self, user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_user_account")?;
+        state
+            .users
+            .remove(&user_id)
+            .ok_or_else(|| not_found("User", user_id))?;
+        state.passwords.remove(&user_id);
+        Ok(())
+    }
+
+    fn delete_user_from_account(&self, user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_user_from_account")?;
+        let user = state
+            .users
+            .get_mut(&user_id)
+            .ok_or_else(|| not_found("User", user_id))?;
+        user.roles = None;
+        user.user_account_status = None;
+        Ok(())
+    }
+
+    fn forgot_password(&self, _body: models::ForgotPasswordRequest) -> Result<(), ApiError> {
+        // Like the server, don't reveal whether the user exists.
+        self.enter("forgot_password").map(|_| ())
+    }
+
+    fn get_all_users(
+        &self,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllUsersResponse, ApiError> {
+        let state = self.enter("get_all_users")?;
+        let members: Vec<_> = state
+            .users
+            .values()
+            .filter(|user| user.user_account_status.is_some())
+            .collect();
+        let total_count = members.len();
+        let mut items: Vec<_> = members
+            .into_iter()
+            .filter(|u| {
+                any_contains(
+                    &[
+                        Some(&u.user_email),
+                        u.first_name.as_deref(),
+                        u.last_name.as_deref(),
+                    ],
+                    &all_search,
+                )
+            })
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |u, field| match field {
+            "user_email" => Some(u.user_email.as_str().into()),
+            "created_at" => Some(u.created_at.unwrap_or(0).into()),
+            "last_logged_in_at" => Some(u.last_logged_in_at.unwrap_or(0).into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, total_count, limit, offset)?;
+        Ok(models::GetAllUsersResponse { metadata, items })
+    }
+
+    fn get_logged_in_user(&self) -> Result<models::User, ApiError> {
+        let state = self.enter("get_logged_in_user")?;
+        state.user().cloned()
+    }
+
+    fn get_user(&self, user_id: uuid::Uuid) -> Result<models::User, ApiError> {
+        let state = self.enter("get_user")?;
+        state.find_user(user_id).cloned()
+    }
+
+    fn invite_user(&self, body: models::InviteUserRequest) -> Result<models::User, ApiError> {
+        let mut state = self.enter("invite_user")?;
+        let existing = state
+            .users
+            .values()
+            .find(|user| user.user_email == body.user_email)
+            .map(|user| user.user_id);
+        let user_id = match existing {
+            Some(user_id) => user_id,
+            None => {
+                let user_id = state.new_id();
+                let mut user = models::User::new(user_id, body.user_email);
+                user.created_at = Some(state.now());
+                user.email_verified = Some(false);
+                user.status = Some(models::UserStatus::PENDING);
+                state.users.insert(user_id, user);
+                user_id
+            }
+        };
+        let user = state.users.get_mut(&user_id).expect("user exists");
+        if user.user_account_status == Some(models::UserAccountStatus::ACTIVE) {
+            return Err(error(
+                409,
+                models::EventType::USER_ALREADY_EXISTS,
+                format!("User {} is already a member", user.user_email),
+            ));
+        }
+        user.first_name = body.first_name.or(user.first_name.take());
+        user.last_name = body.last_name.or(user.last_name.take());
+        user.roles = Some(body.roles);
+        user.user_account_status = Some(models::UserAccountStatus::PENDING);
+        Ok(user.clone())
+    }
+
+    fn process_invitations(&self, body: models::ProcessInviteRequest) -> Result<(), ApiError> {
+        let mut state = self.enter("process_invitations")?;
+        let accepts = body.accepts.unwrap_or_default();
+        let rejects = body.rejects.unwrap_or_default();
+        for account_id in accepts.iter().chain(rejects.iter()) {
+            if !state.accounts.contains_key(account_id) {
+                return Err(not_found("Account", account_id));
+            }
+        }
+        let user_id = state.user_id;
+        let user = state
+            .users
+            .get_mut(&user_id)
+            .ok_or_else(|| not_found("User", user_id))?;
+        if user.user_account_status == Some(models::UserAccountStatus::PENDING) {
+            match accepts.is_empty() {
+                false => user.user_account_status = Some(models::UserAccountStatus::ACTIVE),
+                true if !rejects.is_empty() => {
+                    user.user_account_status = None;
+                    user.roles = None;
+                }
+                true => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn resend_confirm_email(&self) -> Result<(), ApiError> {
+        let state = self.enter("resend_confirm_email")?;
+        state.user().map(|_| ())
+    }
+
+    fn resend_invitation(&self, user_id: uuid::Uuid) -> Result<(), ApiError> {
+        let state = self.enter("resend_invitation")?;
+        match state.find_user(user_id)?.user_account_status {
+            Some(models::UserAccountStatus::PENDING) => Ok(()),
+            _ => Err(invalid_state(format!("User {} is not invited", user_id))),
+        }
+    }
+
+    /// The reset token is the ID of the user.
+    fn reset_password(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::PasswordResetRequest,
+    ) -> Result<(), ApiError> {
+        let mut state = self.enter("reset_password")?;
+        state.find_user(user_id)?;
+        check_token(user_id, &body.reset_token)?;
+        state.passwords.insert(user_id, body.new_password);
+        Ok(())
+    }
+
+    fn update_user(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::UpdateUserRequest,
+    ) -> Result<models::User, ApiError> {
+        let mut state = self.enter("update_user")?;
+        let user = state
+            .users
+            .get_mut(&user_id)
+            .ok_or_else(|| not_found("User", user_id))?;
+        if body.first_name.is_some() {
+            user.first_name = body.first_name;
+        }
+        if body.last_name.is_some() {
+            user.last_name = body.last_name;
+        }
+        if body.roles.is_some() {
+            user.roles = body.roles;
+        }
+        Ok(user.clone())
+    }
+
+    fn validate_password_reset_token(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::ValidateTokenRequest,
+    ) -> Result<models::ValidateTokenResponse, ApiError> {
+        let state = self.enter("validate_password_reset_token")?;
+        let user = state.find_user(user_id)?;
+        check_token(user_id, &body.reset_token)?;
+        Ok(models::ValidateTokenResponse::new(user.user_email.clone()))
+    }
+}
+
+impl WorkflowApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_workflow_graph(
+        &self,
+        body: models::CreateWorkflowGraph,
+    ) -> Result<models::WorkflowGraph, ApiError> {
+        let mut state = self.enter("create_workflow_graph")?;
+        let graph_id = state.new_id();
+        let now = state.now();
+        let mut graph = models::WorkflowGraph::new(
+            graph_id,
+            body.name,
+            state.user_id,
+            now,
+            now,
+            body.description,
+            1,
+            body.objects,
+            body.edges,
+        );
+        graph.metadata = body.metadata;
+        state.workflows.insert(graph_id, graph.clone());
+        Ok(graph)
+    }
+
+    fn delete_workflow_graph(&self, graph_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_workflow_graph")?;
+        state
+            .workflows
+            .remove(&graph_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("Workflow graph", graph_id))
+    }
+
+    fn get_all_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        parent_graph_id: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllWorkflowGraphsResponse, ApiError> {
+        let state = self.enter("get_all_workflow_graphs")?;
+        let mut items: Vec<_> = state
+            .workflows
+            .values()
+            .filter(|g| contains(Some(&g.name), &name))
+            .filter(|g| contains(Some(&g.description), &description))
+            .filter(|g| any_contains(&[Some(&g.name), Some(&g.description)], &all_search))
+            .filter(|g| {
+                equals(
+                    g.metadata
+                        .as_ref()
+                        .and_then(|m| m.parent.as_ref())
+                        .map(|p| p.graph_id),
+                    &parent_graph_id,
+                )
+            })
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |g, field| match field {
+            "name" => Some(g.name.as_str().into()),
+            "created_at" => Some(g.created_at.into()),
+            "updated_at" => Some(g.updated_at.into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.workflows.len(), limit, offset)?;
+        Ok(models::GetAllWorkflowGraphsResponse { metadata, items })
+    }
+
+    fn get_workflow_graph(&self, graph_id: uuid::Uuid) -> Result<models::WorkflowGraph, ApiError> {
+        let state = self.enter("get_workflow_graph")?;
+        state.workflow(graph_id).cloned()
+    }
+
+    /// Fails with a conflict unless `body.version` is the current version.
+    fn update_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::UpdateWorkflowGraph,
+    ) -> Result<models::WorkflowGraph, ApiError> {
+        let mut state = self.enter("update_workflow_graph")?;
+        let version = state.workflow(graph_id)?.version;
+        if body.version != version {
+            return Err(error(
+                409,
+                models::EventType::CONFLICT,
+                format!(
+                    "Workflow graph {} is at version {}, not {}",
+                    graph_id, version, body.version
+                ),
+            ));
+        }
+        let now = state.now();
+        let graph = state.workflows.get_mut(&graph_id).expect("graph exists");
+        graph.name = body.name;
+        graph.description = body.description;
+        graph.objects = body.objects;
+        graph.edges = body.edges;
+        graph.metadata = body.metadata;
+        graph.version += 1;
+        graph.updated_at = now;
+        Ok(graph.clone())
+    }
+}
+
+impl FakeEnclaveManager {
+    fn final_version(
+        state: &mut State,
+        contents: models::CreateWorkflowVersionRequest,
+    ) -> models::FinalWorkflowGraph {
+        let runtime_configs = contents
+            .objects
+            .iter()
+            .filter_map(|(id, object)| object._ref.app.clone().map(|app| (id.clone(), app)))
+            .collect();
+        let mut version = models::FinalWorkflowGraph::new(
+            state.now(),
+            contents.objects,
+            contents.edges,
+            runtime_configs,
+        );
+        version.metadata = contents.metadata;
+        version
+    }
+}
+
+impl WorkflowFinalApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_final_workflow_graph(
+        &self,
+        body: models::CreateFinalWorkflowGraph,
+    ) -> Result<models::FinalWorkflow, ApiError> {
+        let mut state = self.enter("create_final_workflow_graph")?;
+        let graph_id = state.new_id();
+        let version = FakeEnclaveManager::final_version(&mut state, body.contents);
+        let mut versions = HashMap::new();
+        versions.insert("1".to_owned(), version.clone());
+        let workflow = models::FinalWorkflow::new(
+            graph_id,
+            body.name,
+            version.created_at,
+            version.created_at,
+            body.description,
+            versions,
+        );
+        state.final_workflows.insert(graph_id, workflow.clone());
+        Ok(workflow)
+    }
+
+    fn delete_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_final_workflow_graph")?;
+        let workflow = state
+            .final_workflows
+            .get_mut(&graph_id)
+            .ok_or_else(|| not_found("Final workflow graph", graph_id))?;
+        workflow
+            .versions
+            .remove(&version)
+            .ok_or_else(|| not_found("Version", &version))?;
+        if workflow.versions.is_empty() {
+            state.final_workflows.remove(&graph_id);
+        }
+        Ok(())
+    }
+
+    fn get_all_final_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<models::GetAllFinalWorkflowGraphsResponse, ApiError> {
+        let state = self.enter("get_all_final_workflow_graphs")?;
+        let mut items: Vec<_> = state
+            .final_workflows
+            .values()
+            .filter(|g| contains(Some(&g.name), &name))
+            .filter(|g| contains(Some(&g.description), &description))
+            .filter(|g| any_contains(&[Some(&g.name), Some(&g.description)], &all_search))
+            .cloned()
+            .collect();
+        sort(&mut items, &sort_by, |g, field| match field {
+            "name" => Some(g.name.as_str().into()),
+            "created_at" => Some(g.created_at.into()),
+            "updated_at" => Some(g.updated_at.into()),
+            _ => None,
+        })?;
+        let (metadata, items) = page(items, state.final_workflows.len(), limit, offset)?;
+        Ok(models::GetAllFinalWorkflowGraphsResponse { metadata, items })
+    }
+
+    fn get_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> Result<models::VersionInFinalWorkflow, ApiError> {
+        let state = self.enter("get_final_workflow_graph")?;
+        let workflow = state.final_workflow(graph_id)?;
+        let contents = workflow
+            .versions
+            .get(&version)
+            .cloned()
+            .ok_or_else(|| not_found("Version", &version))?;
+        Ok(models::VersionInFinalWorkflow::new(
+            graph_id,
+            workflow.name.clone(),
+            workflow.description.clone(),
+            version,
+            contents,
+        ))
+    }
+
+    fn get_full_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+    ) -> Result<models::FinalWorkflow, ApiError> {
+        let state = self.enter("get_full_final_workflow_graph")?;
+        state.final_workflow(graph_id).cloned()
+    }
+
+    /// Adds a version numbered one above the highest existing version.
+    fn update_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::CreateWorkflowVersionRequest,
+    ) -> Result<models::VersionInFinalWorkflow, ApiError> {
+        let mut state = self.enter("update_final_workflow_graph")?;
+        state.final_workflow(graph_id)?;
+        let contents = FakeEnclaveManager::final_version(&mut state, body);
+        let workflow = state
+            .final_workflows
+            .get_mut(&graph_id)
+            .expect("graph exists");
+        let latest = workflow
+            .versions
+            .keys()
+            .filter_map(|version| version.parse::<u64>().ok())
+            .max()
+            .unwrap_or(0);
+        let version = (latest + 1).to_string();
+        workflow.versions.insert(version.clone(), contents.clone());
+        workflow.updated_at = contents.created_at;
+        Ok(models::VersionInFinalWorkflow::new(
+            graph_id,
+            workflow.name.clone(),
+            workflow.description.clone(),
+            version,
+            contents,
+        ))
+    }
+}
+
+impl ZoneApi for FakeEnclaveManager {
+    type Error = ApiError;
+
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, ApiError> {
+        let mut state = self.enter("create_zone")?;
+        let acct_id = state.account_id()?;
+        let name = body
+            .name
+            .ok_or_else(|| bad_request("Zone name is required".to_owned()))?;
+        Self::check_zone_name(&state, acct_id, &name)?;
+        let zone_id = state.new_id();
+        let mut zone = models::Zone::new(
+            acct_id,
+            fake_pem(zone_id),
+            zone_id,
+            name,
+            NODE_REFRESH_INTERVAL,
+            NODE_RENEWAL_THRESHOLD,
+        );
+        zone.description = body.description;
+        state.zones.insert(zone_id, zone.clone());
+        Ok(zone)
+    }
+
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), ApiError> {
+        let mut state = self.enter("delete_zone")?;
+        state.zone(zone_id)?;
+        if state
+            .nodes
+            .values()
+            .any(|node| node.zone_id == Some(zone_id))
+        {
+            return Err(invalid_state(format!("Zone {} has nodes", zone_id)));
+        }
+        state.zones.remove(&zone_id);
+        Ok(())
+    }
+
+    fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, ApiError> {
+        let state = self.enter("get_zone")?;
+        state.zone(zone_id).cloned()
+    }
+
+    fn get_zone_join_token(&self, zone_id: uuid::Uuid) -> Result<models::ZoneJoinToken, ApiError> {
+        let mut state = self.enter("get_zone_join_token")?;
+        state.zone(zone_id)?;
+        let mut token = models::ZoneJoinToken::new();
+        token.token = Some(format!("fake-join-token-{}-{}", zone_id, state.now()));
+        Ok(token)
+    }
+
+    fn get_zones(&self) -> Result<Vec<models::Zone>, ApiError> {
+        let state = self.enter("get_zones")?;
+        let acct_id = state.account_id()?;
+        Ok(state
+            .zones
+            .values()
+            .filter(|zone| zone.acct_id == acct_id)
+            .cloned()
+            .collect())
+    }
+
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, ApiError> {
+        let mut state = self.enter("update_zone")?;
+        let mut zone = state.zone(zone_id)?.clone();
+        if let Some(name) = body.name {
+            if name != zone.name {
+                Self::check_zone_name(&state, zone.acct_id, &name)?;
+            }
+            zone.name = name;
+        }
+        zone.description = body.description.or(zone.description);
+        if let Some(interval) = body.node_refresh_interval {
+            if interval <= 0 {
+                return Err(bad_request(format!(
+                    "Invalid node refresh interval {}",
+                    interval
+                )));
+            }
+            zone.node_refresh_interval = interval;
+        }
+        if let Some(threshold) = body.node_renewal_threshold {
+            if !(1..=100).contains(&threshold) {
+                return Err(bad_request(format!(
+                    "Invalid node renewal threshold {}",
+                    threshold
+                )));
+            }
+            zone.node_renewal_threshold = threshold;
+        }
+        state.zones.insert(zone_id, zone.clone());
+        Ok(zone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FakeEnclaveManager;
+    use models;
+    use pagination::PaginatedApi;
+    use query::{EventQuery, EventSortField, NodeQuery, NodeSortField, QueryApi, SortOrder};
+    use uuid;
+    use {Api, SimpleErrorType};
+
+    fn build_request(name: &str) -> models::CreateBuildRequest {
+        models::CreateBuildRequest {
+            docker_info: None,
+            mrenclave: "11".repeat(32),
+            mrsigner: "22".repeat(32),
+            isvprodid: 1,
+            isvsvn: 1,
+            app_id: None,
+            app_name: Some(name.to_owned()),
+            mem_size: None,
+            threads: None,
+            advanced_settings: None,
+        }
+    }
+
+    fn provision(fake: &FakeEnclaveManager, name: &str) -> models::TaskResult {
+        fake.provision_node(models::NodeProvisionRequest {
+            name: name.to_owned(),
+            description: None,
+            ipaddress: "10.0.0.1".to_owned(),
+            host_id: None,
+            sgx_version: "2".to_owned(),
+            attestation_request: None,
+            error_report: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_whitelist() {
+        let fake = FakeEnclaveManager::new();
+        let build = fake.create_build(build_request("app")).unwrap();
+        assert_eq!(build.status.status, models::BuildStatusType::PENDING);
+        let task_id = build.pending_task_id.unwrap();
+        assert_eq!(
+            fake.get_task(task_id).unwrap().status.status,
+            models::TaskStatusType::INPROGRESS
+        );
+
+        let result = fake
+            .update_task(
+                task_id,
+                models::TaskUpdateRequest::new(models::ApprovalStatus::APPROVED),
+            )
+            .unwrap();
+        assert_eq!(result.build_id, build.build_id);
+        assert_eq!(
+            result.task_status.unwrap().status,
+            models::TaskStatusType::SUCCESS
+        );
+        let build = fake.get_build(build.build_id.unwrap()).unwrap();
+        assert_eq!(build.status.status, models::BuildStatusType::WHITELISTED);
+        assert_eq!(build.pending_task_id, None);
+
+        let err = fake
+            .update_task(
+                task_id,
+                models::TaskUpdateRequest::new(models::ApprovalStatus::DENIED),
+            )
+            .unwrap_err();
+        assert_eq!(err.status(), Some(409));
+    }
+
+    #[test]
+    fn test_node_attestation_and_deployment() {
+        let fake = FakeEnclaveManager::new();
+        let task = provision(&fake, "node-1");
+        let node_id = task.node_id.unwrap();
+        fake.set_task_status(task.task_id.unwrap(), models::TaskStatusType::SUCCESS)
+            .unwrap();
+        let node = fake.get_node(node_id).unwrap();
+        assert_eq!(node.status.status, models::NodeStatusType::RUNNING);
+        assert!(node.certificate.is_some());
+
+        let build = fake.create_build(build_request("app")).unwrap();
+        let build_id = build.build_id.unwrap();
+        assert!(fake.deploy_build(build_id, node_id).is_err());
+        fake.set_task_status(
+            build.pending_task_id.unwrap(),
+            models::TaskStatusType::SUCCESS,
+        )
+        .unwrap();
+        fake.deploy_build(build_id, node_id).unwrap();
+        let deployments = fake
+            .get_build_deployments(build_id, None, None, None, None, None)
+            .unwrap();
+        assert_eq!(deployments.items.len(), 1);
+        assert_eq!(fake.get_node(node_id).unwrap().apps.len(), 1);
+    }
+
+    #[test]
+    fn test_zone_lifecycle() {
+        let fake = FakeEnclaveManager::new();
+        let mut request = models::AddZoneRequest::new();
+        request.name = Some("edge".to_owned());
+        let zone = fake.create_zone(request.clone()).unwrap();
+        assert_eq!(fake.get_zones().unwrap().len(), 2);
+        assert_eq!(fake.create_zone(request).unwrap_err().status(), Some(409));
+
+        let mut update = models::UpdateZoneRequest::new();
+        update.node_refresh_interval = Some(600);
+        update.node_renewal_threshold = Some(30);
+        let updated = fake.update_zone(zone.zone_id, update).unwrap();
+        assert_eq!(updated.name, "edge");
+        assert_eq!(
+            (
+                updated.node_refresh_interval,
+                updated.node_renewal_threshold
+            ),
+            (600, 30)
+        );
+        let mut update = models::UpdateZoneRequest::new();
+        update.node_renewal_threshold = Some(0);
+        let err = fake.update_zone(zone.zone_id, update).unwrap_err();
+        assert_eq!(err.status(), Some(400));
+
+        // Nodes join the default zone, which then cannot be deleted.
+        let node_id = provision(&fake, "node-1").node_id.unwrap();
+        let default_zone = fake.get_node(node_id).unwrap().zone_id.unwrap();
+        let err = fake.delete_zone(default_zone).unwrap_err();
+        assert_eq!(err.status(), Some(409));
+        fake.delete_zone(zone.zone_id).unwrap();
+        assert!(fake.get_zone(zone.zone_id).unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_filtering_and_pagination() {
+        let fake = FakeEnclaveManager::new();
+        for name in &["node-c", "node-a", "other", "node-b"] {
+            provision(&fake, name);
+        }
+        let query = NodeQuery::new()
+            .name("NODE")
+            .sort_by(NodeSortField::Name, SortOrder::Descending)
+            .limit(2)
+            .offset(2);
+        let response = fake.query_nodes(&query).unwrap();
+        let names: Vec<_> = response.items.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, ["node-a"]);
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata.page, 2);
+        assert_eq!(metadata.pages, 2);
+        assert_eq!(metadata.total_count, 4);
+        assert_eq!(metadata.filtered_count, 3);
+
+        let all: Vec<_> = fake
+            .iter_nodes(NodeQuery::new())
+            .with_page_size(3)
+            .map(|node| node.unwrap().name)
+            .collect();
+        assert_eq!(all, ["node-c", "node-a", "other", "node-b"]);
+
+        let err = fake
+            .get_all_nodes(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("ip".to_owned()),
+            )
+            .unwrap_err();
+        assert_eq!(err.status(), Some(400));
+    }
+
+    #[test]
+    fn test_audit_events() {
+        let fake = FakeEnclaveManager::new();
+        fake.authenticate_user(None).unwrap();
+        let build = fake.create_build(build_request("app")).unwrap();
+        let task_id = build.pending_task_id.unwrap();
+        fake.update_task(
+            task_id,
+            models::TaskUpdateRequest::new(models::ApprovalStatus::DENIED),
+        )
+        .unwrap();
+
+        let events: Vec<_> = fake
+            .iter_events(
+                EventQuery::new().sort_by(EventSortField::CreatedAt, SortOrder::Descending),
+            )
+            .with_page_size(1)
+            .map(|event| event.unwrap())
+            .collect();
+        let actions: Vec<_> = events.iter().map(|e| e.action_type).collect();
+        assert_eq!(
+            actions,
+            [
+                models::EventActionType::USER_APPROVAL,
+                models::EventActionType::USER_AUTH
+            ]
+        );
+        assert_eq!(events[0].entity_id, Some(task_id));
+
+        let query = EventQuery::new()
+            .actor_type(models::EventActorType::USER)
+            .entity_id(task_id);
+        assert_eq!(fake.query_events(&query).unwrap().items, &events[..1]);
+        let query = EventQuery::new().to(events[1].created_at);
+        assert_eq!(fake.query_events(&query).unwrap().items, &events[1..]);
+        let query = EventQuery::new().from(events[0].created_at + 1);
+        assert!(fake.query_events(&query).unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn test_error_injection() {
+        let fake = FakeEnclaveManager::new();
+        let err = fake.get_task(uuid::Uuid::nil()).unwrap_err();
+        assert!(err.is_not_found());
+
+        fake.fail_next("get_zones", 503, models::EventType::UNDERGOING_MAINTENANCE);
+        let err = fake.get_zones().unwrap_err();
+        assert_eq!(err.error_type(), SimpleErrorType::Temporary);
+        assert_eq!(fake.get_zones().unwrap().len(), 1);
+
+        fake.fail_always("get_zones", 404, models::EventType::NOT_FOUND);
+        assert!(fake.get_zones().unwrap_err().is_not_found());
+        assert!(fake.get_zones().unwrap_err().is_not_found());
+        fake.clear_failures();
+        assert!(fake.get_zones().is_ok());
+        assert_eq!(fake.calls("get_zones"), 5);
+    }
+
+    #[test]
+    fn test_runtime_config_hash() {
+        fn app_config_hash<A: Api>(api: &A, config_id: &str) -> [u8; 32] {
+            let runtime = api
+                .get_specific_runtime_application_config(config_id.to_owned())
+                .ok()
+                .unwrap();
+            *runtime.config.canonical_hash()
+        }
+
+        let fake = FakeEnclaveManager::new();
+        let config = fake
+            .create_application_config(models::ApplicationConfig::new(
+                "config".to_owned(),
+                "".to_owned(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ))
+            .unwrap();
+        let hash = app_config_hash(&fake, &config.config_id);
+        let runtime = fake.get_runtime_application_config(&hash).unwrap();
+        assert_eq!(runtime.config.zone_ca.len(), 1);
+        assert!(fake
+            .get_runtime_application_config(&[0; 32])
+            .unwrap_err()
+            .is_not_found());
+    }
+}
@@ -24,13 +24,22 @@ extern crate log;
 #[macro_use]
 extern crate serde_derive;
 
-#[cfg(feature = "client")]
+#[cfg(any(feature = "client", feature = "server"))]
 #[macro_use]
 extern crate hyper;
-#[cfg(feature = "client")]
+#[cfg(any(feature = "client", feature = "server"))]
 #[macro_use]
 extern crate url;
 
+#[cfg(feature = "async-client")]
+extern crate bytes;
+#[cfg(feature = "async-client")]
+extern crate http_body_util;
+#[cfg(feature = "async-client")]
+extern crate hyper1;
+#[cfg(feature = "async-client")]
+extern crate hyper_util;
+
 extern crate mime;
 extern crate serde;
 extern crate serde_json;
@@ -46,6 +55,7 @@ use std::error;
 use std::fmt;
 use std::io::Error;
 use std::ops::Deref;
+use std::pin::Pin;
 
 #[allow(unused_imports)]
 use std::collections::HashMap;
@@ -245,12 +255,21 @@ pub trait Api {
         request_id: uuid::Uuid,
     ) -> Result<models::ApprovableResult, Self::Error>;
 
+    /// Discover the authentication methods available to a user
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error>;
+
     /// User authentication
     fn authenticate_user(
         &self,
         body: Option<models::AuthRequest>,
     ) -> Result<models::AuthResponse, Self::Error>;
 
+    /// Extend the current session
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error>;
+
     /// Convert a docker image and create a new image.
     fn convert_app_build(
         &self,
@@ -330,6 +349,20 @@ pub trait Api {
         body: models::DatasetUpdateRequest,
     ) -> Result<models::Dataset, Self::Error>;
 
+    /// Get all events, e.g. for an audit trail.
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error>;
+
     /// Deactivate a particular compute node.
     fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), Self::Error>;
 
@@ -596,6 +629,12 @@ pub trait Api {
         body: models::CreateWorkflowVersionRequest,
     ) -> Result<models::VersionInFinalWorkflow, Self::Error>;
 
+    /// Create a new zone.
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error>;
+
+    /// Delete a zone.
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error>;
+
     /// Get zone details.
     fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error>;
 
@@ -607,6 +646,13 @@ pub trait Api {
 
     /// Get all zones.
     fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error>;
+
+    /// Update zone details and settings.
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error>;
 }
 
 /// API trait with mutable receivers i.e. `fn api_call(&mut self, ...)`
@@ -774,12 +820,21 @@ pub trait ApiMut {
         request_id: uuid::Uuid,
     ) -> Result<models::ApprovableResult, Self::Error>;
 
+    /// Discover the authentication methods available to a user
+    fn auth_discover(
+        &mut self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error>;
+
     /// User authentication
     fn authenticate_user(
         &mut self,
         body: Option<models::AuthRequest>,
     ) -> Result<models::AuthResponse, Self::Error>;
 
+    /// Extend the current session
+    fn refresh_session(&mut self) -> Result<models::RefreshResponse, Self::Error>;
+
     /// Convert a docker image and create a new image.
     fn convert_app_build(
         &mut self,
@@ -862,6 +917,20 @@ pub trait ApiMut {
         body: models::DatasetUpdateRequest,
     ) -> Result<models::Dataset, Self::Error>;
 
+    /// Get all events, e.g. for an audit trail.
+    fn get_all_events(
+        &mut self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error>;
+
     /// Deactivate a particular compute node.
     fn deactivate_node(&mut self, node_id: uuid::Uuid) -> Result<(), Self::Error>;
 
@@ -1134,6 +1203,12 @@ pub trait ApiMut {
         body: models::CreateWorkflowVersionRequest,
     ) -> Result<models::VersionInFinalWorkflow, Self::Error>;
 
+    /// Create a new zone.
+    fn create_zone(&mut self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error>;
+
+    /// Delete a zone.
+    fn delete_zone(&mut self, zone_id: uuid::Uuid) -> Result<(), Self::Error>;
+
     /// Get zone details.
     fn get_zone(&mut self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error>;
 
@@ -1145,6 +1220,13 @@ pub trait ApiMut {
 
     /// Get all zones.
     fn get_zones(&mut self) -> Result<Vec<models::Zone>, Self::Error>;
+
+    /// Update zone details and settings.
+    fn update_zone(
+        &mut self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error>;
 }
 
 /*
@@ -1167,6 +1249,7 @@ where
         + BuildApi<Error = E>
         + CertificateApi<Error = E>
         + DatasetApi<Error = E>
+        + EventsApi<Error = E>
         + NodeApi<Error = E>
         + RegistryApi<Error = E>
         + SystemApi<Error = E>
@@ -1198,6 +1281,7 @@ where
         + BuildApi<Error = E>
         + CertificateApi<Error = E>
         + DatasetApi<Error = E>
+        + EventsApi<Error = E>
         + NodeApi<Error = E>
         + RegistryApi<Error = E>
         + SystemApi<Error = E>
@@ -1414,6 +1498,13 @@ where
         ApprovalRequestsApi::get_approval_request_result(self.0, request_id)
     }
 
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        AuthApi::auth_discover(self.0, body)
+    }
+
     fn authenticate_user(
         &self,
         body: Option<models::AuthRequest>,
@@ -1421,6 +1512,10 @@ where
         AuthApi::authenticate_user(self.0, body)
     }
 
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error> {
+        AuthApi::refresh_session(self.0)
+    }
+
     fn convert_app_build(
         &self,
         body: models::ConvertAppBuildRequest,
@@ -1530,6 +1625,32 @@ where
         DatasetApi::update_dataset(self.0, dataset_id, body)
     }
 
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        EventsApi::get_all_events(
+            self.0,
+            actor_type,
+            action_type,
+            severity,
+            entity_id,
+            from,
+            to,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
     fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), Self::Error> {
         NodeApi::deactivate_node(self.0, node_id)
     }
@@ -1891,6 +2012,14 @@ where
         WorkflowFinalApi::update_final_workflow_graph(self.0, graph_id, body)
     }
 
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        ZoneApi::create_zone(self.0, body)
+    }
+
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        ZoneApi::delete_zone(self.0, zone_id)
+    }
+
     fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
         ZoneApi::get_zone(self.0, zone_id)
     }
@@ -1905,6 +2034,14 @@ where
     fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error> {
         ZoneApi::get_zones(self.0)
     }
+
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        ZoneApi::update_zone(self.0, zone_id, body)
+    }
 }
 
 /// Implements with functions of the form `fn api_call(&self, ...) { self.dispatch(|a| Api::api_call(a, ...)) }`
@@ -2108,6 +2245,13 @@ where
         self.dispatch(|a| Api::get_approval_request_result(a, request_id))
     }
 
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        self.dispatch(|a| Api::auth_discover(a, body))
+    }
+
     fn authenticate_user(
         &self,
         body: Option<models::AuthRequest>,
@@ -2115,6 +2259,10 @@ where
         self.dispatch(|a| Api::authenticate_user(a, body))
     }
 
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error> {
+        self.dispatch(|a| Api::refresh_session(a))
+    }
+
     fn convert_app_build(
         &self,
         body: models::ConvertAppBuildRequest,
@@ -2226,6 +2374,34 @@ where
         self.dispatch(|a| Api::update_dataset(a, dataset_id, body))
     }
 
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        self.dispatch(|a| {
+            Api::get_all_events(
+                a,
+                actor_type,
+                action_type,
+                severity,
+                entity_id,
+                from,
+                to,
+                limit,
+                offset,
+                sort_by,
+            )
+        })
+    }
+
     fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), Self::Error> {
         self.dispatch(|a| Api::deactivate_node(a, node_id))
     }
@@ -2595,6 +2771,14 @@ where
         self.dispatch(|a| Api::update_final_workflow_graph(a, graph_id, body))
     }
 
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        self.dispatch(|a| Api::create_zone(a, body))
+    }
+
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        self.dispatch(|a| Api::delete_zone(a, zone_id))
+    }
+
     fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
         self.dispatch(|a| Api::get_zone(a, zone_id))
     }
@@ -2609,6 +2793,14 @@ where
     fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error> {
         self.dispatch(|a| Api::get_zones(a))
     }
+
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        self.dispatch(|a| Api::update_zone(a, zone_id, body))
+    }
 }
 
 /// Implements with functions of the form `fn api_call(&mut self, ...) { self.api_call(...) }`
@@ -2622,6 +2814,7 @@ where
         + BuildApiMut<Error = E>
         + CertificateApiMut<Error = E>
         + DatasetApiMut<Error = E>
+        + EventsApiMut<Error = E>
         + NodeApiMut<Error = E>
         + RegistryApiMut<Error = E>
         + SystemApiMut<Error = E>
@@ -2825,6 +3018,13 @@ where
         self.get_approval_request_result(request_id)
     }
 
+    fn auth_discover(
+        &mut self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        self.auth_discover(body)
+    }
+
     fn authenticate_user(
         &mut self,
         body: Option<models::AuthRequest>,
@@ -2832,6 +3032,10 @@ where
         self.authenticate_user(body)
     }
 
+    fn refresh_session(&mut self) -> Result<models::RefreshResponse, Self::Error> {
+        self.refresh_session()
+    }
+
     fn convert_app_build(
         &mut self,
         body: models::ConvertAppBuildRequest,
@@ -2941,6 +3145,31 @@ where
         self.update_dataset(dataset_id, body)
     }
 
+    fn get_all_events(
+        &mut self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        self.get_all_events(
+            actor_type,
+            action_type,
+            severity,
+            entity_id,
+            from,
+            to,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
     fn deactivate_node(&mut self, node_id: uuid::Uuid) -> Result<(), Self::Error> {
         self.deactivate_node(node_id)
     }
@@ -3297,6 +3526,14 @@ where
         self.update_final_workflow_graph(graph_id, body)
     }
 
+    fn create_zone(&mut self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        self.create_zone(body)
+    }
+
+    fn delete_zone(&mut self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        self.delete_zone(zone_id)
+    }
+
     fn get_zone(&mut self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
         self.get_zone(zone_id)
     }
@@ -3311,6 +3548,14 @@ where
     fn get_zones(&mut self) -> Result<Vec<models::Zone>, Self::Error> {
         self.get_zones()
     }
+
+    fn update_zone(
+        &mut self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        self.update_zone(zone_id, body)
+    }
 }
 
 impl<T, E> Api for std::cell::RefCell<T>
@@ -3512,6 +3757,13 @@ where
         self.borrow_mut().get_approval_request_result(request_id)
     }
 
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        self.borrow_mut().auth_discover(body)
+    }
+
     fn authenticate_user(
         &self,
         body: Option<models::AuthRequest>,
@@ -3519,6 +3771,10 @@ where
         self.borrow_mut().authenticate_user(body)
     }
 
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error> {
+        self.borrow_mut().refresh_session()
+    }
+
     fn convert_app_build(
         &self,
         body: models::ConvertAppBuildRequest,
@@ -3627,6 +3883,31 @@ where
         self.borrow_mut().update_dataset(dataset_id, body)
     }
 
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        self.borrow_mut().get_all_events(
+            actor_type,
+            action_type,
+            severity,
+            entity_id,
+            from,
+            to,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+
     fn deactivate_node(&self, node_id: uuid::Uuid) -> Result<(), Self::Error> {
         self.borrow_mut().deactivate_node(node_id)
     }
@@ -3989,6 +4270,14 @@ where
             .update_final_workflow_graph(graph_id, body)
     }
 
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        self.borrow_mut().create_zone(body)
+    }
+
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        self.borrow_mut().delete_zone(zone_id)
+    }
+
     fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
         self.borrow_mut().get_zone(zone_id)
     }
@@ -4003,6 +4292,14 @@ where
     fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error> {
         self.borrow_mut().get_zones()
     }
+
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        self.borrow_mut().update_zone(zone_id, body)
+    }
 }
 
 pub trait AccountsApi {
@@ -4593,21 +4890,39 @@ where
 pub trait AuthApi {
     type Error;
 
+    /// Discover the authentication methods available to a user
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error>;
+
     /// User authentication
     fn authenticate_user(
         &self,
         body: Option<models::AuthRequest>,
     ) -> Result<models::AuthResponse, Self::Error>;
+
+    /// Extend the current session
+    fn refresh_session(&self) -> Result<models::RefreshResponse, Self::Error>;
 }
 
 pub trait AuthApiMut {
     type Error;
 
+    /// Discover the authentication methods available to a user
+    fn auth_discover(
+        &mut self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error>;
+
     /// User authentication
     fn authenticate_user(
         &mut self,
         body: Option<models::AuthRequest>,
     ) -> Result<models::AuthResponse, Self::Error>;
+
+    /// Extend the current session
+    fn refresh_session(&mut self) -> Result<models::RefreshResponse, Self::Error>;
 }
 
 // This is mostly so that we don't have to convert all the malbork APIs to
@@ -4618,12 +4933,23 @@ where
 {
     type Error = E;
 
+    fn auth_discover(
+        &mut self,
+        body: models::AuthDiscoverRequest,
+    ) -> Result<models::AuthDiscoverResponse, Self::Error> {
+        <T as AuthApi>::auth_discover(self, body)
+    }
+
     fn authenticate_user(
         &mut self,
         body: Option<models::AuthRequest>,
     ) -> Result<models::AuthResponse, Self::Error> {
         <T as AuthApi>::authenticate_user(self, body)
     }
+
+    fn refresh_session(&mut self) -> Result<models::RefreshResponse, Self::Error> {
+        <T as AuthApi>::refresh_session(self)
+    }
 }
 
 pub trait BuildApi {
@@ -4950,6 +5276,77 @@ where
     }
 }
 
+pub trait EventsApi {
+    type Error;
+
+    /// Get all events, e.g. for an audit trail.
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error>;
+}
+
+pub trait EventsApiMut {
+    type Error;
+
+    /// Get all events, e.g. for an audit trail.
+    fn get_all_events(
+        &mut self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error>;
+}
+
+// This is mostly so that we don't have to convert all the malbork APIs to
+// ApiMut at once.
+impl<T, E> EventsApiMut for T
+where
+    T: EventsApi<Error = E>,
+{
+    type Error = E;
+
+    fn get_all_events(
+        &mut self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        <T as EventsApi>::get_all_events(
+            self,
+            actor_type,
+            action_type,
+            severity,
+            entity_id,
+            from,
+            to,
+            limit,
+            offset,
+            sort_by,
+        )
+    }
+}
+
 pub trait NodeApi {
     type Error;
 
@@ -5994,6 +6391,12 @@ where
 pub trait ZoneApi {
     type Error;
 
+    /// Create a new zone.
+    fn create_zone(&self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error>;
+
+    /// Delete a zone.
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> Result<(), Self::Error>;
+
     /// Get zone details.
     fn get_zone(&self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error>;
 
@@ -6005,11 +6408,24 @@ pub trait ZoneApi {
 
     /// Get all zones.
     fn get_zones(&self) -> Result<Vec<models::Zone>, Self::Error>;
+
+    /// Update zone details and settings.
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error>;
 }
 
 pub trait ZoneApiMut {
     type Error;
 
+    /// Create a new zone.
+    fn create_zone(&mut self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error>;
+
+    /// Delete a zone.
+    fn delete_zone(&mut self, zone_id: uuid::Uuid) -> Result<(), Self::Error>;
+
     /// Get zone details.
     fn get_zone(&mut self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error>;
 
@@ -6021,6 +6437,13 @@ pub trait ZoneApiMut {
 
     /// Get all zones.
     fn get_zones(&mut self) -> Result<Vec<models::Zone>, Self::Error>;
+
+    /// Update zone details and settings.
+    fn update_zone(
+        &mut self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error>;
 }
 
 // This is mostly so that we don't have to convert all the malbork APIs to
@@ -6031,6 +6454,14 @@ where
 {
     type Error = E;
 
+    fn create_zone(&mut self, body: models::AddZoneRequest) -> Result<models::Zone, Self::Error> {
+        <T as ZoneApi>::create_zone(self, body)
+    }
+
+    fn delete_zone(&mut self, zone_id: uuid::Uuid) -> Result<(), Self::Error> {
+        <T as ZoneApi>::delete_zone(self, zone_id)
+    }
+
     fn get_zone(&mut self, zone_id: uuid::Uuid) -> Result<models::Zone, Self::Error> {
         <T as ZoneApi>::get_zone(self, zone_id)
     }
@@ -6045,82 +6476,756 @@ where
     fn get_zones(&mut self) -> Result<Vec<models::Zone>, Self::Error> {
         <T as ZoneApi>::get_zones(self)
     }
+
+    fn update_zone(
+        &mut self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> Result<models::Zone, Self::Error> {
+        <T as ZoneApi>::update_zone(self, zone_id, body)
+    }
 }
 
-#[cfg(feature = "client")]
-pub mod client;
+/// Future returned by the operations of the `*ApiAsync` traits.
+pub type ApiFuture<T, E> = Pin<Box<dyn futures::Future<Output = Result<T, E>> + Send>>;
 
-// Re-export Client as a top-level name
-#[cfg(feature = "client")]
-pub use self::client::Client;
+pub trait AccountsApiAsync {
+    type Error;
 
-pub mod models;
+    /// Create a new account.
+    fn create_account(
+        &self,
+        body: models::AccountRequest,
+    ) -> ApiFuture<models::Account, Self::Error>;
 
-pub mod base64_format {
-    // This module from swagger-rs
+    /// Delete an account.
+    fn delete_account(&self, account_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
 
-    use base64::{decode, encode};
-    use serde::de::{Deserialize, Deserializer, Error};
-    use serde::ser::{Serialize, Serializer};
-    use std::ops::{Deref, DerefMut};
+    /// Get a specific account.
+    fn get_account(&self, account_id: uuid::Uuid) -> ApiFuture<models::Account, Self::Error>;
 
-    #[derive(Debug, Clone, PartialEq, PartialOrd)]
-    /// Base64-encoded byte array
-    pub struct ByteArray(pub Vec<u8>);
+    /// Get all accounts.
+    fn get_accounts(&self) -> ApiFuture<models::AccountListResponse, Self::Error>;
 
-    impl Serialize for ByteArray {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            serializer.serialize_str(&encode(&self.0))
-        }
-    }
+    /// Select a user's account to work on.
+    fn select_account(&self, account_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
 
-    impl<'de> Deserialize<'de> for ByteArray {
-        fn deserialize<D>(deserializer: D) -> Result<ByteArray, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            match decode(&s) {
-                Ok(bin) => Ok(ByteArray(bin)),
-                _ => Err(D::Error::custom("invalid base64")),
-            }
-        }
-    }
+    /// Update an account.
+    fn update_account(
+        &self,
+        account_id: uuid::Uuid,
+        body: models::AccountUpdateRequest,
+    ) -> ApiFuture<models::Account, Self::Error>;
+}
 
-    impl Deref for ByteArray {
-        type Target = Vec<u8>;
-        fn deref(&self) -> &Vec<u8> {
-            &self.0
-        }
-    }
+pub trait AppApiAsync {
+    type Error;
 
-    impl DerefMut for ByteArray {
-        fn deref_mut(&mut self) -> &mut Vec<u8> {
-            &mut self.0
-        }
-    }
+    /// Add an application.
+    fn add_application(&self, body: models::AppRequest) -> ApiFuture<models::App, Self::Error>;
 
-    impl AsRef<[u8]> for ByteArray {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
-    }
+    /// Delete a particular app
+    fn delete_app(&self, app_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get all apps information.
+    fn get_all_apps(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllAppsResponse, Self::Error>;
+
+    /// Get details of a particular app.
+    fn get_app(&self, app_id: uuid::Uuid) -> ApiFuture<models::App, Self::Error>;
+
+    /// Get an attested app's certificate.
+    fn get_app_certificate(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> ApiFuture<models::Certificate, Self::Error>;
+
+    /// Get an app's certificate for a compute node.
+    fn get_app_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+        app_id: uuid::Uuid,
+    ) -> ApiFuture<models::CertificateDetails, Self::Error>;
+
+    /// Get all the unique labels across all the applications within selected account
+    fn get_apps_unique_labels(&self) -> ApiFuture<models::LabelsCount, Self::Error>;
+
+    /// Update details of a particular app.
+    fn update_app(
+        &self,
+        app_id: uuid::Uuid,
+        body: models::AppBodyUpdateRequest,
+    ) -> ApiFuture<models::App, Self::Error>;
+}
+
+pub trait ApplicationConfigApiAsync {
+    type Error;
+
+    /// Add an app config.
+    fn create_application_config(
+        &self,
+        body: models::ApplicationConfig,
+    ) -> ApiFuture<models::ApplicationConfigResponse, Self::Error>;
+
+    /// Delete a particular app config
+    fn delete_application_config(&self, config_id: String) -> ApiFuture<(), Self::Error>;
+
+    /// Get all app configs
+    fn get_all_application_configs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        image_id: Option<uuid::Uuid>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllApplicationConfigsResponse, Self::Error>;
+
+    /// Get details of a particular app config.
+    fn get_application_config(
+        &self,
+        config_id: String,
+    ) -> ApiFuture<models::ApplicationConfigResponse, Self::Error>;
+
+    /// Get app config
+    fn get_runtime_application_config(
+        &self,
+        expected_hash: &[u8; 32],
+    ) -> ApiFuture<models::RuntimeAppConfig, Self::Error>;
+
+    /// Get details of a particular runtime app config.
+    fn get_specific_runtime_application_config(
+        &self,
+        config_id: String,
+    ) -> ApiFuture<models::RuntimeAppConfig, Self::Error>;
+
+    /// Update details of a particular app config.
+    fn update_application_config(
+        &self,
+        config_id: String,
+        body: models::UpdateApplicationConfigRequest,
+    ) -> ApiFuture<models::ApplicationConfigResponse, Self::Error>;
+}
+
+pub trait ApprovalRequestsApiAsync {
+    type Error;
+
+    /// Approve a request.
+    fn approve_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::ApproveRequest>,
+    ) -> ApiFuture<models::ApprovalRequest, Self::Error>;
+
+    /// Create approval request.
+    fn create_approval_request(
+        &self,
+        body: models::ApprovalRequestRequest,
+    ) -> ApiFuture<models::ApprovalRequest, Self::Error>;
+
+    /// Delete an approval request.
+    fn delete_approval_request(&self, request_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Deny a request.
+    fn deny_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+        body: Option<models::DenyRequest>,
+    ) -> ApiFuture<models::ApprovalRequest, Self::Error>;
+
+    /// Get all approval requests
+    fn get_all_approval_requests(
+        &self,
+        requester: Option<uuid::Uuid>,
+        reviewer: Option<uuid::Uuid>,
+        subject: Option<uuid::Uuid>,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllApprovalRequests, Self::Error>;
+
+    /// Get an approval request.
+    fn get_approval_request(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> ApiFuture<models::ApprovalRequest, Self::Error>;
+
+    /// Get the result for an approved or failed request.
+    fn get_approval_request_result(
+        &self,
+        request_id: uuid::Uuid,
+    ) -> ApiFuture<models::ApprovableResult, Self::Error>;
+}
+
+pub trait AuthApiAsync {
+    type Error;
+
+    /// Discover the authentication methods available to a user
+    fn auth_discover(
+        &self,
+        body: models::AuthDiscoverRequest,
+    ) -> ApiFuture<models::AuthDiscoverResponse, Self::Error>;
+
+    /// User authentication
+    fn authenticate_user(
+        &self,
+        body: Option<models::AuthRequest>,
+    ) -> ApiFuture<models::AuthResponse, Self::Error>;
+
+    /// Extend the current session
+    fn refresh_session(&self) -> ApiFuture<models::RefreshResponse, Self::Error>;
+}
+
+pub trait BuildApiAsync {
+    type Error;
+
+    /// Convert a docker image and create a new image.
+    fn convert_app_build(
+        &self,
+        body: models::ConvertAppBuildRequest,
+    ) -> ApiFuture<models::Build, Self::Error>;
+
+    /// Create a new image.
+    fn create_build(
+        &self,
+        body: models::CreateBuildRequest,
+    ) -> ApiFuture<models::Build, Self::Error>;
+
+    /// Delete a particular image.
+    fn delete_build(&self, build_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get all images information.
+    fn get_all_builds(
+        &self,
+        all_search: Option<String>,
+        docker_image_name: Option<String>,
+        config_id: Option<String>,
+        deployed_status: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllBuildsResponse, Self::Error>;
+
+    /// Get details of a particular image.
+    fn get_build(&self, build_id: uuid::Uuid) -> ApiFuture<models::Build, Self::Error>;
+
+    /// Get all deployments of an image.
+    fn get_build_deployments(
+        &self,
+        build_id: uuid::Uuid,
+        status: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllBuildDeploymentsResponse, Self::Error>;
+
+    /// Update details of a particular image.
+    fn update_build(
+        &self,
+        build_id: uuid::Uuid,
+        body: models::BuildUpdateRequest,
+    ) -> ApiFuture<models::Build, Self::Error>;
+}
+
+pub trait CertificateApiAsync {
+    type Error;
+
+    /// Retrieve a certificate.
+    fn get_certificate(&self, cert_id: uuid::Uuid) -> ApiFuture<models::Certificate, Self::Error>;
+
+    /// Request a new certificate for an Enclave application
+    fn new_certificate(
+        &self,
+        body: models::NewCertificateRequest,
+    ) -> ApiFuture<models::TaskResult, Self::Error>;
+}
+
+pub trait DatasetApiAsync {
+    type Error;
+
+    fn create_dataset(
+        &self,
+        body: models::CreateDatasetRequest,
+    ) -> ApiFuture<models::Dataset, Self::Error>;
+
+    fn delete_dataset(&self, dataset_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get all datasets
+    fn get_all_datasets(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllDatasetsResponse, Self::Error>;
+
+    fn get_dataset(&self, dataset_id: uuid::Uuid) -> ApiFuture<models::Dataset, Self::Error>;
+
+    fn update_dataset(
+        &self,
+        dataset_id: uuid::Uuid,
+        body: models::DatasetUpdateRequest,
+    ) -> ApiFuture<models::Dataset, Self::Error>;
+}
+
+pub trait EventsApiAsync {
+    type Error;
+
+    /// Get all events, e.g. for an audit trail.
+    fn get_all_events(
+        &self,
+        actor_type: Option<String>,
+        action_type: Option<String>,
+        severity: Option<String>,
+        entity_id: Option<uuid::Uuid>,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllEventsResponse, Self::Error>;
+}
+
+pub trait NodeApiAsync {
+    type Error;
+
+    /// Deactivate a particular compute node.
+    fn deactivate_node(&self, node_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get all compute nodes information.
+    fn get_all_nodes(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        sgx_version: Option<String>,
+        all_search: Option<String>,
+        status: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllNodesResponse, Self::Error>;
+
+    /// Get details of a particular compute node.
+    fn get_node(&self, node_id: uuid::Uuid) -> ApiFuture<models::Node, Self::Error>;
+
+    /// Get an attested compute node's certificate.
+    fn get_node_certificate(
+        &self,
+        node_id: uuid::Uuid,
+    ) -> ApiFuture<models::Certificate, Self::Error>;
+
+    /// Get a compute node's certificate.
+    fn get_node_certificate_details(
+        &self,
+        node_id: uuid::Uuid,
+    ) -> ApiFuture<models::CertificateDetails, Self::Error>;
+
+    /// Get all the unique labels across all the nodes within selected account
+    fn get_nodes_unique_labels(&self) -> ApiFuture<models::LabelsCount, Self::Error>;
+
+    /// Provision a new compute node.
+    fn provision_node(
+        &self,
+        body: models::NodeProvisionRequest,
+    ) -> ApiFuture<models::TaskResult, Self::Error>;
+
+    /// Update details of a particular compute node.
+    fn update_node(
+        &self,
+        node_id: uuid::Uuid,
+        body: models::NodeUpdateRequest,
+    ) -> ApiFuture<models::Node, Self::Error>;
+
+    /// Called periodically by a compute node.
+    fn update_node_status(
+        &self,
+        body: models::NodeStatusRequest,
+    ) -> ApiFuture<models::NodeStatusResponse, Self::Error>;
+}
+
+pub trait RegistryApiAsync {
+    type Error;
+
+    /// Add a new registry to an account
+    fn create_registry(
+        &self,
+        registry_request: models::RegistryRequest,
+    ) -> ApiFuture<models::Registry, Self::Error>;
+
+    /// Delete registry
+    fn delete_registry(&self, registry_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get details of all registry in the account
+    fn get_all_registries(&self) -> ApiFuture<Vec<models::Registry>, Self::Error>;
+
+    /// Get details of a particular registry
+    fn get_registry(&self, registry_id: uuid::Uuid) -> ApiFuture<models::Registry, Self::Error>;
+
+    /// Get details of the registry that will be used for the particular app images
+    fn get_registry_for_app(
+        &self,
+        app_id: uuid::Uuid,
+    ) -> ApiFuture<models::AppRegistryResponse, Self::Error>;
+
+    /// Get details of the registry that will be used for the particular image
+    fn get_registry_for_image(
+        &self,
+        image_name: String,
+    ) -> ApiFuture<models::ImageRegistryResponse, Self::Error>;
+
+    /// Update a particular registry details
+    fn update_registry(
+        &self,
+        registry_id: uuid::Uuid,
+        body: models::UpdateRegistryRequest,
+    ) -> ApiFuture<models::Registry, Self::Error>;
+}
+
+pub trait SystemApiAsync {
+    type Error;
+
+    /// Get Manager Version.
+    fn get_manager_version(&self) -> ApiFuture<models::VersionResponse, Self::Error>;
+}
+
+pub trait TaskApiAsync {
+    type Error;
+
+    /// Get all the tasks.
+    fn get_all_tasks(
+        &self,
+        task_type: Option<String>,
+        status: Option<String>,
+        requester: Option<String>,
+        approver: Option<String>,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+        base_filters: Option<String>,
+    ) -> ApiFuture<models::GetAllTasksResponse, Self::Error>;
+
+    /// Get details of a particular task.
+    fn get_task(&self, task_id: uuid::Uuid) -> ApiFuture<models::Task, Self::Error>;
+
+    /// Get status and result of a particular task.
+    fn get_task_status(&self, task_id: uuid::Uuid) -> ApiFuture<models::TaskResult, Self::Error>;
+
+    /// Update status of approver and task.
+    fn update_task(
+        &self,
+        task_id: uuid::Uuid,
+        body: models::TaskUpdateRequest,
+    ) -> ApiFuture<models::TaskResult, Self::Error>;
+}
+
+pub trait ToolsApiAsync {
+    type Error;
+
+    /// Convert an application to run in EnclaveOS.
+    fn convert_app(
+        &self,
+        body: models::ConversionRequest,
+    ) -> ApiFuture<models::ConversionResponse, Self::Error>;
+}
+
+pub trait UsersApiAsync {
+    type Error;
+
+    /// Current user accepts latest terms and conditions.
+    fn accept_terms_and_conditions(&self) -> ApiFuture<(), Self::Error>;
+
+    /// Change user password.
+    fn change_password(&self, body: models::PasswordChangeRequest) -> ApiFuture<(), Self::Error>;
+
+    /// Confirms user's email address.
+    fn confirm_email(
+        &self,
+        body: models::ConfirmEmailRequest,
+    ) -> ApiFuture<models::ConfirmEmailResponse, Self::Error>;
+
+    /// Create a new user.
+    fn create_user(&self, body: models::SignupRequest) -> ApiFuture<models::User, Self::Error>;
+
+    /// Completely delete a user profile from system
+    fn delete_user_account(&self, user_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Removed user's association with an account.
+    fn delete_user_from_account(&self, user_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Initiate password reset sequence for a user.
+    fn forgot_password(&self, body: models::ForgotPasswordRequest) -> ApiFuture<(), Self::Error>;
+
+    /// Get all user's information.
+    fn get_all_users(
+        &self,
+        all_search: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort_by: Option<String>,
+    ) -> ApiFuture<models::GetAllUsersResponse, Self::Error>;
+
+    /// Get details of the current logged in user.
+    fn get_logged_in_user(&self) -> ApiFuture<models::User, Self::Error>;
+
+    /// Get details of a particular user.
+    fn get_user(&self, user_id: uuid::Uuid) -> ApiFuture<models::User, Self::Error>;
+
+    /// Invite a user.
+    fn invite_user(&self, body: models::InviteUserRequest) -> ApiFuture<models::User, Self::Error>;
+
+    /// Process a user's pending account invitations.
+    fn process_invitations(&self, body: models::ProcessInviteRequest)
+        -> ApiFuture<(), Self::Error>;
+
+    /// Resend email with link to confirm user's email address.
+    fn resend_confirm_email(&self) -> ApiFuture<(), Self::Error>;
+
+    /// Resend invite to the user to join a specific account.
+    fn resend_invitation(&self, user_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Reset a user's password.
+    fn reset_password(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::PasswordResetRequest,
+    ) -> ApiFuture<(), Self::Error>;
+
+    /// Update status, name, and the role of a user. User with MANAGER access role can only update another user.
+    fn update_user(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::UpdateUserRequest,
+    ) -> ApiFuture<models::User, Self::Error>;
+
+    /// Validates password reset token for the user.
+    fn validate_password_reset_token(
+        &self,
+        user_id: uuid::Uuid,
+        body: models::ValidateTokenRequest,
+    ) -> ApiFuture<models::ValidateTokenResponse, Self::Error>;
 }
-pub use base64_format::ByteArray;
 
-/// Very simple error type - just holds a description of the error. This is useful for human
-/// diagnosis and troubleshooting, but not for applications to parse. The justification for this
-/// is to deny applications visibility into the communication layer, forcing the application code
-/// to act solely on the logical responses that the API provides, promoting abstraction in the
-/// application code.
+pub trait WorkflowApiAsync {
+    type Error;
 
-#[derive(Debug)]
+    fn create_workflow_graph(
+        &self,
+        body: models::CreateWorkflowGraph,
+    ) -> ApiFuture<models::WorkflowGraph, Self::Error>;
+
+    /// Delete a particular draft workflow
+    fn delete_workflow_graph(&self, graph_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    fn get_all_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        parent_graph_id: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllWorkflowGraphsResponse, Self::Error>;
+
+    /// Get details of a particular draft workflow
+    fn get_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+    ) -> ApiFuture<models::WorkflowGraph, Self::Error>;
+
+    fn update_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::UpdateWorkflowGraph,
+    ) -> ApiFuture<models::WorkflowGraph, Self::Error>;
+}
+
+pub trait WorkflowFinalApiAsync {
+    type Error;
+
+    fn create_final_workflow_graph(
+        &self,
+        body: models::CreateFinalWorkflowGraph,
+    ) -> ApiFuture<models::FinalWorkflow, Self::Error>;
+
+    /// Delete a particular final workflow
+    fn delete_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> ApiFuture<(), Self::Error>;
+
+    fn get_all_final_workflow_graphs(
+        &self,
+        name: Option<String>,
+        description: Option<String>,
+        all_search: Option<String>,
+        sort_by: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> ApiFuture<models::GetAllFinalWorkflowGraphsResponse, Self::Error>;
+
+    /// Get details of a particular final workflow version
+    fn get_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        version: String,
+    ) -> ApiFuture<models::VersionInFinalWorkflow, Self::Error>;
+
+    /// Get details of a particular final workflow
+    fn get_full_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+    ) -> ApiFuture<models::FinalWorkflow, Self::Error>;
+
+    /// Create a new version for a particular final workflow
+    fn update_final_workflow_graph(
+        &self,
+        graph_id: uuid::Uuid,
+        body: models::CreateWorkflowVersionRequest,
+    ) -> ApiFuture<models::VersionInFinalWorkflow, Self::Error>;
+}
+
+pub trait ZoneApiAsync {
+    type Error;
+
+    /// Create a new zone.
+    fn create_zone(&self, body: models::AddZoneRequest) -> ApiFuture<models::Zone, Self::Error>;
+
+    /// Delete a zone.
+    fn delete_zone(&self, zone_id: uuid::Uuid) -> ApiFuture<(), Self::Error>;
+
+    /// Get zone details.
+    fn get_zone(&self, zone_id: uuid::Uuid) -> ApiFuture<models::Zone, Self::Error>;
+
+    /// Get the authentication token.
+    fn get_zone_join_token(
+        &self,
+        zone_id: uuid::Uuid,
+    ) -> ApiFuture<models::ZoneJoinToken, Self::Error>;
+
+    /// Get all zones.
+    fn get_zones(&self) -> ApiFuture<Vec<models::Zone>, Self::Error>;
+
+    /// Update zone details and settings.
+    fn update_zone(
+        &self,
+        zone_id: uuid::Uuid,
+        body: models::UpdateZoneRequest,
+    ) -> ApiFuture<models::Zone, Self::Error>;
+}
+
+pub mod cache;
+pub mod config_files;
+pub mod config_hash;
+pub mod config_watch;
+pub mod endpoints;
+#[cfg(feature = "fake")]
+pub mod fake;
+#[cfg(feature = "client")]
+pub mod oauth;
+pub mod pagination;
+pub mod query;
+pub mod wait;
+
+#[cfg(feature = "client")]
+pub mod client;
+
+#[cfg(feature = "async-client")]
+pub mod async_client;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+// Re-export Client as a top-level name
+#[cfg(feature = "client")]
+pub use self::client::Client;
+
+// Re-export AsyncClient as a top-level name
+#[cfg(feature = "async-client")]
+pub use self::async_client::AsyncClient;
+
+pub mod models;
+
+pub mod base64_format {
+    // This module from swagger-rs
+
+    use base64::{decode, encode};
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+    use std::ops::{Deref, DerefMut};
+
+    #[derive(Debug, Clone, PartialEq, PartialOrd)]
+    /// Base64-encoded byte array
+    pub struct ByteArray(pub Vec<u8>);
+
+    impl Serialize for ByteArray {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&encode(&self.0))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ByteArray {
+        fn deserialize<D>(deserializer: D) -> Result<ByteArray, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            match decode(&s) {
+                Ok(bin) => Ok(ByteArray(bin)),
+                _ => Err(D::Error::custom("invalid base64")),
+            }
+        }
+    }
+
+    impl Deref for ByteArray {
+        type Target = Vec<u8>;
+        fn deref(&self) -> &Vec<u8> {
+            &self.0
+        }
+    }
+
+    impl DerefMut for ByteArray {
+        fn deref_mut(&mut self) -> &mut Vec<u8> {
+            &mut self.0
+        }
+    }
+
+    impl AsRef<[u8]> for ByteArray {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+}
+pub use base64_format::ByteArray;
+
+/// Error returned by the API operations. The message is meant for human diagnosis and
+/// troubleshooting. When the server answered with an unexpected status, the error also carries
+/// the status code, headers and body of the response, and the `Event` the server sent as its
+/// error payload, so applications can act on the failure without parsing the message.
 pub struct ApiError {
     message: String,
     error_type: SimpleErrorType,
+    status: Option<u16>,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+    event: Option<models::Event>,
+    timed_out: bool,
 }
 
 impl ApiError {
@@ -6131,6 +7236,82 @@ impl ApiError {
     pub fn error_type(&self) -> SimpleErrorType {
         self.error_type
     }
+
+    /// The request was abandoned because one of the client's timeouts
+    /// expired. Timeouts are always temporary errors.
+    pub fn is_timeout(&self) -> bool {
+        self.timed_out
+    }
+
+    /// The HTTP status code, if the error was caused by a response with an
+    /// unexpected status.
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// The headers of the response that caused the error, if any.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// The first value of the response header `name` (compared case-insensitively).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The raw body of the response that caused the error, if any.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+
+    /// The error payload sent by the server, if the response body could be
+    /// decoded as an `Event`.
+    pub fn event(&self) -> Option<&models::Event> {
+        self.event.as_ref()
+    }
+
+    pub fn event_type(&self) -> Option<models::EventType> {
+        self.event.as_ref().map(|event| event.code)
+    }
+
+    /// The requested object does not exist. Responses without an error
+    /// payload are classified by their status code.
+    pub fn is_not_found(&self) -> bool {
+        match self.event_type() {
+            Some(code) => code == models::EventType::NOT_FOUND,
+            None => self.status == Some(404),
+        }
+    }
+
+    /// The credentials were rejected or the caller is not allowed to perform
+    /// the operation.
+    pub fn is_auth_failure(&self) -> bool {
+        match self.event_type() {
+            Some(code) => matches!(
+                code,
+                models::EventType::AUTH_FAILED
+                    | models::EventType::UN_AUTHORIZED
+                    | models::EventType::FORBIDDEN
+            ),
+            None => self.status == Some(401) || self.status == Some(403),
+        }
+    }
+
+    /// The session used for the request is no longer valid and the caller
+    /// needs to authenticate again.
+    pub fn is_session_expired(&self) -> bool {
+        matches!(
+            self.event_type(),
+            Some(models::EventType::INVALID_SESSION) | Some(models::EventType::SESSION_EXPIRED)
+        )
+    }
+
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.event_type() == Some(models::EventType::QUOTA_EXCEEDED)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -6144,7 +7325,56 @@ impl ApiError {
         ApiError {
             message,
             error_type,
+            status: None,
+            headers: Vec::new(),
+            body: None,
+            event: None,
+            timed_out: false,
+        }
+    }
+
+    pub fn new_timeout(message: String) -> ApiError {
+        ApiError {
+            timed_out: true,
+            ..ApiError::new(message, SimpleErrorType::Temporary)
+        }
+    }
+
+    /// Attach the response that caused the error. The body is decoded as an
+    /// `Event` when possible.
+    pub fn with_response(
+        self,
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    ) -> ApiError {
+        let event = serde_json::from_slice::<models::Event>(&body).ok();
+        ApiError {
+            status: Some(status),
+            headers,
+            body: Some(body),
+            event,
+            ..self
+        }
+    }
+}
+
+// The raw headers and body are already part of the message.
+impl fmt::Debug for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("ApiError");
+        debug.field("message", &self.message);
+        debug.field("error_type", &self.error_type);
+        if self.timed_out {
+            debug.field("timed_out", &true);
         }
+        if let Some(status) = self.status {
+            debug.field("status", &status);
+        }
+        if let Some(ref event) = self.event {
+            debug.field("event", event);
+        }
+        debug.finish()
     }
 }
 
@@ -6227,9 +7457,25 @@ impl ::std::fmt::Display for ErrorType {
 }
 
 /// Describes SHA256 hash sum in byte format
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Sha256Hash([u8; SHA256_BYTE_LENGTH]);
 
+impl From<[u8; SHA256_BYTE_LENGTH]> for Sha256Hash {
+    fn from(bytes: [u8; SHA256_BYTE_LENGTH]) -> Self {
+        Sha256Hash(bytes)
+    }
+}
+
+/// Formats the hash as lowercase hex, the form accepted by `try_from`.
+impl fmt::Display for Sha256Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<&str> for Sha256Hash {
     type Error = String;
 
@@ -6267,8 +7513,11 @@ impl Deref for Sha256Hash {
 
 #[cfg(test)]
 mod tests {
+    use models::EventType;
     use std::convert::TryFrom;
+    use ApiError;
     use Sha256Hash;
+    use SimpleErrorType;
     use SHA256_BYTE_LENGTH;
 
     #[test]
@@ -6287,6 +7536,7 @@ mod tests {
                 0x78, 0x52, 0xb8, 0x55
             ]
         );
+        assert_eq!(hash.to_string(), valid_sha256);
     }
 
     #[test]
@@ -6312,4 +7562,35 @@ mod tests {
 
         assert!(hash.is_err());
     }
+
+    #[test]
+    fn test_api_error_classification() {
+        let error = |status: u16, body: &str| {
+            ApiError::new("error".to_owned(), SimpleErrorType::Permanent).with_response(
+                status,
+                Vec::new(),
+                body.as_bytes().to_vec(),
+            )
+        };
+
+        let expired = error(401, r#"{"message":"expired","code":"SESSION_EXPIRED"}"#);
+        assert_eq!(expired.event_type(), Some(EventType::SESSION_EXPIRED));
+        assert!(expired.is_session_expired());
+        assert!(!expired.is_auth_failure());
+
+        let invalid = error(401, r#"{"message":"invalid","code":"INVALID_SESSION"}"#);
+        assert!(invalid.is_session_expired());
+
+        let auth_failed = error(401, r#"{"message":"bad password","code":"AUTH_FAILED"}"#);
+        assert!(auth_failed.is_auth_failure());
+
+        let quota = error(400, r#"{"message":"too many","code":"QUOTA_EXCEEDED"}"#);
+        assert!(quota.is_quota_exceeded());
+        assert!(!quota.is_not_found());
+
+        let plain = error(404, "not json");
+        assert!(plain.event().is_none());
+        assert!(plain.is_not_found());
+        assert!(!plain.is_session_expired());
+    }
 }
@@ -124,6 +124,11 @@ pub mod responses {
         pub static ref GET_APPROVAL_REQUEST_RESULT_DETAILS_ABOUT_THE_SPECIFIED_APPROVAL_REQUEST_RESULT: Mime = "application/json".parse().unwrap();
     }
 
+    lazy_static! {
+        /// Create Mime objects for the response content types for AuthDiscover
+        pub static ref AUTH_DISCOVER_: Mime = "application/json".parse().unwrap();
+    }
+
     lazy_static! {
         /// Create Mime objects for the response content types for AuthenticateUser
         pub static ref AUTHENTICATE_USER_: Mime = "application/json".parse().unwrap();
@@ -189,6 +194,11 @@ pub mod responses {
         pub static ref UPDATE_DATASET_: Mime = "application/json".parse().unwrap();
     }
 
+    lazy_static! {
+        /// Create Mime objects for the response content types for GetAllEvents
+        pub static ref GET_ALL_EVENTS_: Mime = "application/json".parse().unwrap();
+    }
+
     lazy_static! {
         /// Create Mime objects for the response content types for GetAllNodes
         pub static ref GET_ALL_NODES_SEARCH_RESULT_FOR_COMPUTE_NODE_OBJECTS: Mime = "application/json".parse().unwrap();
@@ -374,6 +384,11 @@ pub mod responses {
         pub static ref UPDATE_FINAL_WORKFLOW_GRAPH_THE_DATA_FOR_THE_CREATED_VERSION_WITHIN_THE_WORKFLOW: Mime = "application/json".parse().unwrap();
     }
 
+    lazy_static! {
+        /// Create Mime objects for the response content types for CreateZone
+        pub static ref CREATE_ZONE_DETAILS_OF_THE_CREATED_ZONE: Mime = "application/json".parse().unwrap();
+    }
+
     lazy_static! {
         /// Create Mime objects for the response content types for GetZone
         pub static ref GET_ZONE_DETAILS_OF_THE_ZONE: Mime = "application/json".parse().unwrap();
@@ -388,6 +403,11 @@ pub mod responses {
         /// Create Mime objects for the response content types for GetZones
         pub static ref GET_ZONES_DETAILS_OF_ALL_THE_ZONES: Mime = "application/json".parse().unwrap();
     }
+
+    lazy_static! {
+        /// Create Mime objects for the response content types for UpdateZone
+        pub static ref UPDATE_ZONE_DETAILS_OF_THE_UPDATED_ZONE: Mime = "application/json".parse().unwrap();
+    }
 }
 
 pub mod requests {
@@ -438,6 +458,11 @@ pub mod requests {
         pub static ref DENY_APPROVAL_REQUEST: Mime = "application/json".parse().unwrap();
     }
 
+    lazy_static! {
+        /// Create Mime objects for the request content types for AuthDiscover
+        pub static ref AUTH_DISCOVER: Mime = "application/json".parse().unwrap();
+    }
+
     lazy_static! {
         /// Create Mime objects for the request content types for AuthenticateUser
         pub static ref AUTHENTICATE_USER: Mime = "application/json".parse().unwrap();
@@ -572,4 +597,14 @@ pub mod requests {
         /// Create Mime objects for the request content types for UpdateFinalWorkflowGraph
         pub static ref UPDATE_FINAL_WORKFLOW_GRAPH: Mime = "application/json".parse().unwrap();
     }
+
+    lazy_static! {
+        /// Create Mime objects for the request content types for CreateZone
+        pub static ref CREATE_ZONE: Mime = "application/json".parse().unwrap();
+    }
+
+    lazy_static! {
+        /// Create Mime objects for the request content types for UpdateZone
+        pub static ref UPDATE_ZONE: Mime = "application/json".parse().unwrap();
+    }
 }
@@ -1398,6 +1398,70 @@ impl AttestationRequest {
     }
 }
 
+/// An event recorded in the audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
+pub struct AuditEvent {
+    /// Event Id
+    #[serde(rename = "event_id")]
+    pub event_id: uuid::Uuid,
+
+    /// Account the event belongs to
+    #[serde(rename = "acct_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acct_id: Option<uuid::Uuid>,
+
+    #[serde(rename = "actor_type")]
+    pub actor_type: models::EventActorType,
+
+    /// Id of the user or app that caused the event
+    #[serde(rename = "actor_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<uuid::Uuid>,
+
+    #[serde(rename = "action_type")]
+    pub action_type: models::EventActionType,
+
+    #[serde(rename = "severity")]
+    pub severity: models::EventSeverity,
+
+    /// Event Message
+    #[serde(rename = "message")]
+    pub message: String,
+
+    /// Id of the object the event is about, e.g. a node or a build
+    #[serde(rename = "entity_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<uuid::Uuid>,
+
+    /// Event creation time
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+}
+
+impl AuditEvent {
+    pub fn new(
+        event_id: uuid::Uuid,
+        actor_type: models::EventActorType,
+        action_type: models::EventActionType,
+        severity: models::EventSeverity,
+        message: String,
+        created_at: i64,
+    ) -> AuditEvent {
+        AuditEvent {
+            event_id: event_id,
+            acct_id: None,
+            actor_type: actor_type,
+            actor_id: None,
+            action_type: action_type,
+            severity: severity,
+            message: message,
+            entity_id: None,
+            created_at: created_at,
+        }
+    }
+}
+
 /// Credentials for authenticating to a docker registry
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
@@ -3452,6 +3516,26 @@ impl GetAllDatasetsResponse {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
+pub struct GetAllEventsResponse {
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<models::SearchMetadata>,
+
+    #[serde(rename = "items")]
+    pub items: Vec<models::AuditEvent>,
+}
+
+impl GetAllEventsResponse {
+    pub fn new(items: Vec<models::AuditEvent>) -> GetAllEventsResponse {
+        GetAllEventsResponse {
+            metadata: None,
+            items: items,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
 pub struct GetAllFinalWorkflowGraphsResponse {
@@ -5186,6 +5270,41 @@ impl UpdateWorkflowGraph {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
+pub struct UpdateZoneRequest {
+    /// New name of the zone.
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// New description of the zone.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Interval between node agent checkins with the backend, in seconds
+    #[serde(rename = "node_refresh_interval")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_refresh_interval: Option<i64>,
+
+    /// The node agent requests certificate renewal when the certificate's remaining validity is less than this percentage of the original validity
+    #[serde(rename = "node_renewal_threshold")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_renewal_threshold: Option<i32>,
+}
+
+impl UpdateZoneRequest {
+    pub fn new() -> UpdateZoneRequest {
+        UpdateZoneRequest {
+            name: None,
+            description: None,
+            node_refresh_interval: None,
+            node_renewal_threshold: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "conversion", derive(LabelledGeneric))]
 pub struct User {
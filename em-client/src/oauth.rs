@@ -0,0 +1,345 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Logging in through an OAuth identity provider with the authorization
+//! code grant.
+//!
+//! The Enclave Manager tells which identity provider a user logs in with.
+//! The user opens the authorization URL in a browser, logs in to the
+//! provider, and is sent back to the redirect URI of the grant with a code,
+//! which the manager exchanges for a session.
+//!
+//! ```no_run
+//! # extern crate em_client;
+//! # extern crate hyper;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use em_client::oauth::OauthLogin;
+//! use hyper::header::{Authorization, Bearer};
+//!
+//! let mut client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let login = OauthLogin::discover(&client, "user@example.com")?
+//!     .ok_or("The user does not log in with OAuth")?;
+//! println!("Log in at {}", login.authorization_url());
+//! // The URL the browser was sent back to.
+//! let redirected_to = "https://em.example.com/oauth/callback?code=c0de&state=xyz";
+//! let auth = login.finish(&client, redirected_to)?;
+//! let token = auth.access_token.ok_or("No access token")?;
+//! client.headers().set(Authorization(Bearer { token }));
+//! # Ok(())
+//! # }
+//! ```
+use std::error;
+use std::fmt;
+
+use url::form_urlencoded;
+
+use models;
+use Api;
+
+/// Scope requested from the identity provider unless configured otherwise.
+pub const DEFAULT_SCOPE: &str = "openid email profile";
+
+/// Why an OAuth login failed.
+#[derive(Debug)]
+pub enum OauthError<E> {
+    /// The identity provider refused the authorization, e.g. because the
+    /// user denied it.
+    Denied {
+        error: String,
+        description: Option<String>,
+    },
+    /// The `state` the user was redirected with is not the one of the
+    /// login, so the redirect does not belong to it.
+    StateMismatch,
+    /// The redirect does not carry an authorization code.
+    MissingCode,
+    /// Discovering the grant or exchanging the code failed.
+    Api(E),
+}
+
+impl<E: fmt::Display> fmt::Display for OauthError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OauthError::Denied {
+                ref error,
+                description: Some(ref description),
+            } => write!(f, "Authorization denied: {}: {}", error, description),
+            OauthError::Denied { ref error, .. } => write!(f, "Authorization denied: {}", error),
+            OauthError::StateMismatch => write!(f, "Redirected with an unexpected state"),
+            OauthError::MissingCode => write!(f, "Redirected without an authorization code"),
+            OauthError::Api(ref err) => write!(f, "Failed to log in: {}", err),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for OauthError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            OauthError::Api(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An OAuth login of one user, from the authorization URL to the session.
+#[derive(Clone, Debug)]
+pub struct OauthLogin {
+    email: String,
+    grant: models::OauthAuthCodeGrant,
+    scope: String,
+}
+
+impl OauthLogin {
+    pub fn new(email: &str, grant: models::OauthAuthCodeGrant) -> Self {
+        OauthLogin {
+            email: email.to_owned(),
+            grant,
+            scope: DEFAULT_SCOPE.to_owned(),
+        }
+    }
+
+    /// Ask the manager how `email` logs in. Returns `None` if the user does
+    /// not log in through an identity provider.
+    pub fn discover<A: Api + ?Sized>(api: &A, email: &str) -> Result<Option<Self>, A::Error> {
+        let request = models::AuthDiscoverRequest {
+            user_email: Some(email.to_owned()),
+        };
+        let response = api.auth_discover(request)?;
+        Ok(response
+            .auth_methods
+            .into_iter()
+            .filter_map(|method| method.oauth_code_grant)
+            .next()
+            .map(|grant| OauthLogin::new(email, grant)))
+    }
+
+    /// Request `scope` instead of `DEFAULT_SCOPE`.
+    pub fn with_scope(self, scope: &str) -> Self {
+        OauthLogin {
+            scope: scope.to_owned(),
+            ..self
+        }
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn grant(&self) -> &models::OauthAuthCodeGrant {
+        &self.grant
+    }
+
+    /// The URL of the identity provider the user logs in at.
+    pub fn authorization_url(&self) -> String {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.grant.client_id)
+            .append_pair("redirect_uri", &self.grant.redirect_uri)
+            .append_pair("scope", &self.scope)
+            .append_pair("state", &self.grant.state)
+            .finish();
+        let separator = match self.grant.authorization_url.contains('?') {
+            true => '&',
+            false => '?',
+        };
+        format!("{}{}{}", self.grant.authorization_url, separator, query)
+    }
+
+    /// Check the URL the user was redirected to, or just its query, and
+    /// exchange the code it carries for a session.
+    pub fn finish<A: Api + ?Sized>(
+        &self,
+        api: &A,
+        redirect: &str,
+    ) -> Result<models::AuthResponse, OauthError<A::Error>> {
+        let code = self.code(redirect)?;
+        self.exchange(api, code).map_err(OauthError::Api)
+    }
+
+    /// Exchange an authorization code for a session. The code must come from
+    /// a redirect with the `state` of the login.
+    pub fn exchange<A: Api + ?Sized>(
+        &self,
+        api: &A,
+        code: String,
+    ) -> Result<models::AuthResponse, A::Error> {
+        let data = models::OauthCodeData::new(self.grant.idp_id.clone(), code, self.email.clone());
+        api.authenticate_user(Some(models::AuthRequest::new(data)))
+    }
+
+    fn code<E>(&self, redirect: &str) -> Result<String, OauthError<E>> {
+        let query = redirect.split('#').next().unwrap_or_default();
+        let query = match query.find('?') {
+            Some(start) => &query[start + 1..],
+            None => query,
+        };
+        let (mut code, mut state, mut error, mut description) = (None, None, None, None);
+        for (name, value) in form_urlencoded::parse(query.as_bytes()) {
+            match &*name {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                "error_description" => description = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+        // Errors are only reported for redirects of this login.
+        if state.as_deref() != Some(&*self.grant.state) {
+            return Err(OauthError::StateMismatch);
+        }
+        if let Some(error) = error {
+            return Err(OauthError::Denied { error, description });
+        }
+        code.filter(|code| !code.is_empty())
+            .ok_or(OauthError::MissingCode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OauthError, OauthLogin};
+    use client::Client;
+    use hyper::client::RedirectPolicy;
+    use hyper::header::Location;
+    use models;
+    use serde_json::json;
+    use ByteArray;
+
+    fn grant(authorization_url: &str) -> models::OauthAuthCodeGrant {
+        models::OauthAuthCodeGrant::new(
+            "Example IdP".to_owned(),
+            "https://idp.example.com/icon.png".to_owned(),
+            authorization_url.to_owned(),
+            "em-client".to_owned(),
+            "https://em.example.com/oauth/callback".to_owned(),
+            "xyz".to_owned(),
+            ByteArray(vec![1, 2, 3]),
+        )
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let login = OauthLogin::new("user@example.com", grant("https://idp.example.com/auth"));
+        assert_eq!(
+            login.authorization_url(),
+            "https://idp.example.com/auth?response_type=code&client_id=em-client\
+             &redirect_uri=https%3A%2F%2Fem.example.com%2Foauth%2Fcallback\
+             &scope=openid+email+profile&state=xyz"
+        );
+        let login = OauthLogin::new(
+            "user@example.com",
+            grant("https://idp.example.com/auth?x=1"),
+        )
+        .with_scope("openid");
+        assert!(login
+            .authorization_url()
+            .starts_with("https://idp.example.com/auth?x=1&response_type=code&"));
+    }
+
+    #[test]
+    fn test_login_through_identity_provider() {
+        let mut manager = mockito::Server::new();
+        let mut idp = mockito::Server::new();
+        let client = Client::try_new_http(&manager.url()).unwrap();
+
+        let discover = manager
+            .mock("POST", "/v1/sys/auth/discover")
+            .match_body(mockito::Matcher::Json(
+                json!({"user_email": "user@example.com"}),
+            ))
+            .with_body(
+                json!({"auth_methods": [
+                    {"password": {}},
+                    {"oauth_code_grant": grant(&format!("{}/authorize", idp.url()))},
+                ]})
+                .to_string(),
+            )
+            .create();
+        // The identity provider stand-in sends the user back with a code.
+        let authorize = idp
+            .mock("GET", "/authorize")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("response_type".into(), "code".into()),
+                mockito::Matcher::UrlEncoded("client_id".into(), "em-client".into()),
+                mockito::Matcher::UrlEncoded(
+                    "redirect_uri".into(),
+                    "https://em.example.com/oauth/callback".into(),
+                ),
+                mockito::Matcher::UrlEncoded("state".into(), "xyz".into()),
+            ]))
+            .with_status(302)
+            .with_header(
+                "location",
+                "https://em.example.com/oauth/callback?code=c0de&state=xyz",
+            )
+            .create();
+        let auth = manager
+            .mock("POST", "/v1/sys/auth")
+            .match_body(mockito::Matcher::Json(json!({"oauth_auth_code": {
+                "idp_id": "AQID",
+                "code": "c0de",
+                "email": "user@example.com",
+            }})))
+            .with_body(r#"{"access_token":"session","expires_in":3600}"#)
+            .create();
+
+        let login = OauthLogin::discover(&client, "user@example.com")
+            .unwrap()
+            .unwrap();
+        let mut browser = hyper::Client::new();
+        browser.set_redirect_policy(RedirectPolicy::FollowNone);
+        let response = browser.get(&login.authorization_url()).send().unwrap();
+        let redirect = response.headers.get::<Location>().unwrap();
+        let response = login.finish(&client, redirect).unwrap();
+        assert_eq!(response.access_token, Some("session".to_owned()));
+
+        discover.assert();
+        authorize.assert();
+        auth.assert();
+    }
+
+    #[test]
+    fn test_redirect_validation() {
+        let mut manager = mockito::Server::new();
+        let client = Client::try_new_http(&manager.url()).unwrap();
+        let auth = manager.mock("POST", "/v1/sys/auth").expect(0).create();
+        let login = OauthLogin::new("user@example.com", grant("https://idp.example.com/auth"));
+
+        let finish = |redirect| login.finish(&client, redirect).unwrap_err();
+        match finish("https://em.example.com/oauth/callback?code=c0de&state=abc") {
+            OauthError::StateMismatch => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        match finish("code=c0de") {
+            OauthError::StateMismatch => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        match finish("?error=access_denied&error_description=User+cancelled&state=xyz") {
+            OauthError::Denied { error, description } => {
+                assert_eq!(error, "access_denied");
+                assert_eq!(description, Some("User cancelled".to_owned()));
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+        match finish("https://em.example.com/oauth/callback?state=xyz#code=c0de") {
+            OauthError::MissingCode => {}
+            err => panic!("unexpected error {:?}", err),
+        }
+        auth.assert();
+    }
+
+    #[test]
+    fn test_discover_without_identity_provider() {
+        let mut manager = mockito::Server::new();
+        let client = Client::try_new_http(&manager.url()).unwrap();
+        manager
+            .mock("POST", "/v1/sys/auth/discover")
+            .with_body(r#"{"auth_methods":[{"password":{}}]}"#)
+            .create();
+        assert!(OauthLogin::discover(&client, "admin@example.com")
+            .unwrap()
+            .is_none());
+    }
+}
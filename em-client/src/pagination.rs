@@ -0,0 +1,397 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Iterators over the paginated `get_all_*` listings.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), em_client::ApiError> {
+//! use em_client::models::NodeStatusType;
+//! use em_client::pagination::PaginatedApi;
+//! use em_client::query::NodeQuery;
+//!
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let query = NodeQuery::new().status(NodeStatusType::RUNNING);
+//! for node in client.iter_nodes(query).with_page_size(20) {
+//!     println!("{}", node?.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::VecDeque;
+use std::fmt;
+
+use models;
+use query::{
+    AppQuery, ApplicationConfigQuery, ApprovalRequestQuery, BuildDeploymentQuery, BuildQuery,
+    DatasetQuery, EventQuery, FinalWorkflowGraphQuery, NodeQuery, QueryApi, TaskQuery, UserQuery,
+    WorkflowGraphQuery,
+};
+use uuid;
+use Api;
+
+/// Number of items requested per page unless configured otherwise.
+pub const DEFAULT_PAGE_SIZE: i32 = 50;
+
+type FetchPage<'a, T, E> =
+    Box<dyn FnMut(i32, i32) -> Result<(Option<models::SearchMetadata>, Vec<T>), E> + 'a>;
+
+/// Yields the items of a paginated listing one at a time, fetching pages
+/// lazily as the iterator advances.
+///
+/// The listing ends once the server has returned `filtered_count` items, or,
+/// if it does not return `SearchMetadata`, at the first page shorter than the
+/// page size. Iteration stops after the first error.
+pub struct Paginated<'a, T, E> {
+    fetch: FetchPage<'a, T, E>,
+    page_size: i32,
+    max_items: Option<usize>,
+    offset: i32,
+    yielded: usize,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+impl<'a, T, E> fmt::Debug for Paginated<'a, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Paginated")
+            .field("page_size", &self.page_size)
+            .field("max_items", &self.max_items)
+            .field("offset", &self.offset)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<'a, T, E> Paginated<'a, T, E> {
+    /// Paginate over a listing. `fetch` is called with the limit and offset
+    /// of each page and returns the items of the page along with the search
+    /// metadata, if any.
+    pub fn new<F>(fetch: F) -> Self
+    where
+        F: FnMut(i32, i32) -> Result<(Option<models::SearchMetadata>, Vec<T>), E> + 'a,
+    {
+        Paginated {
+            fetch: Box::new(fetch),
+            page_size: DEFAULT_PAGE_SIZE,
+            max_items: None,
+            offset: 0,
+            yielded: 0,
+            buffer: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Number of items to request per page.
+    pub fn with_page_size(self, page_size: i32) -> Self {
+        Paginated {
+            page_size: page_size.max(1),
+            ..self
+        }
+    }
+
+    /// Stop after yielding `max_items` items. Unlike `Iterator::take`, the
+    /// last page is shortened so that no more items than needed are fetched.
+    pub fn with_max_items(self, max_items: usize) -> Self {
+        Paginated {
+            max_items: Some(max_items),
+            ..self
+        }
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        self.max_items.map(|max| max.saturating_sub(self.yielded))
+    }
+
+    fn fetch_page(&mut self) -> Result<(), E> {
+        let limit = match self.remaining() {
+            Some(remaining) if remaining < self.page_size as usize => remaining as i32,
+            _ => self.page_size,
+        };
+        let (metadata, items) = (self.fetch)(limit, self.offset)?;
+        let received = items.len() as i32;
+        self.offset += received;
+        self.done = received == 0
+            || match metadata {
+                // The server may return fewer items than requested, so only
+                // the total tells whether more pages follow.
+                Some(ref metadata) => self.offset as isize >= metadata.filtered_count,
+                None => received < limit,
+            };
+        self.buffer.extend(items);
+        Ok(())
+    }
+}
+
+impl<'a, T, E> Iterator for Paginated<'a, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining() == Some(0) {
+            return None;
+        }
+        if self.buffer.is_empty() && !self.done {
+            if let Err(err) = self.fetch_page() {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+        let item = self.buffer.pop_front()?;
+        self.yielded += 1;
+        Some(Ok(item))
+    }
+}
+
+/// Iterators over the `get_all_*` listings of any `Api` implementation.
+///
+/// The limit and offset of the query are ignored; use
+/// `Paginated::with_page_size` and `Paginated::with_max_items` instead.
+pub trait PaginatedApi: Api {
+    fn iter_apps(&self, query: AppQuery) -> Paginated<'_, models::App, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_apps(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_application_configs(
+        &self,
+        query: ApplicationConfigQuery,
+    ) -> Paginated<'_, models::ApplicationConfigResponse, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_application_configs(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_approval_requests(
+        &self,
+        query: ApprovalRequestQuery,
+    ) -> Paginated<'_, models::ApprovalRequest, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_approval_requests(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_builds(&self, query: BuildQuery) -> Paginated<'_, models::Build, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_builds(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_build_deployments(
+        &self,
+        build_id: uuid::Uuid,
+        query: BuildDeploymentQuery,
+    ) -> Paginated<'_, models::AppNodeInfo, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_build_deployments(build_id, &page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_datasets(&self, query: DatasetQuery) -> Paginated<'_, models::Dataset, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_datasets(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_events(&self, query: EventQuery) -> Paginated<'_, models::AuditEvent, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_events(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_nodes(&self, query: NodeQuery) -> Paginated<'_, models::Node, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_nodes(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_tasks(&self, query: TaskQuery) -> Paginated<'_, models::Task, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_tasks(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_users(&self, query: UserQuery) -> Paginated<'_, models::User, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_users(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_workflow_graphs(
+        &self,
+        query: WorkflowGraphQuery,
+    ) -> Paginated<'_, models::WorkflowGraph, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_workflow_graphs(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+
+    fn iter_final_workflow_graphs(
+        &self,
+        query: FinalWorkflowGraphQuery,
+    ) -> Paginated<'_, models::FinalWorkflow, Self::Error> {
+        Paginated::new(move |limit, offset| {
+            let page = query.clone().limit(limit).offset(offset);
+            self.query_final_workflow_graphs(&page)
+                .map(|response| (response.metadata, response.items))
+        })
+    }
+}
+
+impl<T: Api + ?Sized> PaginatedApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Paginated, PaginatedApi};
+    use models::SearchMetadata;
+    use query::UserQuery;
+    use std::cell::RefCell;
+
+    /// A listing of `total` numbers that returns at most `max_limit` items
+    /// per page, recording the requested limits and offsets.
+    fn listing<'a>(
+        total: i32,
+        max_limit: i32,
+        metadata: bool,
+        requests: &'a RefCell<Vec<(i32, i32)>>,
+    ) -> Paginated<'a, i32, String> {
+        Paginated::new(move |limit, offset| {
+            requests.borrow_mut().push((limit, offset));
+            let items: Vec<i32> = (offset..total)
+                .take(limit.min(max_limit) as usize)
+                .collect();
+            let metadata = match metadata {
+                true => Some(SearchMetadata::new(
+                    0,
+                    0,
+                    limit as isize,
+                    total as isize,
+                    total as isize,
+                )),
+                false => None,
+            };
+            Ok((metadata, items))
+        })
+    }
+
+    #[test]
+    fn test_pages_are_fetched_lazily() {
+        let requests = RefCell::new(Vec::new());
+        let mut items = listing(5, 100, true, &requests).with_page_size(2);
+        assert_eq!(items.next(), Some(Ok(0)));
+        assert_eq!(*requests.borrow(), vec![(2, 0)]);
+
+        assert_eq!(items.collect::<Result<Vec<_>, _>>(), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(*requests.borrow(), vec![(2, 0), (2, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn test_short_pages_do_not_end_the_listing() {
+        let requests = RefCell::new(Vec::new());
+        let items: Vec<_> = listing(5, 2, true, &requests).with_page_size(10).collect();
+        assert_eq!(items.len(), 5);
+        assert_eq!(*requests.borrow(), vec![(10, 0), (10, 2), (10, 4)]);
+
+        // Without metadata a short page is the last one.
+        requests.borrow_mut().clear();
+        let items: Vec<_> = listing(5, 100, false, &requests)
+            .with_page_size(2)
+            .collect();
+        assert_eq!(items.len(), 5);
+        assert_eq!(*requests.borrow(), vec![(2, 0), (2, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn test_max_items() {
+        let requests = RefCell::new(Vec::new());
+        let items: Vec<_> = listing(10, 100, true, &requests)
+            .with_page_size(2)
+            .with_max_items(3)
+            .collect();
+        assert_eq!(items, vec![Ok(0), Ok(1), Ok(2)]);
+        assert_eq!(*requests.borrow(), vec![(2, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_iteration_stops_after_error() {
+        let mut calls = 0;
+        let mut items = Paginated::<i32, _>::new(|_, _| {
+            calls += 1;
+            Err("unavailable".to_owned())
+        });
+        assert_eq!(items.next(), Some(Err("unavailable".to_owned())));
+        assert_eq!(items.next(), None);
+        drop(items);
+        assert_eq!(calls, 1);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_iter_users() {
+        let mut server = mockito::Server::new();
+        let user = |n: i32| {
+            format!(
+                r#"{{"user_id":"00000000-0000-0000-0000-00000000000{}","user_email":"user{}@example.com"}}"#,
+                n, n
+            )
+        };
+        let mut page = |offset: &str, items: String| {
+            server
+                .mock("GET", "/v1/users")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("all_search".to_owned(), "example".to_owned()),
+                    mockito::Matcher::UrlEncoded("limit".to_owned(), "2".to_owned()),
+                    mockito::Matcher::UrlEncoded("offset".to_owned(), offset.to_owned()),
+                ]))
+                .with_body(format!(
+                    r#"{{"metadata":{{"page":1,"pages":2,"limit":2,"total_count":3,"filtered_count":3}},"items":[{}]}}"#,
+                    items
+                ))
+                .create()
+        };
+        let first = page("0", format!("{},{}", user(1), user(2)));
+        let second = page("2", user(3));
+
+        let client = ::client::Client::try_new_http(&server.url()).unwrap();
+        let query = UserQuery::new().all_search("example");
+        let emails = client
+            .iter_users(query)
+            .with_page_size(2)
+            .map(|user| user.map(|user| user.user_email))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            emails,
+            vec![
+                "user1@example.com",
+                "user2@example.com",
+                "user3@example.com"
+            ]
+        );
+        first.assert();
+        second.assert();
+    }
+}
@@ -0,0 +1,1189 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Typed filters for the `get_all_*` listings.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), em_client::ApiError> {
+//! use em_client::models::{TaskStatusType, TaskType};
+//! use em_client::query::{QueryApi, SortOrder, TaskQuery, TaskSortField};
+//!
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! let query = TaskQuery::new()
+//!     .task_type(TaskType::BUILD_WHITELIST)
+//!     .status(TaskStatusType::INPROGRESS)
+//!     .sort_by(TaskSortField::Status, SortOrder::Descending)
+//!     .limit(10);
+//! let tasks = client.query_tasks(&query)?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use models;
+use uuid;
+use Api;
+
+/// Direction in which a listing is sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SortOrder::Ascending => write!(f, "asc"),
+            SortOrder::Descending => write!(f, "desc"),
+        }
+    }
+}
+
+/// A field by which a listing can be sorted.
+pub trait SortField: Copy {
+    /// Name of the field in the `sort_by` query parameter.
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! sort_field {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident => $field:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl SortField for $name {
+            fn name(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $field,)*
+                }
+            }
+        }
+    };
+}
+
+sort_field!(
+    /// Fields by which apps can be sorted.
+    AppSortField {
+        Name => "name",
+        CreatedAt => "created_at",
+        UpdatedAt => "updated_at",
+    }
+);
+
+sort_field!(
+    /// Fields by which approval requests can be sorted.
+    ApprovalRequestSortField {
+        CreatedAt => "created_at",
+        Expiry => "expiry",
+        Status => "status",
+    }
+);
+
+sort_field!(
+    /// Fields by which builds can be sorted.
+    BuildSortField {
+        AppName => "app_name",
+        CreatedAt => "created_at",
+        UpdatedAt => "updated_at",
+        Status => "status",
+    }
+);
+
+sort_field!(
+    /// Fields by which build deployments can be sorted.
+    BuildDeploymentSortField {
+        NodeName => "node_name",
+        CreatedAt => "created_at",
+        Status => "status",
+    }
+);
+
+sort_field!(
+    /// Fields by which events can be sorted.
+    EventSortField {
+        CreatedAt => "created_at",
+        Severity => "severity",
+    }
+);
+
+sort_field!(
+    /// Fields by which nodes can be sorted.
+    NodeSortField {
+        Name => "name",
+        Status => "status",
+        AttestedAt => "attested_at",
+    }
+);
+
+sort_field!(
+    /// Fields by which tasks can be sorted.
+    TaskSortField {
+        TaskType => "task_type",
+        Status => "status",
+    }
+);
+
+sort_field!(
+    /// Fields by which users can be sorted.
+    UserSortField {
+        UserEmail => "user_email",
+        CreatedAt => "created_at",
+        LastLoggedInAt => "last_logged_in_at",
+    }
+);
+
+sort_field!(
+    /// Fields by which workflow graphs, draft or final, can be sorted.
+    WorkflowGraphSortField {
+        Name => "name",
+        CreatedAt => "created_at",
+        UpdatedAt => "updated_at",
+    }
+);
+
+/// Value of the `sort_by` query parameter, e.g. `created_at:desc`.
+fn sort_by<F: SortField>(sort: Option<(F, SortOrder)>) -> Option<String> {
+    sort.map(|(field, order)| format!("{}:{}", field.name(), order))
+}
+
+fn string<T: ToString>(value: Option<T>) -> Option<String> {
+    value.map(|value| value.to_string())
+}
+
+/// Collects the query parameters of a listing, skipping unset ones.
+#[derive(Default)]
+struct Pairs(Vec<(&'static str, String)>);
+
+impl Pairs {
+    fn push<T: ToString>(mut self, name: &'static str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.0.push((name, value.to_string()));
+        }
+        self
+    }
+}
+
+#[cfg(feature = "client")]
+fn to_query_string(pairs: Vec<(&'static str, String)>) -> String {
+    use url::form_urlencoded::Serializer;
+    let mut serializer = Serializer::new(String::new());
+    for (name, value) in pairs {
+        serializer.append_pair(name, &value);
+    }
+    serializer.finish()
+}
+
+macro_rules! query_string {
+    () => {
+        /// The query parameters in the form sent by `Client`.
+        #[cfg(feature = "client")]
+        pub fn to_query_string(&self) -> String {
+            to_query_string(self.pairs())
+        }
+
+        /// Maximum number of items to return.
+        pub fn limit(self, limit: i32) -> Self {
+            Self {
+                limit: Some(limit),
+                ..self
+            }
+        }
+
+        /// Number of items to skip.
+        pub fn offset(self, offset: i32) -> Self {
+            Self {
+                offset: Some(offset),
+                ..self
+            }
+        }
+    };
+}
+
+/// Filters for `QueryApi::query_apps`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AppQuery {
+    name: Option<String>,
+    description: Option<String>,
+    all_search: Option<String>,
+    sort: Option<(AppSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl AppQuery {
+    pub fn new() -> Self {
+        AppQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        AppQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        AppQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        AppQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: AppSortField, order: SortOrder) -> Self {
+        AppQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("all_search", self.all_search.as_ref())
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_application_configs`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ApplicationConfigQuery {
+    name: Option<String>,
+    description: Option<String>,
+    image_id: Option<uuid::Uuid>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl ApplicationConfigQuery {
+    pub fn new() -> Self {
+        ApplicationConfigQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        ApplicationConfigQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        ApplicationConfigQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn image_id(self, image_id: uuid::Uuid) -> Self {
+        ApplicationConfigQuery {
+            image_id: Some(image_id),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("image_id", self.image_id)
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_approval_requests`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ApprovalRequestQuery {
+    requester: Option<uuid::Uuid>,
+    reviewer: Option<uuid::Uuid>,
+    subject: Option<uuid::Uuid>,
+    status: Option<models::ApprovalRequestStatus>,
+    all_search: Option<String>,
+    sort: Option<(ApprovalRequestSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl ApprovalRequestQuery {
+    pub fn new() -> Self {
+        ApprovalRequestQuery::default()
+    }
+
+    pub fn requester(self, requester: uuid::Uuid) -> Self {
+        ApprovalRequestQuery {
+            requester: Some(requester),
+            ..self
+        }
+    }
+
+    pub fn reviewer(self, reviewer: uuid::Uuid) -> Self {
+        ApprovalRequestQuery {
+            reviewer: Some(reviewer),
+            ..self
+        }
+    }
+
+    pub fn subject(self, subject: uuid::Uuid) -> Self {
+        ApprovalRequestQuery {
+            subject: Some(subject),
+            ..self
+        }
+    }
+
+    pub fn status(self, status: models::ApprovalRequestStatus) -> Self {
+        ApprovalRequestQuery {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        ApprovalRequestQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: ApprovalRequestSortField, order: SortOrder) -> Self {
+        ApprovalRequestQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("requester", self.requester)
+            .push("reviewer", self.reviewer)
+            .push("subject", self.subject)
+            .push("status", self.status)
+            .push("all_search", self.all_search.as_ref())
+            .push("sort_by", sort_by(self.sort))
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_builds`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BuildQuery {
+    all_search: Option<String>,
+    docker_image_name: Option<String>,
+    config_id: Option<String>,
+    deployed_status: Option<models::BuildDeploymentStatusType>,
+    status: Option<models::BuildStatusType>,
+    sort: Option<(BuildSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl BuildQuery {
+    pub fn new() -> Self {
+        BuildQuery::default()
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        BuildQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn docker_image_name(self, docker_image_name: &str) -> Self {
+        BuildQuery {
+            docker_image_name: Some(docker_image_name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn config_id(self, config_id: &str) -> Self {
+        BuildQuery {
+            config_id: Some(config_id.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn deployed_status(self, deployed_status: models::BuildDeploymentStatusType) -> Self {
+        BuildQuery {
+            deployed_status: Some(deployed_status),
+            ..self
+        }
+    }
+
+    pub fn status(self, status: models::BuildStatusType) -> Self {
+        BuildQuery {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: BuildSortField, order: SortOrder) -> Self {
+        BuildQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("all_search", self.all_search.as_ref())
+            .push("docker_image_name", self.docker_image_name.as_ref())
+            .push("config_id", self.config_id.as_ref())
+            .push("deployed_status", self.deployed_status)
+            .push("status", self.status)
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_build_deployments`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BuildDeploymentQuery {
+    status: Option<models::AppStatusType>,
+    all_search: Option<String>,
+    sort: Option<(BuildDeploymentSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl BuildDeploymentQuery {
+    pub fn new() -> Self {
+        BuildDeploymentQuery::default()
+    }
+
+    pub fn status(self, status: models::AppStatusType) -> Self {
+        BuildDeploymentQuery {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        BuildDeploymentQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: BuildDeploymentSortField, order: SortOrder) -> Self {
+        BuildDeploymentQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("status", self.status)
+            .push("all_search", self.all_search.as_ref())
+            .push("sort_by", sort_by(self.sort))
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_datasets`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DatasetQuery {
+    name: Option<String>,
+    description: Option<String>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl DatasetQuery {
+    pub fn new() -> Self {
+        DatasetQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        DatasetQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        DatasetQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_events`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventQuery {
+    actor_type: Option<models::EventActorType>,
+    action_type: Option<models::EventActionType>,
+    severity: Option<models::EventSeverity>,
+    entity_id: Option<uuid::Uuid>,
+    from: Option<i64>,
+    to: Option<i64>,
+    sort: Option<(EventSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl EventQuery {
+    pub fn new() -> Self {
+        EventQuery::default()
+    }
+
+    pub fn actor_type(self, actor_type: models::EventActorType) -> Self {
+        EventQuery {
+            actor_type: Some(actor_type),
+            ..self
+        }
+    }
+
+    pub fn action_type(self, action_type: models::EventActionType) -> Self {
+        EventQuery {
+            action_type: Some(action_type),
+            ..self
+        }
+    }
+
+    pub fn severity(self, severity: models::EventSeverity) -> Self {
+        EventQuery {
+            severity: Some(severity),
+            ..self
+        }
+    }
+
+    /// Events about one object, e.g. a node or a build.
+    pub fn entity_id(self, entity_id: uuid::Uuid) -> Self {
+        EventQuery {
+            entity_id: Some(entity_id),
+            ..self
+        }
+    }
+
+    /// Events created at or after `from`, in seconds since the epoch.
+    pub fn from(self, from: i64) -> Self {
+        EventQuery {
+            from: Some(from),
+            ..self
+        }
+    }
+
+    /// Events created at or before `to`, in seconds since the epoch.
+    pub fn to(self, to: i64) -> Self {
+        EventQuery {
+            to: Some(to),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: EventSortField, order: SortOrder) -> Self {
+        EventQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("actor_type", self.actor_type)
+            .push("action_type", self.action_type)
+            .push("severity", self.severity)
+            .push("entity_id", self.entity_id)
+            .push("from", self.from)
+            .push("to", self.to)
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_nodes`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeQuery {
+    name: Option<String>,
+    description: Option<String>,
+    sgx_version: Option<String>,
+    all_search: Option<String>,
+    status: Option<models::NodeStatusType>,
+    sort: Option<(NodeSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl NodeQuery {
+    pub fn new() -> Self {
+        NodeQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        NodeQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        NodeQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sgx_version(self, sgx_version: &str) -> Self {
+        NodeQuery {
+            sgx_version: Some(sgx_version.to_owned()),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        NodeQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn status(self, status: models::NodeStatusType) -> Self {
+        NodeQuery {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: NodeSortField, order: SortOrder) -> Self {
+        NodeQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("sgx_version", self.sgx_version.as_ref())
+            .push("all_search", self.all_search.as_ref())
+            .push("status", self.status)
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_tasks`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskQuery {
+    task_type: Option<models::TaskType>,
+    status: Option<models::TaskStatusType>,
+    requester: Option<String>,
+    approver: Option<String>,
+    all_search: Option<String>,
+    sort: Option<(TaskSortField, SortOrder)>,
+    base_filters: Option<String>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl TaskQuery {
+    pub fn new() -> Self {
+        TaskQuery::default()
+    }
+
+    pub fn task_type(self, task_type: models::TaskType) -> Self {
+        TaskQuery {
+            task_type: Some(task_type),
+            ..self
+        }
+    }
+
+    pub fn status(self, status: models::TaskStatusType) -> Self {
+        TaskQuery {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    pub fn requester(self, requester: &str) -> Self {
+        TaskQuery {
+            requester: Some(requester.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn approver(self, approver: &str) -> Self {
+        TaskQuery {
+            approver: Some(approver.to_owned()),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        TaskQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: TaskSortField, order: SortOrder) -> Self {
+        TaskQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    pub fn base_filters(self, base_filters: &str) -> Self {
+        TaskQuery {
+            base_filters: Some(base_filters.to_owned()),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("task_type", self.task_type)
+            .push("status", self.status)
+            .push("requester", self.requester.as_ref())
+            .push("approver", self.approver.as_ref())
+            .push("all_search", self.all_search.as_ref())
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .push("base_filters", self.base_filters.as_ref())
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_users`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserQuery {
+    all_search: Option<String>,
+    sort: Option<(UserSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl UserQuery {
+    pub fn new() -> Self {
+        UserQuery::default()
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        UserQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: UserSortField, order: SortOrder) -> Self {
+        UserQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("all_search", self.all_search.as_ref())
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .push("sort_by", sort_by(self.sort))
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_workflow_graphs`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkflowGraphQuery {
+    name: Option<String>,
+    description: Option<String>,
+    all_search: Option<String>,
+    parent_graph_id: Option<uuid::Uuid>,
+    sort: Option<(WorkflowGraphSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl WorkflowGraphQuery {
+    pub fn new() -> Self {
+        WorkflowGraphQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        WorkflowGraphQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        WorkflowGraphQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        WorkflowGraphQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn parent_graph_id(self, parent_graph_id: uuid::Uuid) -> Self {
+        WorkflowGraphQuery {
+            parent_graph_id: Some(parent_graph_id),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: WorkflowGraphSortField, order: SortOrder) -> Self {
+        WorkflowGraphQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("all_search", self.all_search.as_ref())
+            .push("parent_graph_id", self.parent_graph_id)
+            .push("sort_by", sort_by(self.sort))
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// Filters for `QueryApi::query_final_workflow_graphs`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FinalWorkflowGraphQuery {
+    name: Option<String>,
+    description: Option<String>,
+    all_search: Option<String>,
+    sort: Option<(WorkflowGraphSortField, SortOrder)>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+impl FinalWorkflowGraphQuery {
+    pub fn new() -> Self {
+        FinalWorkflowGraphQuery::default()
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        FinalWorkflowGraphQuery {
+            name: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn description(self, description: &str) -> Self {
+        FinalWorkflowGraphQuery {
+            description: Some(description.to_owned()),
+            ..self
+        }
+    }
+
+    /// Match any searchable field.
+    pub fn all_search(self, all_search: &str) -> Self {
+        FinalWorkflowGraphQuery {
+            all_search: Some(all_search.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn sort_by(self, field: WorkflowGraphSortField, order: SortOrder) -> Self {
+        FinalWorkflowGraphQuery {
+            sort: Some((field, order)),
+            ..self
+        }
+    }
+
+    query_string!();
+
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        Pairs::default()
+            .push("name", self.name.as_ref())
+            .push("description", self.description.as_ref())
+            .push("all_search", self.all_search.as_ref())
+            .push("sort_by", sort_by(self.sort))
+            .push("limit", self.limit)
+            .push("offset", self.offset)
+            .0
+    }
+}
+
+/// The `get_all_*` listings of any `Api` implementation, filtered by a
+/// typed query instead of positional arguments.
+pub trait QueryApi: Api {
+    fn query_apps(&self, query: &AppQuery) -> Result<models::GetAllAppsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_apps(
+            q.name,
+            q.description,
+            q.all_search,
+            q.limit,
+            q.offset,
+            sort_by(q.sort),
+        )
+    }
+
+    fn query_application_configs(
+        &self,
+        query: &ApplicationConfigQuery,
+    ) -> Result<models::GetAllApplicationConfigsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_application_configs(q.name, q.description, q.image_id, q.limit, q.offset)
+    }
+
+    fn query_approval_requests(
+        &self,
+        query: &ApprovalRequestQuery,
+    ) -> Result<models::GetAllApprovalRequests, Self::Error> {
+        let q = query.clone();
+        self.get_all_approval_requests(
+            q.requester,
+            q.reviewer,
+            q.subject,
+            string(q.status),
+            q.all_search,
+            sort_by(q.sort),
+            q.limit,
+            q.offset,
+        )
+    }
+
+    fn query_builds(
+        &self,
+        query: &BuildQuery,
+    ) -> Result<models::GetAllBuildsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_builds(
+            q.all_search,
+            q.docker_image_name,
+            q.config_id,
+            string(q.deployed_status),
+            string(q.status),
+            q.limit,
+            q.offset,
+            sort_by(q.sort),
+        )
+    }
+
+    fn query_build_deployments(
+        &self,
+        build_id: uuid::Uuid,
+        query: &BuildDeploymentQuery,
+    ) -> Result<models::GetAllBuildDeploymentsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_build_deployments(
+            build_id,
+            string(q.status),
+            q.all_search,
+            sort_by(q.sort),
+            q.limit,
+            q.offset,
+        )
+    }
+
+    fn query_datasets(
+        &self,
+        query: &DatasetQuery,
+    ) -> Result<models::GetAllDatasetsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_datasets(q.name, q.description, q.limit, q.offset)
+    }
+
+    fn query_events(
+        &self,
+        query: &EventQuery,
+    ) -> Result<models::GetAllEventsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_events(
+            string(q.actor_type),
+            string(q.action_type),
+            string(q.severity),
+            q.entity_id,
+            q.from,
+            q.to,
+            q.limit,
+            q.offset,
+            sort_by(q.sort),
+        )
+    }
+
+    fn query_nodes(&self, query: &NodeQuery) -> Result<models::GetAllNodesResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_nodes(
+            q.name,
+            q.description,
+            q.sgx_version,
+            q.all_search,
+            string(q.status),
+            q.limit,
+            q.offset,
+            sort_by(q.sort),
+        )
+    }
+
+    fn query_tasks(&self, query: &TaskQuery) -> Result<models::GetAllTasksResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_tasks(
+            string(q.task_type),
+            string(q.status),
+            q.requester,
+            q.approver,
+            q.all_search,
+            q.limit,
+            q.offset,
+            sort_by(q.sort),
+            q.base_filters,
+        )
+    }
+
+    fn query_users(&self, query: &UserQuery) -> Result<models::GetAllUsersResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_users(q.all_search, q.limit, q.offset, sort_by(q.sort))
+    }
+
+    fn query_workflow_graphs(
+        &self,
+        query: &WorkflowGraphQuery,
+    ) -> Result<models::GetAllWorkflowGraphsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_workflow_graphs(
+            q.name,
+            q.description,
+            q.all_search,
+            string(q.parent_graph_id),
+            sort_by(q.sort),
+            q.limit,
+            q.offset,
+        )
+    }
+
+    fn query_final_workflow_graphs(
+        &self,
+        query: &FinalWorkflowGraphQuery,
+    ) -> Result<models::GetAllFinalWorkflowGraphsResponse, Self::Error> {
+        let q = query.clone();
+        self.get_all_final_workflow_graphs(
+            q.name,
+            q.description,
+            q.all_search,
+            sort_by(q.sort),
+            q.limit,
+            q.offset,
+        )
+    }
+}
+
+impl<T: Api + ?Sized> QueryApi for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::{NodeQuery, NodeSortField, QueryApi, SortOrder, TaskQuery, TaskSortField};
+    use models::{NodeStatusType, TaskStatusType, TaskType};
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_to_query_string() {
+        let query = TaskQuery::new()
+            .task_type(TaskType::BUILD_WHITELIST)
+            .status(TaskStatusType::INPROGRESS)
+            .all_search("my app")
+            .sort_by(TaskSortField::Status, SortOrder::Descending)
+            .limit(10);
+        assert_eq!(
+            query.to_query_string(),
+            "task_type=BUILD_WHITELIST&status=INPROGRESS&all_search=my+app&limit=10&sort_by=status%3Adesc"
+        );
+        assert_eq!(TaskQuery::new().to_query_string(), "");
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_query_nodes() {
+        let mut server = mockito::Server::new();
+        let query = NodeQuery::new()
+            .status(NodeStatusType::RUNNING)
+            .sort_by(NodeSortField::Name, SortOrder::Ascending)
+            .offset(20);
+        let nodes = server
+            .mock("GET", "/v1/nodes")
+            .match_query(query.to_query_string().as_str())
+            .with_body(r#"{"items":[]}"#)
+            .create();
+
+        let client = ::client::Client::try_new_http(&server.url()).unwrap();
+        let response = client.query_nodes(&query).unwrap();
+        assert!(response.items.is_empty());
+        nodes.assert();
+        assert_eq!(
+            query.to_query_string(),
+            "status=RUNNING&offset=20&sort_by=name%3Aasc"
+        );
+    }
+}
@@ -0,0 +1,494 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Serve the API over HTTP, with any `Api` implementation behind it.
+//!
+//! `handle` decodes an HTTP request for one of the `/v1/...` operations, or
+//! for the same operation under `/api/v1/confidential_computing/...`, calls
+//! the operation and encodes its result the way `Client` expects it. `serve`
+//! runs `handle` in an HTTP server, e.g. to host a stand-in manager for
+//! integration tests:
+//!
+//! ```no_run
+//! # extern crate em_client;
+//! # #[cfg(feature = "fake")]
+//! # fn main() {
+//! use em_client::fake::FakeEnclaveManager;
+//! use em_client::{server, Api, Client};
+//!
+//! let server = server::serve("127.0.0.1:0", FakeEnclaveManager::new()).unwrap();
+//! let client = Client::try_new_http(&server.url()).unwrap();
+//! assert_eq!(client.get_zones().unwrap().len(), 1);
+//! # }
+//! # #[cfg(not(feature = "fake"))]
+//! # fn main() {}
+//! ```
+//!
+//! The enclave manager identifies the app asking for its runtime config by
+//! its client certificate. As `handle` can't do that, it expects the hex
+//! SHA-256 hash of the config in the `hash` query parameter of
+//! `GET /v1/runtime/app_configs` instead.
+use std::fmt;
+use std::io::Read;
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use hyper;
+use hyper::server::{Handler, Request as HyperRequest, Response as HyperResponse, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use serde::Serialize;
+use serde_json;
+use url;
+
+use models;
+use {Api, ApiError, ErrorType, ServerError, SimpleErrorType};
+
+mod routes;
+
+/// Prefix under which newer Enclave Manager deployments serve the API.
+const NEW_PATH_PREFIX: &str = "/api/v1/confidential_computing/";
+
+/// Trait for dispatching the operations of a request to an implementation
+/// of the API. Unlike `ApiDecorator`, it sees the request, so it can e.g.
+/// check its credentials or pick the implementation to call.
+///
+/// Every `Api` implementation dispatches to itself.
+pub trait ApiDispatch {
+    type Error;
+
+    fn dispatch<F, T>(&self, req: &Request, f: F) -> Result<T, Self::Error>
+    where
+        F: FnOnce(&dyn Api<Error = Self::Error>) -> Result<T, Self::Error>;
+}
+
+impl<A: Api> ApiDispatch for A {
+    type Error = A::Error;
+
+    fn dispatch<F, T>(&self, _req: &Request, f: F) -> Result<T, Self::Error>
+    where
+        F: FnOnce(&dyn Api<Error = Self::Error>) -> Result<T, Self::Error>,
+    {
+        f(self)
+    }
+}
+
+/// An HTTP request, independent of the server it was received by.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    /// The method in upper case, e.g. `GET`.
+    pub method: String,
+    /// The path of the request, including the query string if there is one.
+    pub uri: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn new(method: &str, uri: &str) -> Self {
+        Request {
+            method: method.to_ascii_uppercase(),
+            uri: uri.to_owned(),
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn with_body<B: Into<Vec<u8>>>(mut self, body: B) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// The first value of the header `name` (compared case-insensitively).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn path(&self) -> &str {
+        self.uri.split('?').next().unwrap_or("")
+    }
+
+    fn query(&self) -> &str {
+        self.uri.split_once('?').map_or("", |(_, query)| query)
+    }
+}
+
+/// An HTTP response produced by `handle`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// A response with `value` as its JSON body.
+    pub fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Response {
+                status,
+                headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+                body,
+            },
+            Err(e) => Response::event(
+                500,
+                models::EventType::INTERNAL_SERVER_ERROR,
+                format!("Unable to serialize response: {}", e),
+            ),
+        }
+    }
+
+    pub fn no_content() -> Self {
+        Response {
+            status: 204,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// An error response with an `Event` payload, as the enclave manager
+    /// sends them.
+    pub fn event(status: u16, code: models::EventType, message: String) -> Self {
+        Response::json(status, &models::Event::new(message, code))
+    }
+}
+
+impl From<ServerError> for Response {
+    fn from(err: ServerError) -> Self {
+        let (status, code) = match err.error_type {
+            ErrorType::BadRequest => (400, models::EventType::BAD_REQUEST),
+            ErrorType::Forbidden => (403, models::EventType::FORBIDDEN),
+            ErrorType::InvalidPathParameter => (400, models::EventType::INVALID_PATH_PARAMETER),
+            ErrorType::InvalidBodyParameter => (400, models::EventType::INVALID_BODY_PARAMETER),
+            ErrorType::InvalidQueryParameter => (400, models::EventType::INVALID_QUERY_PARAMETER),
+            ErrorType::MissingParameter => (400, models::EventType::MISSING_REQUIRED_PARAMETER),
+            ErrorType::NotFound => (404, models::EventType::NOT_FOUND),
+            ErrorType::MethodNotAllowed => (405, models::EventType::METHOD_NOT_ALLOWED),
+            ErrorType::InvalidHeader => (400, models::EventType::INVALID_HEADER),
+        };
+        Response::event(status, code, err.message)
+    }
+}
+
+/// Errors that carry a response, e.g. those returned by `Client`, are sent
+/// back as they were received. Other errors become a 500 response, or a
+/// 503 or 504 response if they are temporary, so that clients retry them.
+impl From<ApiError> for Response {
+    fn from(err: ApiError) -> Self {
+        let status = match err.status() {
+            Some(status) => status,
+            None => {
+                let status = match err.error_type() {
+                    _ if err.is_timeout() => 504,
+                    SimpleErrorType::Temporary => 503,
+                    SimpleErrorType::Permanent => 500,
+                };
+                let code = match err.event_type() {
+                    Some(code) => code,
+                    None => models::EventType::INTERNAL_SERVER_ERROR,
+                };
+                return Response::event(status, code, err.message().to_owned());
+            }
+        };
+        let headers = err
+            .headers()
+            .iter()
+            .filter(|(name, _)| !is_hop_by_hop(name))
+            .cloned()
+            .collect();
+        Response {
+            status,
+            headers,
+            body: err.body().unwrap_or_default().to_vec(),
+        }
+    }
+}
+
+/// Headers that describe the connection a response was received on rather
+/// than the response itself.
+fn is_hop_by_hop(name: &str) -> bool {
+    [
+        "connection",
+        "content-length",
+        "date",
+        "keep-alive",
+        "server",
+        "transfer-encoding",
+    ]
+    .iter()
+    .any(|header| name.eq_ignore_ascii_case(header))
+}
+
+/// Handle a request for one of the API operations.
+pub fn handle<D>(api: &D, req: &Request) -> Response
+where
+    D: ApiDispatch + ?Sized,
+    D::Error: Into<Response>,
+{
+    let path = req.path();
+    let operation = match path.strip_prefix(NEW_PATH_PREFIX) {
+        Some(operation) => operation,
+        None => match path.strip_prefix("/v1/") {
+            Some(operation) => operation,
+            None => return not_found(path).into(),
+        },
+    };
+    let segments: Vec<&str> = operation.split('/').collect();
+    match routes::route(api, req, &segments) {
+        Ok(response) => response,
+        Err(err) => {
+            debug!("Rejected {} {}: {}", req.method, path, err.message);
+            err.into()
+        }
+    }
+}
+
+fn not_found(path: &str) -> ServerError {
+    ServerError::new(&format!("No operation at {}", path), ErrorType::NotFound)
+}
+
+/// A server started by `serve`.
+///
+/// hyper can't stop a listening server, so it keeps serving requests until
+/// the process exits, even after this is dropped.
+pub struct Listening {
+    inner: hyper::server::Listening,
+}
+
+impl Listening {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.inner.socket
+    }
+
+    /// The base URL of the server, e.g. for `Client::try_new_http`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.inner.socket)
+    }
+}
+
+impl fmt::Debug for Listening {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Listening {{ local_addr: {} }}", self.inner.socket)
+    }
+}
+
+impl Drop for Listening {
+    fn drop(&mut self) {
+        // Dropping hyper's handle would wait for the server to stop, i.e.
+        // forever.
+        let _ = self.inner.close();
+    }
+}
+
+/// Serve the API over HTTP on `addr`, in background threads.
+///
+/// Connections are closed after each response, so that idle clients don't
+/// hold on to the server's threads.
+pub fn serve<A, D>(addr: A, api: D) -> hyper::Result<Listening>
+where
+    A: ToSocketAddrs,
+    D: ApiDispatch + Send + Sync + 'static,
+    D::Error: Into<Response>,
+{
+    let mut server = Server::http(addr)?;
+    server.keep_alive(None);
+    let inner = server.handle(HyperHandler { api })?;
+    Ok(Listening { inner })
+}
+
+struct HyperHandler<D> {
+    api: D,
+}
+
+impl<D> Handler for HyperHandler<D>
+where
+    D: ApiDispatch + Send + Sync,
+    D::Error: Into<Response>,
+{
+    fn handle(&self, mut req: HyperRequest, mut res: HyperResponse) {
+        let response = match read_request(&mut req) {
+            Ok(request) => handle(&self.api, &request),
+            Err(err) => err.into(),
+        };
+        *res.status_mut() = StatusCode::from_u16(response.status);
+        for (name, value) in response.headers {
+            res.headers_mut().set_raw(name, vec![value.into_bytes()]);
+        }
+        if let Err(e) = res.send(&response.body) {
+            debug!("Failed to send response: {}", e);
+        }
+    }
+}
+
+fn read_request(req: &mut HyperRequest) -> Result<Request, ServerError> {
+    let uri = match req.uri {
+        RequestUri::AbsolutePath(ref path) => path.clone(),
+        RequestUri::AbsoluteUri(ref url) => url[url::Position::BeforePath..].to_owned(),
+        _ => {
+            return Err(ServerError::new(
+                "Unsupported request target",
+                ErrorType::BadRequest,
+            ))
+        }
+    };
+    let mut request = Request::new(req.method.as_ref(), &uri);
+    request.headers = req
+        .headers
+        .iter()
+        .map(|header| (header.name().to_owned(), header.value_string()))
+        .collect();
+    req.read_to_end(&mut request.body).map_err(|e| {
+        ServerError::new(
+            &format!("Failed to read request body: {}", e),
+            ErrorType::BadRequest,
+        )
+    })?;
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+    use models;
+    use {ErrorType, ServerError};
+
+    #[test]
+    fn test_server_errors_become_events() {
+        let response: Response = ServerError::new("no such thing", ErrorType::NotFound).into();
+        assert_eq!(response.status, 404);
+        let event: models::Event = ::serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(event.code, models::EventType::NOT_FOUND);
+        assert_eq!(event.message, "no such thing");
+    }
+
+    #[cfg(feature = "fake")]
+    mod fake {
+        use super::super::{handle, serve, ApiDispatch, Request, Response};
+        use fake::FakeEnclaveManager;
+        use models;
+        use {Api, ApiError, SimpleErrorType};
+
+        fn event(response: &Response) -> models::Event {
+            ::serde_json::from_slice(&response.body).unwrap()
+        }
+
+        #[test]
+        fn test_routing() {
+            let fake = FakeEnclaveManager::new();
+            let body = r#"{"name":"app","input_image_name":"in","output_image_name":"out","isvprodid":1,"isvsvn":1,"mem_size":1024,"threads":4}"#;
+            let response = handle(&fake, &Request::new("POST", "/v1/apps").with_body(body));
+            assert_eq!(response.status, 200);
+            let app: models::App = ::serde_json::from_slice(&response.body).unwrap();
+
+            let path = format!("/api/v1/confidential_computing/apps/{}", app.app_id);
+            let response = handle(&fake, &Request::new("GET", &path));
+            assert_eq!(response.status, 200);
+
+            let response = handle(&fake, &Request::new("DELETE", &path));
+            assert_eq!(response.status, 204);
+            let response = handle(&fake, &Request::new("GET", &path));
+            assert_eq!(response.status, 404);
+            assert_eq!(event(&response).code, models::EventType::NOT_FOUND);
+
+            let response = handle(&fake, &Request::new("GET", "/v1/apps?name=x&limit=ten"));
+            assert_eq!(
+                event(&response).code,
+                models::EventType::INVALID_QUERY_PARAMETER
+            );
+            let response = handle(&fake, &Request::new("GET", "/v1/apps/not-a-uuid"));
+            assert_eq!(
+                event(&response).code,
+                models::EventType::INVALID_PATH_PARAMETER
+            );
+            let response = handle(&fake, &Request::new("POST", "/v1/apps"));
+            assert_eq!(
+                event(&response).code,
+                models::EventType::MISSING_REQUIRED_PARAMETER
+            );
+            let response = handle(&fake, &Request::new("PUT", "/v1/apps"));
+            assert_eq!(response.status, 405);
+            let response = handle(&fake, &Request::new("GET", "/v2/apps"));
+            assert_eq!(response.status, 404);
+        }
+
+        /// Requires an `Authorization` header on every request.
+        struct RequireAuth(FakeEnclaveManager);
+
+        impl ApiDispatch for RequireAuth {
+            type Error = ApiError;
+
+            fn dispatch<F, T>(&self, req: &Request, f: F) -> Result<T, ApiError>
+            where
+                F: FnOnce(&dyn Api<Error = ApiError>) -> Result<T, ApiError>,
+            {
+                match req.header("authorization") {
+                    Some(_) => f(&self.0),
+                    None => Err(ApiError::new(
+                        "Missing credentials".to_owned(),
+                        SimpleErrorType::Permanent,
+                    )
+                    .with_response(401, Vec::new(), Vec::new())),
+                }
+            }
+        }
+
+        #[test]
+        fn test_custom_dispatch() {
+            let api = RequireAuth(FakeEnclaveManager::new());
+            let response = handle(&api, &Request::new("GET", "/v1/sys/version"));
+            assert_eq!(response.status, 401);
+            let request =
+                Request::new("GET", "/v1/sys/version").with_header("Authorization", "Bearer x");
+            assert_eq!(handle(&api, &request).status, 200);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn test_client_round_trip() {
+            let server = serve("127.0.0.1:0", FakeEnclaveManager::new()).unwrap();
+            let client = ::client::Client::try_new_http(&server.url()).unwrap();
+
+            let task = client
+                .provision_node(models::NodeProvisionRequest {
+                    name: "node".to_owned(),
+                    description: None,
+                    ipaddress: "10.0.0.1".to_owned(),
+                    host_id: None,
+                    sgx_version: "2".to_owned(),
+                    attestation_request: None,
+                    error_report: None,
+                })
+                .unwrap();
+            let nodes = client
+                .get_all_nodes(
+                    Some("node".to_owned()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(nodes.items.len(), 1);
+            assert_eq!(Some(nodes.items[0].node_id), task.node_id);
+
+            let err = client.get_node(::uuid::Uuid::nil()).unwrap_err();
+            assert!(err.is_not_found());
+            assert_eq!(err.status(), Some(404));
+
+            let client = client.with_new_paths();
+            assert_eq!(client.get_zones().unwrap().len(), 1);
+        }
+    }
+}
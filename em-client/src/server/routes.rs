@@ -0,0 +1,855 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json;
+use url::form_urlencoded;
+use url::percent_encoding::percent_decode;
+
+use super::{ApiDispatch, Request, Response};
+use {ErrorType, ServerError, Sha256Hash};
+
+/// The query parameters of a request.
+struct Query(Vec<(String, String)>);
+
+impl Query {
+    fn new(req: &Request) -> Self {
+        Query(
+            form_urlencoded::parse(req.query().as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
+    fn string(&self, name: &str) -> Option<String> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn parse<T>(&self, name: &str) -> Result<Option<T>, ServerError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.string(name) {
+            Some(value) => value.parse().map(Some).map_err(|e| {
+                ServerError::new(
+                    &format!("Invalid query parameter {}: {}", name, e),
+                    ErrorType::InvalidQueryParameter,
+                )
+            }),
+            None => Ok(None),
+        }
+    }
+
+    fn required(&self, name: &str) -> Result<String, ServerError> {
+        self.string(name).ok_or_else(|| {
+            ServerError::new(
+                &format!("Missing query parameter {}", name),
+                ErrorType::MissingParameter,
+            )
+        })
+    }
+}
+
+/// Decode a path segment, which `Client` percent-encodes.
+fn path<T>(name: &str, segment: &str) -> Result<T, ServerError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let invalid = |e: &dyn Display| {
+        ServerError::new(
+            &format!("Invalid path parameter {}: {}", name, e),
+            ErrorType::InvalidPathParameter,
+        )
+    };
+    let decoded = percent_decode(segment.as_bytes())
+        .decode_utf8()
+        .map_err(|e| invalid(&e))?;
+    decoded.parse().map_err(|e| invalid(&e))
+}
+
+fn body<T: DeserializeOwned>(req: &Request) -> Result<T, ServerError> {
+    optional_body(req)?
+        .ok_or_else(|| ServerError::new("Missing request body", ErrorType::MissingParameter))
+}
+
+fn optional_body<T: DeserializeOwned>(req: &Request) -> Result<Option<T>, ServerError> {
+    if req.body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+    serde_json::from_slice(&req.body).map(Some).map_err(|e| {
+        ServerError::new(
+            &format!("Invalid request body: {}", e),
+            ErrorType::InvalidBodyParameter,
+        )
+    })
+}
+
+fn ok<T: Serialize, E: Into<Response>>(result: Result<T, E>) -> Response {
+    respond(200, result)
+}
+
+fn created<T: Serialize, E: Into<Response>>(result: Result<T, E>) -> Response {
+    respond(201, result)
+}
+
+fn respond<T: Serialize, E: Into<Response>>(status: u16, result: Result<T, E>) -> Response {
+    match result {
+        Ok(value) => Response::json(status, &value),
+        Err(err) => err.into(),
+    }
+}
+
+fn no_content<E: Into<Response>>(result: Result<(), E>) -> Response {
+    match result {
+        Ok(()) => Response::no_content(),
+        Err(err) => err.into(),
+    }
+}
+
+fn method_not_allowed(req: &Request) -> Result<Response, ServerError> {
+    Err(ServerError::new(
+        &format!("Method {} not allowed for {}", req.method, req.path()),
+        ErrorType::MethodNotAllowed,
+    ))
+}
+
+/// Route a request to its operation. `segments` is the path of the request
+/// after the `/v1/` prefix, split at each `/`.
+pub(super) fn route<D>(api: &D, req: &Request, segments: &[&str]) -> Result<Response, ServerError>
+where
+    D: ApiDispatch + ?Sized,
+    D::Error: Into<Response>,
+{
+    let query = Query::new(req);
+    let method = req.method.as_str();
+    let response = match *segments {
+        ["accounts"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_accounts())),
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_account(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["accounts", "select_account", account_id] => match method {
+            "POST" => {
+                let account_id = path("account_id", account_id)?;
+                no_content(api.dispatch(req, |api| api.select_account(account_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["accounts", account_id] => {
+            let account_id = path("account_id", account_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_account(account_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_account(account_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_account(account_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["apps"] => match method {
+            "GET" => {
+                let (name, description, all_search) = (
+                    query.string("name"),
+                    query.string("description"),
+                    query.string("all_search"),
+                );
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_apps(name, description, all_search, limit, offset, sort_by)
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.add_application(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["apps", "unique_labels", "count"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_apps_unique_labels())),
+            _ => return method_not_allowed(req),
+        },
+        ["apps", app_id, "node", node_id, "certificate"] => match method {
+            "GET" => {
+                let app_id = path("app_id", app_id)?;
+                let node_id = path("node_id", node_id)?;
+                ok(api.dispatch(req, |api| api.get_app_certificate(node_id, app_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["apps", app_id, "node", node_id, "certificate-details"] => match method {
+            "GET" => {
+                let app_id = path("app_id", app_id)?;
+                let node_id = path("node_id", node_id)?;
+                ok(api.dispatch(req, |api| {
+                    api.get_app_node_certificate_details(node_id, app_id)
+                }))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["apps", app_id] => {
+            let app_id = path("app_id", app_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_app(app_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_app(app_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_app(app_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["app_configs"] => match method {
+            "GET" => {
+                let (name, description) = (query.string("name"), query.string("description"));
+                let image_id = query.parse("image_id")?;
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_all_application_configs(name, description, image_id, limit, offset)
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_application_config(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["app_configs", config_id] => {
+            let config_id: String = path("config_id", config_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_application_config(config_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_application_config(config_id, body)))
+                }
+                "DELETE" => {
+                    no_content(api.dispatch(req, |api| api.delete_application_config(config_id)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+        ["runtime", "app_configs"] => match method {
+            "GET" => {
+                let hash = Sha256Hash::try_from(query.required("hash")?.as_str()).map_err(|e| {
+                    ServerError::new(
+                        &format!("Invalid query parameter hash: {}", e),
+                        ErrorType::InvalidQueryParameter,
+                    )
+                })?;
+                ok(api.dispatch(req, |api| api.get_runtime_application_config(&hash)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["runtime", "app_configs", config_id] => match method {
+            "GET" => {
+                let config_id = path("config_id", config_id)?;
+                ok(api.dispatch(req, |api| {
+                    api.get_specific_runtime_application_config(config_id)
+                }))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["approval_requests"] => match method {
+            "GET" => {
+                let requester = query.parse("requester")?;
+                let reviewer = query.parse("reviewer")?;
+                let subject = query.parse("subject")?;
+                let (status, all_search) = (query.string("status"), query.string("all_search"));
+                let sort_by = query.string("sort_by");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_all_approval_requests(
+                        requester, reviewer, subject, status, all_search, sort_by, limit, offset,
+                    )
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                created(api.dispatch(req, |api| api.create_approval_request(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["approval_requests", request_id, "approve"] => match method {
+            "POST" => {
+                let request_id = path("request_id", request_id)?;
+                let body = optional_body(req)?;
+                ok(api.dispatch(req, |api| api.approve_approval_request(request_id, body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["approval_requests", request_id, "deny"] => match method {
+            "POST" => {
+                let request_id = path("request_id", request_id)?;
+                let body = optional_body(req)?;
+                ok(api.dispatch(req, |api| api.deny_approval_request(request_id, body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["approval_requests", request_id, "result"] => match method {
+            "POST" => {
+                let request_id = path("request_id", request_id)?;
+                ok(api.dispatch(req, |api| api.get_approval_request_result(request_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["approval_requests", request_id] => {
+            let request_id = path("request_id", request_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_approval_request(request_id))),
+                "DELETE" => {
+                    no_content(api.dispatch(req, |api| api.delete_approval_request(request_id)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["sys", "auth"] => match method {
+            "POST" => {
+                let body = optional_body(req)?;
+                ok(api.dispatch(req, |api| api.authenticate_user(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["sys", "auth", "discover"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.auth_discover(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["sys", "session", "refresh"] => match method {
+            "POST" => ok(api.dispatch(req, |api| api.refresh_session())),
+            _ => return method_not_allowed(req),
+        },
+        ["sys", "version"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_manager_version())),
+            _ => return method_not_allowed(req),
+        },
+
+        ["builds"] => match method {
+            "GET" => {
+                let all_search = query.string("all_search");
+                let docker_image_name = query.string("docker_image_name");
+                let config_id = query.string("config_id");
+                let deployed_status = query.string("deployed_status");
+                let status = query.string("status");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_builds(
+                        all_search,
+                        docker_image_name,
+                        config_id,
+                        deployed_status,
+                        status,
+                        limit,
+                        offset,
+                        sort_by,
+                    )
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_build(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["builds", "convert-app"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.convert_app_build(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["builds", "deployments", build_id] => match method {
+            "GET" => {
+                let build_id = path("build_id", build_id)?;
+                let (status, all_search) = (query.string("status"), query.string("all_search"));
+                let sort_by = query.string("sort_by");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_build_deployments(build_id, status, all_search, sort_by, limit, offset)
+                }))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["builds", build_id] => {
+            let build_id = path("build_id", build_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_build(build_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_build(build_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_build(build_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["certificates"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.new_certificate(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["certificates", cert_id] => match method {
+            "GET" => {
+                let cert_id = path("cert_id", cert_id)?;
+                ok(api.dispatch(req, |api| api.get_certificate(cert_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["datasets"] => match method {
+            "GET" => {
+                let (name, description) = (query.string("name"), query.string("description"));
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_all_datasets(name, description, limit, offset)
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_dataset(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["datasets", dataset_id] => {
+            let dataset_id = path("dataset_id", dataset_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_dataset(dataset_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_dataset(dataset_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_dataset(dataset_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["events"] => match method {
+            "GET" => {
+                let actor_type = query.string("actor_type");
+                let (action_type, severity) =
+                    (query.string("action_type"), query.string("severity"));
+                let entity_id = query.parse("entity_id")?;
+                let (from, to) = (query.parse("from")?, query.parse("to")?);
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_events(
+                        actor_type,
+                        action_type,
+                        severity,
+                        entity_id,
+                        from,
+                        to,
+                        limit,
+                        offset,
+                        sort_by,
+                    )
+                }))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["nodes"] => match method {
+            "GET" => {
+                let (name, description) = (query.string("name"), query.string("description"));
+                let sgx_version = query.string("sgx_version");
+                let (all_search, status) = (query.string("all_search"), query.string("status"));
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_nodes(
+                        name,
+                        description,
+                        sgx_version,
+                        all_search,
+                        status,
+                        limit,
+                        offset,
+                        sort_by,
+                    )
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.provision_node(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["nodes", "unique_labels", "count"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_nodes_unique_labels())),
+            _ => return method_not_allowed(req),
+        },
+        ["nodes", node_id, "deactivate"] => match method {
+            "POST" => {
+                let node_id = path("node_id", node_id)?;
+                no_content(api.dispatch(req, |api| api.deactivate_node(node_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["nodes", node_id, "certificate"] => match method {
+            "GET" => {
+                let node_id = path("node_id", node_id)?;
+                ok(api.dispatch(req, |api| api.get_node_certificate(node_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["nodes", node_id, "certificate-details"] => match method {
+            "GET" => {
+                let node_id = path("node_id", node_id)?;
+                ok(api.dispatch(req, |api| api.get_node_certificate_details(node_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["nodes", node_id] => {
+            let node_id = path("node_id", node_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_node(node_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_node(node_id, body)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+        ["node", "status"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.update_node_status(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["registry"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_all_registries())),
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_registry(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["registry", "app", app_id] => match method {
+            "GET" => {
+                let app_id = path("app_id", app_id)?;
+                ok(api.dispatch(req, |api| api.get_registry_for_app(app_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["registry", registry_id] => {
+            let registry_id = path("registry_id", registry_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_registry(registry_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_registry(registry_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_registry(registry_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+        ["image", "registry"] => match method {
+            "GET" => {
+                let image_name = query.required("image_name")?;
+                ok(api.dispatch(req, |api| api.get_registry_for_image(image_name)))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["tasks"] => match method {
+            "GET" => {
+                let (task_type, status) = (query.string("task_type"), query.string("status"));
+                let (requester, approver) = (query.string("requester"), query.string("approver"));
+                let all_search = query.string("all_search");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                let base_filters = query.string("base_filters");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_tasks(
+                        task_type,
+                        status,
+                        requester,
+                        approver,
+                        all_search,
+                        limit,
+                        offset,
+                        sort_by,
+                        base_filters,
+                    )
+                }))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["tasks", "status", task_id] => match method {
+            "GET" => {
+                let task_id = path("task_id", task_id)?;
+                ok(api.dispatch(req, |api| api.get_task_status(task_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["tasks", task_id] => {
+            let task_id = path("task_id", task_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_task(task_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_task(task_id, body)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["tools", "converter", "convert-app"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.convert_app(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+
+        ["user"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_logged_in_user())),
+            _ => return method_not_allowed(req),
+        },
+        ["users"] => match method {
+            "GET" => {
+                let all_search = query.string("all_search");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                let sort_by = query.string("sort_by");
+                ok(api.dispatch(req, |api| {
+                    api.get_all_users(all_search, limit, offset, sort_by)
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                created(api.dispatch(req, |api| api.create_user(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "terms_and_conditions"] => match method {
+            "PATCH" => no_content(api.dispatch(req, |api| api.accept_terms_and_conditions())),
+            _ => return method_not_allowed(req),
+        },
+        ["users", "change_password"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                no_content(api.dispatch(req, |api| api.change_password(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "confirm_email"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.confirm_email(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "forgot_password"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                no_content(api.dispatch(req, |api| api.forgot_password(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "invite"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                created(api.dispatch(req, |api| api.invite_user(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "process_invite"] => match method {
+            "POST" => {
+                let body = body(req)?;
+                no_content(api.dispatch(req, |api| api.process_invitations(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", "resend_confirm_email"] => match method {
+            "POST" => no_content(api.dispatch(req, |api| api.resend_confirm_email())),
+            _ => return method_not_allowed(req),
+        },
+        ["users", user_id, "accounts"] => match method {
+            "DELETE" => {
+                let user_id = path("user_id", user_id)?;
+                no_content(api.dispatch(req, |api| api.delete_user_from_account(user_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", user_id, "resend_invite"] => match method {
+            "POST" => {
+                let user_id = path("user_id", user_id)?;
+                no_content(api.dispatch(req, |api| api.resend_invitation(user_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", user_id, "reset_password"] => match method {
+            "POST" => {
+                let user_id = path("user_id", user_id)?;
+                let body = body(req)?;
+                no_content(api.dispatch(req, |api| api.reset_password(user_id, body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", user_id, "validate_token"] => match method {
+            "POST" => {
+                let user_id = path("user_id", user_id)?;
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.validate_password_reset_token(user_id, body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["users", user_id] => {
+            let user_id = path("user_id", user_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_user(user_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_user(user_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_user_account(user_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["workflows", "draft", "graphs"] => match method {
+            "GET" => {
+                let (name, description) = (query.string("name"), query.string("description"));
+                let all_search = query.string("all_search");
+                let parent_graph_id = query.string("parent_graph_id");
+                let sort_by = query.string("sort_by");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_all_workflow_graphs(
+                        name,
+                        description,
+                        all_search,
+                        parent_graph_id,
+                        sort_by,
+                        limit,
+                        offset,
+                    )
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_workflow_graph(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["workflows", "draft", "graphs", graph_id] => {
+            let graph_id = path("graph_id", graph_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_workflow_graph(graph_id))),
+                "PUT" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_workflow_graph(graph_id, body)))
+                }
+                "DELETE" => {
+                    no_content(api.dispatch(req, |api| api.delete_workflow_graph(graph_id)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+        ["workflows", "final", "graphs"] => match method {
+            "GET" => {
+                let (name, description) = (query.string("name"), query.string("description"));
+                let all_search = query.string("all_search");
+                let sort_by = query.string("sort_by");
+                let (limit, offset) = (query.parse("limit")?, query.parse("offset")?);
+                ok(api.dispatch(req, |api| {
+                    api.get_all_final_workflow_graphs(
+                        name,
+                        description,
+                        all_search,
+                        sort_by,
+                        limit,
+                        offset,
+                    )
+                }))
+            }
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_final_workflow_graph(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["workflows", "final", "graphs", graph_id] => {
+            let graph_id = path("graph_id", graph_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_full_final_workflow_graph(graph_id))),
+                "POST" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_final_workflow_graph(graph_id, body)))
+                }
+                _ => return method_not_allowed(req),
+            }
+        }
+        ["workflows", "final", "graphs", graph_id, version] => {
+            let graph_id = path("graph_id", graph_id)?;
+            let version: String = path("version", version)?;
+            match method {
+                "GET" => {
+                    ok(api.dispatch(req, |api| api.get_final_workflow_graph(graph_id, version)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| {
+                    api.delete_final_workflow_graph(graph_id, version)
+                })),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        ["zones"] => match method {
+            "GET" => ok(api.dispatch(req, |api| api.get_zones())),
+            "POST" => {
+                let body = body(req)?;
+                ok(api.dispatch(req, |api| api.create_zone(body)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["zones", zone_id, "token"] => match method {
+            "GET" => {
+                let zone_id = path("zone_id", zone_id)?;
+                ok(api.dispatch(req, |api| api.get_zone_join_token(zone_id)))
+            }
+            _ => return method_not_allowed(req),
+        },
+        ["zones", zone_id] => {
+            let zone_id = path("zone_id", zone_id)?;
+            match method {
+                "GET" => ok(api.dispatch(req, |api| api.get_zone(zone_id))),
+                "PATCH" => {
+                    let body = body(req)?;
+                    ok(api.dispatch(req, |api| api.update_zone(zone_id, body)))
+                }
+                "DELETE" => no_content(api.dispatch(req, |api| api.delete_zone(zone_id))),
+                _ => return method_not_allowed(req),
+            }
+        }
+
+        _ => return Err(super::not_found(req.path())),
+    };
+    Ok(response)
+}
@@ -0,0 +1,423 @@
+/* Copyright (c) Fortanix, Inc.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+//! Polling for the outcome of tasks, builds and nodes.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use em_client::wait::{WaitApi, WaitError, WaitOptions};
+//! use em_client::Api;
+//! use std::time::Duration;
+//!
+//! let client = em_client::Client::try_new_http("http://localhost:9090").unwrap();
+//! # let mut request = em_client::models::CreateBuildRequest::new(
+//! #     "11".repeat(32),
+//! #     "22".repeat(32),
+//! #     1,
+//! #     1,
+//! # );
+//! # request.app_name = Some("app".to_owned());
+//! let build = client.create_build(request)?;
+//! let options = WaitOptions::new().with_timeout(Duration::from_secs(600));
+//! match client.wait_for_build(build.build_id.unwrap(), options) {
+//!     Ok(build) => println!("{:?} is whitelisted", build.build_id),
+//!     Err(WaitError::Denied(build)) => println!("{:?} was rejected", build.build_id),
+//!     Err(err) => return Err(err.to_string().into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use models;
+use uuid;
+use Api;
+
+/// Time between two polls unless configured otherwise.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest time a waiter sleeps before checking for cancellation.
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Stops a wait from another thread. Clones share the cancellation.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// How a waiter polls. By default it polls every second, without a timeout.
+#[derive(Clone, Debug)]
+pub struct WaitOptions {
+    interval: Duration,
+    timeout: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        WaitOptions {
+            interval: DEFAULT_POLL_INTERVAL,
+            timeout: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl WaitOptions {
+    pub fn new() -> Self {
+        WaitOptions::default()
+    }
+
+    pub fn with_interval(self, interval: Duration) -> Self {
+        WaitOptions { interval, ..self }
+    }
+
+    /// Give up once `timeout` has passed since the wait started.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        WaitOptions {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Give up once `token` is cancelled.
+    pub fn with_cancellation(self, token: CancellationToken) -> Self {
+        WaitOptions {
+            cancellation: Some(token),
+            ..self
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Sleep for `duration`, returning early with `false` if cancelled.
+    pub(crate) fn sleep(&self, duration: Duration) -> bool {
+        let until = Instant::now() + duration;
+        loop {
+            if self.is_cancelled() {
+                return false;
+            }
+            let now = Instant::now();
+            if now >= until {
+                return true;
+            }
+            thread::sleep((until - now).min(CANCELLATION_CHECK_INTERVAL));
+        }
+    }
+}
+
+/// Why waiting for a task, build or node did not succeed. Apart from `Api`
+/// and `Cancelled`, the variants carry the object as last seen.
+#[derive(Debug)]
+pub enum WaitError<T, E> {
+    /// The task was denied, or the build rejected.
+    Denied(Box<T>),
+    /// The task or the node failed.
+    Failed(Box<T>),
+    /// The timeout passed while the task was still awaiting approval, or the
+    /// build was still pending whitelisting.
+    PendingWhitelist(Box<T>),
+    /// The timeout passed while the node was still being set up.
+    TimedOut(Box<T>),
+    /// The wait was cancelled through its `CancellationToken`.
+    Cancelled,
+    /// Polling failed.
+    Api(E),
+}
+
+impl<T, E> WaitError<T, E> {
+    /// The object as last seen, if any.
+    pub fn last_seen(&self) -> Option<&T> {
+        match *self {
+            WaitError::Denied(ref t)
+            | WaitError::Failed(ref t)
+            | WaitError::PendingWhitelist(ref t)
+            | WaitError::TimedOut(ref t) => Some(t),
+            WaitError::Cancelled | WaitError::Api(_) => None,
+        }
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for WaitError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WaitError::Denied(_) => write!(f, "Denied"),
+            WaitError::Failed(_) => write!(f, "Failed"),
+            WaitError::PendingWhitelist(_) => write!(f, "Timed out pending whitelisting"),
+            WaitError::TimedOut(_) => write!(f, "Timed out"),
+            WaitError::Cancelled => write!(f, "Cancelled"),
+            WaitError::Api(ref err) => write!(f, "Failed to poll: {}", err),
+        }
+    }
+}
+
+impl<T: fmt::Debug, E: error::Error + 'static> error::Error for WaitError<T, E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            WaitError::Api(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of one poll.
+enum Step<T> {
+    Done(T),
+    Pending(T),
+}
+
+/// Poll until `poll` is done, fails, or the wait times out or is cancelled.
+/// `timed_out` makes the error for the object last seen pending.
+fn wait<T, E, F>(
+    options: &WaitOptions,
+    timed_out: fn(T) -> WaitError<T, E>,
+    mut poll: F,
+) -> Result<T, WaitError<T, E>>
+where
+    F: FnMut() -> Result<Step<T>, WaitError<T, E>>,
+{
+    let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+    loop {
+        if options.is_cancelled() {
+            return Err(WaitError::Cancelled);
+        }
+        let pending = match poll()? {
+            Step::Done(value) => return Ok(value),
+            Step::Pending(value) => value,
+        };
+        let now = Instant::now();
+        let sleep = match deadline {
+            Some(deadline) if deadline <= now => return Err(timed_out(pending)),
+            Some(deadline) => options.interval.min(deadline - now),
+            None => options.interval,
+        };
+        if !options.sleep(sleep) {
+            return Err(WaitError::Cancelled);
+        }
+    }
+}
+
+/// Waiters for the long-running operations of any `Api` implementation.
+pub trait WaitApi: Api {
+    /// Wait until the task leaves `INPROGRESS`, e.g. after `create_build`,
+    /// `provision_node` or `new_certificate`. Returns the task if it
+    /// succeeded.
+    fn wait_for_task(
+        &self,
+        task_id: uuid::Uuid,
+        options: WaitOptions,
+    ) -> Result<models::Task, WaitError<models::Task, Self::Error>> {
+        wait(
+            &options,
+            |task| WaitError::PendingWhitelist(Box::new(task)),
+            || {
+                let task = self.get_task(task_id).map_err(WaitError::Api)?;
+                match task.status.status {
+                    models::TaskStatusType::INPROGRESS => Ok(Step::Pending(task)),
+                    models::TaskStatusType::SUCCESS => Ok(Step::Done(task)),
+                    models::TaskStatusType::DENIED => Err(WaitError::Denied(Box::new(task))),
+                    models::TaskStatusType::FAILED => Err(WaitError::Failed(Box::new(task))),
+                }
+            },
+        )
+    }
+
+    /// Wait until the build is no longer `PENDING`. Returns the build if it
+    /// was whitelisted.
+    fn wait_for_build(
+        &self,
+        build_id: uuid::Uuid,
+        options: WaitOptions,
+    ) -> Result<models::Build, WaitError<models::Build, Self::Error>> {
+        wait(
+            &options,
+            |build| WaitError::PendingWhitelist(Box::new(build)),
+            || {
+                let build = self.get_build(build_id).map_err(WaitError::Api)?;
+                match build.status.status {
+                    models::BuildStatusType::PENDING => Ok(Step::Pending(build)),
+                    models::BuildStatusType::WHITELISTED => Ok(Step::Done(build)),
+                    models::BuildStatusType::REJECTED => Err(WaitError::Denied(Box::new(build))),
+                }
+            },
+        )
+    }
+
+    /// Wait until the node is no longer `INPROGRESS`. Returns the node if it
+    /// is `RUNNING`; a node that is `FAILED`, `STOPPED` or `DEACTIVATED`
+    /// instead is reported as `WaitError::Failed`.
+    fn wait_for_node(
+        &self,
+        node_id: uuid::Uuid,
+        options: WaitOptions,
+    ) -> Result<models::Node, WaitError<models::Node, Self::Error>> {
+        wait(
+            &options,
+            |node| WaitError::TimedOut(Box::new(node)),
+            || {
+                let node = self.get_node(node_id).map_err(WaitError::Api)?;
+                match node.status.status {
+                    models::NodeStatusType::INPROGRESS => Ok(Step::Pending(node)),
+                    models::NodeStatusType::RUNNING => Ok(Step::Done(node)),
+                    models::NodeStatusType::FAILED
+                    | models::NodeStatusType::STOPPED
+                    | models::NodeStatusType::DEACTIVATED => Err(WaitError::Failed(Box::new(node))),
+                }
+            },
+        )
+    }
+}
+
+impl<T: Api + ?Sized> WaitApi for T {}
+
+#[cfg(all(test, feature = "fake"))]
+mod tests {
+    use super::{CancellationToken, WaitApi, WaitError, WaitOptions};
+    use fake::FakeEnclaveManager;
+    use models;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use Api;
+
+    fn create_build(fake: &FakeEnclaveManager) -> models::Build {
+        fake.create_build(models::CreateBuildRequest {
+            docker_info: None,
+            mrenclave: "11".repeat(32),
+            mrsigner: "22".repeat(32),
+            isvprodid: 1,
+            isvsvn: 1,
+            app_id: None,
+            app_name: Some("app".to_owned()),
+            mem_size: None,
+            threads: None,
+            advanced_settings: None,
+        })
+        .unwrap()
+    }
+
+    fn options() -> WaitOptions {
+        WaitOptions::new()
+            .with_interval(Duration::from_millis(10))
+            .with_timeout(Duration::from_secs(5))
+    }
+
+    #[test]
+    fn test_wait_for_approval() {
+        let fake = Arc::new(FakeEnclaveManager::new());
+        let build = create_build(&fake);
+        let task_id = build.pending_task_id.unwrap();
+
+        let approver = fake.clone();
+        let approval = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            approver
+                .update_task(
+                    task_id,
+                    models::TaskUpdateRequest::new(models::ApprovalStatus::APPROVED),
+                )
+                .unwrap();
+        });
+        let task = fake.wait_for_task(task_id, options()).unwrap();
+        approval.join().unwrap();
+        assert_eq!(task.status.status, models::TaskStatusType::SUCCESS);
+        assert!(fake.calls("get_task") > 1);
+
+        let build = fake
+            .wait_for_build(build.build_id.unwrap(), options())
+            .unwrap();
+        assert_eq!(build.status.status, models::BuildStatusType::WHITELISTED);
+    }
+
+    #[test]
+    fn test_wait_errors() {
+        let fake = FakeEnclaveManager::new();
+        let build = create_build(&fake);
+        let task_id = build.pending_task_id.unwrap();
+
+        let err = fake
+            .wait_for_task(task_id, options().with_timeout(Duration::from_millis(30)))
+            .unwrap_err();
+        match err {
+            WaitError::PendingWhitelist(ref task) => assert_eq!(task.task_id, task_id),
+            ref err => panic!("unexpected error {}", err),
+        }
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            canceller.cancel();
+        });
+        let started = Instant::now();
+        let err = fake
+            .wait_for_task(
+                task_id,
+                WaitOptions::new()
+                    .with_interval(Duration::from_secs(60))
+                    .with_cancellation(token),
+            )
+            .unwrap_err();
+        assert!(matches!(err, WaitError::Cancelled));
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        fake.set_task_status(task_id, models::TaskStatusType::FAILED)
+            .unwrap();
+        let err = fake.wait_for_task(task_id, options()).unwrap_err();
+        assert!(matches!(err, WaitError::Failed(_)));
+
+        let build = create_build(&fake);
+        fake.update_task(
+            build.pending_task_id.unwrap(),
+            models::TaskUpdateRequest::new(models::ApprovalStatus::DENIED),
+        )
+        .unwrap();
+        let err = fake
+            .wait_for_task(build.pending_task_id.unwrap(), options())
+            .unwrap_err();
+        assert!(matches!(err, WaitError::Denied(_)));
+        let err = fake
+            .wait_for_build(build.build_id.unwrap(), options())
+            .unwrap_err();
+        assert_eq!(
+            err.last_seen().unwrap().status.status,
+            models::BuildStatusType::REJECTED
+        );
+
+        let err = fake
+            .wait_for_node(uuid::Uuid::nil(), options())
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Failed to poll"));
+    }
+}